@@ -1,9 +1,9 @@
 use serde::Deserialize;
 use std::collections::HashMap;
 
-use super::Error;
+use super::{DownloadOptions, Error, ModelInfo, ModelVersion, is_retryable, with_retries};
 
-const MANIFEST_URL: &str = "https://artifacts.ai-coustics.io/manifest.json";
+pub(crate) const MANIFEST_URL: &str = "https://artifacts.ai-coustics.io/manifest.json";
 
 #[derive(Debug, Deserialize)]
 pub struct Manifest {
@@ -15,52 +15,95 @@ struct Model {
     versions: HashMap<String, ModelMetadata>,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+/// Manifest entry describing a single model file.
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
 pub struct ModelMetadata {
+    /// Path of the model file relative to the artifact base URL.
     #[serde(rename(deserialize = "file"))]
     pub url_path: String,
+    /// File name the model is stored as in the download directory.
     #[serde(rename(deserialize = "filename"))]
     pub file_name: String,
+    /// Hex-encoded SHA-256 checksum of the model file.
     pub checksum: String,
+    /// Size of the model file in bytes, if known.
+    ///
+    /// This is not part of the manifest. It is only populated by
+    /// [`model_metadata`](crate::model_metadata), which queries it from the server.
+    #[serde(skip)]
+    pub content_length: Option<u64>,
 }
 
 impl Manifest {
     pub fn from_json(json: &str) -> Result<Self, Error> {
-        serde_json::from_str(json).map_err(|err| Error::ManifestParse(err.to_string()))
+        serde_json::from_str(json).map_err(Error::ManifestParse)
     }
 
-    pub fn download() -> Result<Self, Error> {
-        let mut response = ureq::get(MANIFEST_URL)
-            .call()
-            .map_err(|err| Error::ManifestDownload(err.to_string()))?;
-
-        let body = response
-            .body_mut()
-            .read_to_string()
-            .map_err(|err| Error::ManifestDownload(err.to_string()))?;
+    pub fn download(options: &DownloadOptions) -> Result<Self, Error> {
+        let manifest_url = options.resolved_manifest_url();
+        let agent = options.agent().map_err(Error::ManifestDownload)?;
+        let body = with_retries(options, is_retryable, || {
+            let request = options
+                .with_timeouts(agent.get(&manifest_url))
+                .config()
+                .timeout_recv_body(options.read_timeout)
+                .build();
+            request.call()?.body_mut().read_to_string()
+        })
+        .map_err(Error::ManifestDownload)?;
 
         Self::from_json(&body)
     }
 
-    pub fn metadata_for_model(&self, id: &str, version: u32) -> Result<&ModelMetadata, Error> {
+    pub fn metadata_for_model(
+        &self,
+        id: &str,
+        version: ModelVersion,
+    ) -> Result<&ModelMetadata, Error> {
         let manifest_model = self.model_entry(id)?;
 
         manifest_model.version(version, id)
     }
 
+    /// Returns all models listed in the manifest, sorted by id.
+    pub fn models(&self) -> Vec<ModelInfo> {
+        let mut models: Vec<ModelInfo> = self
+            .models
+            .iter()
+            .map(|(id, model)| {
+                let mut versions: Vec<ModelVersion> = model
+                    .versions
+                    .keys()
+                    .filter_map(|key| Self::parse_version_key(key))
+                    .collect();
+                versions.sort_unstable();
+                ModelInfo {
+                    id: id.clone(),
+                    versions,
+                }
+            })
+            .collect();
+        models.sort_by(|a, b| a.id.cmp(&b.id));
+        models
+    }
+
     fn model_entry(&self, id: &str) -> Result<&Model, Error> {
         self.models
             .get(id)
             .ok_or_else(|| Error::ModelNotFound(id.to_string()))
     }
 
-    fn version_key(version: u32) -> String {
-        format!("v{version}")
+    fn version_key(version: ModelVersion) -> String {
+        version.to_string()
+    }
+
+    fn parse_version_key(key: &str) -> Option<ModelVersion> {
+        key.strip_prefix('v')?.parse().ok().map(ModelVersion)
     }
 }
 
 impl Model {
-    fn version(&self, version: u32, id: &str) -> Result<&ModelMetadata, Error> {
+    fn version(&self, version: ModelVersion, id: &str) -> Result<&ModelMetadata, Error> {
         self.versions
             .get(&Manifest::version_key(version))
             .ok_or_else(|| Error::IncompatibleModel {
@@ -87,7 +130,7 @@ mod tests {
         let manifest = load_manifest();
 
         let model = manifest
-            .metadata_for_model("quail-vf-2.0-l-16khz", 2)
+            .metadata_for_model("quail-vf-2.0-l-16khz", ModelVersion(2))
             .unwrap();
 
         assert_eq!(
@@ -102,5 +145,76 @@ mod tests {
             model.checksum,
             "c33a73442e2598acfd2fdc88ca127d1e8ecea0941dc93e4d3e1169246941de6e"
         );
+        assert_eq!(model.content_length, None);
+    }
+
+    #[test]
+    fn unknown_model_is_reported() {
+        let manifest = load_manifest();
+
+        let error = manifest
+            .metadata_for_model("does-not-exist", ModelVersion(2))
+            .unwrap_err();
+
+        assert!(matches!(error, Error::ModelNotFound(id) if id == "does-not-exist"));
+    }
+
+    #[test]
+    fn model_url_resolves_against_artifact_cdn() {
+        let manifest = load_manifest();
+        let model = manifest
+            .metadata_for_model("quail-vf-2.0-l-16khz", ModelVersion(2))
+            .unwrap();
+
+        let options = DownloadOptions::default();
+
+        if std::env::var_os(crate::MODEL_BASE_URL_ENV).is_none() {
+            assert_eq!(
+                options.model_url(&model.url_path),
+                "https://artifacts.ai-coustics.io/models/quail-vf-2-0-l-16khz/v2/quail_vf_2_0_l_16khz_d42jls1e_v18.aicmodel"
+            );
+        }
+    }
+
+    #[test]
+    fn models_lists_ids_and_versions() {
+        let manifest = load_manifest();
+        let models = manifest.models();
+
+        assert!(models.windows(2).all(|pair| pair[0].id < pair[1].id));
+
+        let model = models
+            .iter()
+            .find(|model| model.id == "quail-vf-2.0-l-16khz")
+            .unwrap();
+        assert!(model.supports_version(ModelVersion(2)));
+        assert!(!model.supports_version(ModelVersion(u32::MAX)));
+        assert!(model.versions.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn parse_version_key_round_trips() {
+        assert_eq!(
+            Manifest::parse_version_key(&Manifest::version_key(ModelVersion(4))),
+            Some(ModelVersion(4))
+        );
+        assert_eq!(Manifest::parse_version_key("latest"), None);
+    }
+
+    #[test]
+    fn missing_compatible_version_is_reported() {
+        let manifest = load_manifest();
+
+        let error = manifest
+            .metadata_for_model("quail-vf-2.0-l-16khz", ModelVersion(u32::MAX))
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            Error::IncompatibleModel {
+                compatible_version: ModelVersion(u32::MAX),
+                ..
+            }
+        ));
     }
 }
@@ -2,10 +2,17 @@ use std::{
     collections::HashMap,
     fs,
     path::{Path, PathBuf},
+    time::Duration,
 };
 
 const CRATE_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// Downloads the prebuilt SDK library for the current target.
+///
+/// The build script reruns, and the library is extracted again, when `checksum.txt`, this file,
+/// `AIC_SDK_ARCHIVE` or `AIC_SDK_CACHE_DIR` change. Archives in the persistent cache are keyed
+/// by their checksum and file name (target and version), so a new entry in `checksum.txt` is
+/// never served a stale archive; an archive that no longer matches its checksum is ignored.
 pub struct Downloader {
     base_url: String,
     version: String,
@@ -20,7 +27,11 @@ impl Downloader {
         let (version, artifact_sha) = read_checksums_from_file();
 
         // Validate that the current target platform exists in the checksum file
-        validate_target_exists(&artifact_sha, &version);
+        let target = std::env::var("TARGET").expect("TARGET environment variable not set");
+        let os = std::env::var("CARGO_CFG_TARGET_OS").unwrap();
+        if let Err(message) = lookup_artifact(&artifact_sha, &target, &os, &version) {
+            panic!("{message}");
+        }
 
         Downloader {
             base_url,
@@ -30,28 +41,57 @@ impl Downloader {
         }
     }
 
+    /// Returns the SHA-256 checksum of the SDK archive for the current target.
+    pub fn artifact_checksum(&self) -> &str {
+        let target = std::env::var("TARGET").unwrap();
+        let os = std::env::var("CARGO_CFG_TARGET_OS").unwrap();
+
+        lookup_artifact(&self.artifact_sha, &target, &os, &self.version)
+            .unwrap_or_else(|message| panic!("{message}"))
+            .1
+    }
+
     pub fn download(&self) -> PathBuf {
         let version = self.version.as_str();
         let target = std::env::var("TARGET").unwrap();
         let os = std::env::var("CARGO_CFG_TARGET_OS").unwrap();
 
-        let file_name = artifact_file_name(&target, &os, version);
+        let (file_name, expected_hash) = lookup_artifact(&self.artifact_sha, &target, &os, version)
+            .unwrap_or_else(|message| panic!("{message}"));
         let file_prefix = format!("aic-sdk-{target}-{version}");
-
-        let expected_hash = self
-            .artifact_sha
-            .get(&file_name)
-            .unwrap_or_else(|| panic!("Invalid artifact name {}", file_name));
         let url = format!("{}/{}/{}", self.base_url, version, file_name);
 
-        let downloaded_file = fetch_file(&url);
-        let downloaded_hash = sha256(&downloaded_file);
-
-        assert_eq!(
-            &downloaded_hash, expected_hash,
-            "SHA mismatch: {} != {}",
-            &downloaded_hash, expected_hash
-        );
+        // A pre-downloaded archive avoids network access, e.g. in CI or offline builds
+        let downloaded_file = match std::env::var_os("AIC_SDK_ARCHIVE") {
+            Some(archive_path) => read_local_archive(Path::new(&archive_path), expected_hash),
+            None => {
+                let cache_path =
+                    cache_dir().map(|dir| cached_archive_path(&dir, &file_name, expected_hash));
+
+                match cache_path
+                    .as_deref()
+                    .and_then(|path| read_cached_archive(path, expected_hash))
+                {
+                    Some(cached_file) => cached_file,
+                    None => {
+                        let downloaded_file = fetch_file(&url);
+                        let downloaded_hash = sha256(&downloaded_file);
+
+                        assert_eq!(
+                            &downloaded_hash, expected_hash,
+                            "SHA mismatch: {} != {}",
+                            &downloaded_hash, expected_hash
+                        );
+
+                        if let Some(cache_path) = &cache_path {
+                            write_cached_archive(cache_path, &downloaded_file);
+                        }
+
+                        downloaded_file
+                    }
+                }
+            }
+        };
 
         let extracted_path = self.output_path.join(&file_prefix);
 
@@ -114,22 +154,39 @@ fn read_checksums_from_file() -> (String, HashMap<String, String>) {
     (version, artifact_sha)
 }
 
-fn validate_target_exists(artifact_sha: &HashMap<String, String>, version: &str) {
-    let target = std::env::var("TARGET").expect("TARGET environment variable not set");
-
-    // Check both .tar.gz and .zip extensions
-    let file_name_tar = format!("aic-sdk-{}-{}.tar.gz", target, version);
-    let file_name_zip = format!("aic-sdk-{}-{}.zip", target, version);
-
-    if !artifact_sha.contains_key(&file_name_tar) && !artifact_sha.contains_key(&file_name_zip) {
-        panic!(
-            "Target platform not available in aic-sdk. Available platforms: {}",
-            artifact_sha
+/// Looks up the artifact name and checksum for a target.
+///
+/// Returns an error message naming the artifact that was looked up and the available
+/// platforms if the target is not supported.
+fn lookup_artifact<'a>(
+    artifact_sha: &'a HashMap<String, String>,
+    target: &str,
+    os: &str,
+    version: &str,
+) -> Result<(String, &'a str), String> {
+    let file_name = artifact_file_name(target, os, version);
+
+    match artifact_sha.get(&file_name) {
+        Some(hash) => Ok((file_name, hash.as_str())),
+        None => {
+            let mut available: Vec<&str> = artifact_sha
                 .keys()
-                .map(|k| k.as_str())
-                .collect::<Vec<_>>()
-                .join(", ")
-        );
+                .filter_map(|name| {
+                    let name = name
+                        .strip_suffix(".tar.gz")
+                        .or_else(|| name.strip_suffix(".zip"))?;
+                    name.strip_prefix("aic-sdk-")?
+                        .strip_suffix(&format!("-{version}"))
+                })
+                .collect();
+            available.sort_unstable();
+
+            Err(format!(
+                "Target platform `{target}` not available in aic-sdk (looked up artifact \
+                 `{file_name}` in checksum.txt). Available platforms: {}",
+                available.join(", ")
+            ))
+        }
     }
 }
 
@@ -168,9 +225,23 @@ fn artifact_file_name(target: &str, os: &str, version: &str) -> String {
 }
 
 fn fetch_file(source_url: &str) -> Vec<u8> {
-    ureq::get(source_url)
+    // Honor the standard proxy environment variables and fail on redirect loops
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .proxy(ureq::Proxy::try_from_env())
+        .max_redirects(10)
+        .max_redirects_will_error(true)
+        .build()
+        .into();
+
+    // Fail instead of hanging the build behind an unresponsive server or proxy
+    agent
+        .get(source_url)
+        .config()
+        .timeout_connect(Some(Duration::from_secs(10)))
+        .timeout_recv_response(Some(Duration::from_secs(30)))
+        .build()
         .call()
-        .unwrap()
+        .unwrap_or_else(|err| panic!("Failed to download {source_url}: {err}"))
         .body_mut()
         .with_config()
         .limit(400 * 1024 * 1024) // 400 MB
@@ -178,6 +249,97 @@ fn fetch_file(source_url: &str) -> Vec<u8> {
         .unwrap()
 }
 
+/// Reads an already downloaded SDK archive and verifies it against the checksum of the
+/// artifact expected for the current target.
+fn read_local_archive(archive_path: &Path, expected_hash: &str) -> Vec<u8> {
+    let archive = fs::read(archive_path).unwrap_or_else(|err| {
+        panic!(
+            "Failed to read AIC_SDK_ARCHIVE {}: {err}",
+            archive_path.display()
+        )
+    });
+    let archive_hash = sha256(&archive);
+
+    assert_eq!(
+        archive_hash,
+        expected_hash,
+        "SHA mismatch for AIC_SDK_ARCHIVE {}: {} != {}. Make sure it is the archive for the current \
+         target and aic-sdk-sys version.",
+        archive_path.display(),
+        archive_hash,
+        expected_hash
+    );
+
+    archive
+}
+
+/// Returns the directory downloaded SDK archives are cached in, so they survive `cargo clean`.
+///
+/// `AIC_SDK_CACHE_DIR` overrides the platform cache directory. Returns `None` if no cache
+/// directory can be determined, in which case the archive is not cached.
+fn cache_dir() -> Option<PathBuf> {
+    if let Some(dir) = std::env::var_os("AIC_SDK_CACHE_DIR") {
+        return Some(PathBuf::from(dir));
+    }
+
+    // The build script runs on the host, so the host's cache directory is the right one even
+    // when cross-compiling.
+    let platform_cache_dir = if cfg!(target_os = "windows") {
+        std::env::var_os("LOCALAPPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        std::env::var_os("HOME").map(|home| PathBuf::from(home).join("Library").join("Caches"))
+    } else {
+        std::env::var_os("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+    };
+
+    platform_cache_dir.map(|dir| dir.join("aic-sdk"))
+}
+
+/// Returns the cache location of an archive. The file name contains the target and version,
+/// the directory the expected checksum, so an updated `checksum.txt` never reuses a stale file.
+fn cached_archive_path(cache_dir: &Path, file_name: &str, expected_hash: &str) -> PathBuf {
+    cache_dir.join(expected_hash).join(file_name)
+}
+
+/// Reads a cached archive, returning `None` if it does not exist or fails verification.
+fn read_cached_archive(path: &Path, expected_hash: &str) -> Option<Vec<u8>> {
+    let archive = fs::read(path).ok()?;
+
+    if sha256(&archive) != expected_hash {
+        println!(
+            "cargo:warning=Ignoring cached SDK archive {} with invalid checksum",
+            path.display()
+        );
+        return None;
+    }
+
+    Some(archive)
+}
+
+/// Stores a verified archive in the cache. Failing to cache is not an error, the archive is
+/// simply downloaded again on the next clean build.
+fn write_cached_archive(path: &Path, archive: &[u8]) {
+    let Some(dir) = path.parent() else {
+        return;
+    };
+
+    // Write to a unique temporary file first, so concurrent builds never read a partial file.
+    let temp_path = dir.join(format!(".download-{}", std::process::id()));
+    let result = fs::create_dir_all(dir)
+        .and_then(|()| fs::write(&temp_path, archive))
+        .and_then(|()| fs::rename(&temp_path, path));
+
+    if let Err(err) = result {
+        let _ = fs::remove_file(&temp_path);
+        println!(
+            "cargo:warning=Failed to cache SDK archive in {}: {err}",
+            dir.display()
+        );
+    }
+}
+
 fn bytes_to_hex_str(bytes: Vec<u8>) -> String {
     let mut s = String::with_capacity(bytes.len() * 2);
     for byte in bytes {
@@ -347,6 +509,48 @@ mod tests {
         assert_eq!(name, "aic-sdk-aarch64-apple-visionos-sim-1.2.3.tar.gz");
     }
 
+    fn read_checksums() -> HashMap<String, String> {
+        read_checksums_from_file().1
+    }
+
+    #[test]
+    fn lookup_artifact_finds_all_supported_targets() {
+        const VERSION: &str = env!("CARGO_PKG_VERSION");
+        let checksums = read_checksums();
+
+        for &(target, os) in TARGETS {
+            let (file_name, hash) = lookup_artifact(&checksums, target, os, VERSION)
+                .unwrap_or_else(|message| panic!("{message}"));
+            assert_eq!(file_name, artifact_file_name(target, os, VERSION));
+            assert_eq!(hash.len(), 64, "unexpected checksum for target '{target}'");
+        }
+    }
+
+    #[test]
+    fn lookup_artifact_reports_unsupported_targets() {
+        const VERSION: &str = env!("CARGO_PKG_VERSION");
+        let checksums = read_checksums();
+
+        for (target, os) in [
+            ("aarch64-unknown-linux-musl", "linux"),
+            ("x86_64-unknown-linux-musl", "linux"),
+            ("x86_64-pc-windows-gnu", "windows"),
+            ("i686-pc-windows-msvc", "windows"),
+        ] {
+            let message = lookup_artifact(&checksums, target, os, VERSION).unwrap_err();
+            let file_name = artifact_file_name(target, os, VERSION);
+
+            assert!(
+                message.contains(&format!("`{file_name}`")),
+                "missing artifact name in: {message}"
+            );
+            assert!(
+                message.contains("x86_64-unknown-linux-gnu"),
+                "missing available platforms in: {message}"
+            );
+        }
+    }
+
     #[test]
     fn extract_version_from_filename_tar_gz() {
         assert_eq!(
@@ -379,6 +583,77 @@ mod tests {
         );
     }
 
+    fn write_temp_archive(name: &str, content: &[u8]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("aic-sdk-sys-{}-{name}", std::process::id()));
+        fs::write(&path, content).expect("Failed to write temporary archive");
+        path
+    }
+
+    #[test]
+    fn read_local_archive_accepts_matching_checksum() {
+        let content = b"archive content";
+        let path = write_temp_archive("matching.tar.gz", content);
+
+        let archive = read_local_archive(&path, &sha256(content));
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(archive, content);
+    }
+
+    #[test]
+    #[should_panic(expected = "SHA mismatch for AIC_SDK_ARCHIVE")]
+    fn read_local_archive_rejects_checksum_mismatch() {
+        let path = write_temp_archive("mismatching.tar.gz", b"archive content");
+        let expected_hash = sha256(b"other content");
+
+        let result = std::panic::catch_unwind(|| read_local_archive(&path, &expected_hash));
+        fs::remove_file(&path).unwrap();
+
+        std::panic::resume_unwind(result.unwrap_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to read AIC_SDK_ARCHIVE")]
+    fn read_local_archive_reports_missing_file() {
+        read_local_archive(Path::new("/nonexistent/aic-sdk.tar.gz"), "");
+    }
+
+    #[test]
+    fn cached_archive_path_is_keyed_by_checksum() {
+        let path = cached_archive_path(
+            Path::new("cache"),
+            "aic-sdk-x86_64-unknown-linux-gnu-1.2.3.tar.gz",
+            "abc123",
+        );
+        assert_eq!(
+            path,
+            Path::new("cache")
+                .join("abc123")
+                .join("aic-sdk-x86_64-unknown-linux-gnu-1.2.3.tar.gz")
+        );
+    }
+
+    #[test]
+    fn cached_archive_round_trips_and_is_verified() {
+        let content = b"archive content";
+        let cache_dir =
+            std::env::temp_dir().join(format!("aic-sdk-sys-cache-{}", std::process::id()));
+        let path = cached_archive_path(&cache_dir, "archive.tar.gz", &sha256(content));
+
+        assert_eq!(read_cached_archive(&path, &sha256(content)), None);
+
+        write_cached_archive(&path, content);
+        assert_eq!(
+            read_cached_archive(&path, &sha256(content)).as_deref(),
+            Some(&content[..])
+        );
+
+        // A corrupted cache entry is ignored, so the archive is downloaded again
+        assert_eq!(read_cached_archive(&path, &sha256(b"other content")), None);
+
+        fs::remove_dir_all(&cache_dir).unwrap();
+    }
+
     #[test]
     fn extract_version_from_filename_invalid() {
         assert_eq!(extract_version_from_filename("invalid"), None);
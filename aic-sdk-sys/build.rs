@@ -12,21 +12,40 @@ fn main() {
     // Rerun the build script if the header file changes
     println!("cargo:rerun-if-changed=include/aic.h");
 
+    // Printing any `rerun-if-changed` disables Cargo's default of rerunning on every package
+    // change, so all other inputs of the build script must be listed explicitly. The pinned SDK
+    // version and checksums decide which library is downloaded and linked.
+    println!("cargo:rerun-if-changed=checksum.txt");
+    println!("cargo:rerun-if-changed=build-utils/downloader.rs");
+    println!("cargo:rerun-if-changed=build-utils/runtime_linking.rs");
+
     // Rerun the build script if the AIC_LIB_PATH environment variable changes
     println!("cargo:rerun-if-env-changed=AIC_LIB_PATH");
 
     // Bindings need to be generated before early return on docs.rs
     generate_bindings();
 
+    let runtime_linking = env::var("CARGO_FEATURE_RUNTIME_LINKING").is_ok();
+    let dynamic_linking = env::var("CARGO_FEATURE_DYNAMIC_LINKING").is_ok();
+
+    // Build metadata reported by `aic_sdk_sys::build_info`
+    println!(
+        "cargo:rustc-env=AIC_SDK_ARTIFACT_VERSION={}",
+        artifact_version()
+    );
+    let linking = match (runtime_linking, dynamic_linking) {
+        (true, _) => "runtime",
+        (false, true) => "dynamic",
+        (false, false) => "static",
+    };
+    println!("cargo:rustc-env=AIC_SDK_LINKING={linking}");
+
     if env::var("DOCS_RS").is_ok() {
         // On docs.rs we don't need to link and we don't have network,
         // so we couldn't download anything if we wanted to
         return;
     }
 
-    let runtime_linking = env::var("CARGO_FEATURE_RUNTIME_LINKING").is_ok();
-    let dynamic_linking = env::var("CARGO_FEATURE_DYNAMIC_LINKING").is_ok();
-
     // `dynamic-linking` and `runtime-linking` select alternative linking strategies. Cargo
     // features are additive, so enabling both (e.g. via `--all-features`) is possible; in that
     // case runtime linking wins. Warn so the choice is not silently surprising.
@@ -132,8 +151,41 @@ fn download_lib() -> PathBuf {
 
     let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
 
+    println!("cargo:rerun-if-env-changed=AIC_SDK_ARCHIVE");
+    println!("cargo:rerun-if-env-changed=AIC_SDK_CACHE_DIR");
+    if let Some(archive_path) = env::var_os("AIC_SDK_ARCHIVE") {
+        println!(
+            "cargo:rerun-if-changed={}",
+            PathBuf::from(archive_path).display()
+        );
+    }
+
     let downloader = Downloader::new(&out_dir);
-    downloader.download()
+    let lib_path = downloader.download();
+    println!(
+        "cargo:rustc-env=AIC_SDK_ARCHIVE_SHA256={}",
+        downloader.artifact_checksum()
+    );
+    lib_path
+}
+
+/// Returns the SDK version pinned in `checksum.txt`, e.g. `0.21.2` for
+/// `aic-sdk-x86_64-unknown-linux-gnu-0.21.2.tar.gz`.
+fn artifact_version() -> String {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let checksums = std::fs::read_to_string(manifest_dir.join("checksum.txt"))
+        .expect("Failed to read checksum.txt");
+
+    checksums
+        .split_whitespace()
+        .filter_map(|field| {
+            field
+                .strip_suffix(".tar.gz")
+                .or_else(|| field.strip_suffix(".zip"))
+        })
+        .find_map(|name| name.rsplit('-').next())
+        .expect("checksum.txt does not list any SDK archive")
+        .to_string()
 }
 
 /// Maps a Cargo target triple to the triple clang should use when parsing the C header.
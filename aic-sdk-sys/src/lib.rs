@@ -31,6 +31,19 @@
 
 include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
 
+/// Metadata about the SDK library this crate was built against, recorded by the build script.
+pub mod build_info {
+    /// SDK version pinned in `checksum.txt`.
+    pub const ARTIFACT_VERSION: &str = env!("AIC_SDK_ARTIFACT_VERSION");
+
+    /// How the library is linked: `"static"`, `"dynamic"` or `"runtime"`.
+    pub const LINKING: &str = env!("AIC_SDK_LINKING");
+
+    /// SHA-256 checksum of the SDK archive the library was extracted from, or `None` if the
+    /// build script did not download it, e.g. with `AIC_LIB_PATH` or runtime linking.
+    pub const ARCHIVE_SHA256: Option<&str> = option_env!("AIC_SDK_ARCHIVE_SHA256");
+}
+
 #[cfg(not(feature = "runtime-linking"))]
 unsafe extern "C" {
     /// Sets the SDK wrapper ID.
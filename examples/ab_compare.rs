@@ -0,0 +1,144 @@
+//! Runs two models on the same input and compares their latency-aligned outputs.
+//!
+//! Each processor delays its output by [`ProcessorContext::output_delay`] samples, which
+//! differs between models. The outputs are shifted by their delays so both are sample-aligned
+//! with the input, and the RMS of their difference is printed. This is useful for regression
+//! testing model updates.
+//!
+//! ```bash
+//! cargo run --example ab_compare --features download-model -- <model-a> <model-b> [audio-file]
+//! ```
+//!
+//! Without an audio file, a synthetic noisy tone is used as input.
+//!
+//! [`ProcessorContext::output_delay`]: aic_sdk::ProcessorContext::output_delay
+
+use aic_sdk::{License, Model, Processor, ProcessorConfig};
+use std::env;
+
+const DEFAULT_MODEL_A: &str = "quail-vf-2.1-s-16khz";
+const DEFAULT_MODEL_B: &str = "rook-s-48khz";
+const SYNTHETIC_SAMPLE_RATE: u32 = 48000;
+const SYNTHETIC_SECONDS: usize = 5;
+
+/// Output of a single model, aligned with the input.
+struct AlignedOutput {
+    model_id: String,
+    delay: usize,
+    samples: Vec<f32>,
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let license = License::from_env()?;
+    let mut args = env::args().skip(1);
+    let model_a = args.next().unwrap_or_else(|| DEFAULT_MODEL_A.to_owned());
+    let model_b = args.next().unwrap_or_else(|| DEFAULT_MODEL_B.to_owned());
+
+    let (input, sample_rate) = match args.next() {
+        Some(path) => load_mono_audio(&path)?,
+        None => (synthetic_input(), SYNTHETIC_SAMPLE_RATE),
+    };
+    println!("Input: {} samples at {} Hz", input.len(), sample_rate);
+
+    let a = process_aligned(&model_a, &license, &input, sample_rate)?;
+    let b = process_aligned(&model_b, &license, &input, sample_rate)?;
+
+    for output in [&a, &b] {
+        println!(
+            "{}: output delay {} samples ({:.2} ms), RMS {:.6}",
+            output.model_id,
+            output.delay,
+            output.delay as f64 * 1000.0 / sample_rate as f64,
+            rms(&output.samples)
+        );
+    }
+
+    let difference: Vec<f32> = a
+        .samples
+        .iter()
+        .zip(&b.samples)
+        .map(|(a, b)| a - b)
+        .collect();
+    println!("Difference RMS: {:.6}", rms(&difference));
+
+    Ok(())
+}
+
+/// Enhances `input` with the given model and removes the processor's output delay.
+///
+/// The input is padded with `output_delay` samples of silence, so the returned samples have
+/// the same length as the input and sample `n` corresponds to input sample `n`.
+fn process_aligned(
+    model_id: &str,
+    license: &License,
+    input: &[f32],
+    sample_rate: u32,
+) -> Result<AlignedOutput, Box<dyn std::error::Error>> {
+    let model_path = Model::download(model_id, "target")?;
+    let model = Model::from_file(&model_path)?;
+
+    let config = ProcessorConfig {
+        sample_rate,
+        num_frames: model.optimal_num_frames(sample_rate),
+        ..ProcessorConfig::optimal(&model)
+    }
+    .with_num_channels(1);
+    let mut processor = Processor::new(&model, license)?.with_config(&config)?;
+    processor.warmup()?;
+    let delay = processor.processor_context().output_delay();
+
+    let mut samples = input.to_vec();
+    samples.resize(input.len() + delay, 0.0);
+    let padded_len = samples.len().next_multiple_of(config.num_frames);
+    samples.resize(padded_len, 0.0);
+
+    for block in samples.chunks_exact_mut(config.num_frames) {
+        processor.process_interleaved(block)?;
+    }
+
+    samples.drain(..delay);
+    samples.truncate(input.len());
+
+    Ok(AlignedOutput {
+        model_id: model.id().to_owned(),
+        delay,
+        samples,
+    })
+}
+
+fn rms(samples: &[f32]) -> f64 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let sum: f64 = samples.iter().map(|&s| f64::from(s) * f64::from(s)).sum();
+    (sum / samples.len() as f64).sqrt()
+}
+
+/// A 220 Hz tone with deterministic pseudo-random noise.
+fn synthetic_input() -> Vec<f32> {
+    let mut state = 0x2545_f491_u32;
+    (0..SYNTHETIC_SAMPLE_RATE as usize * SYNTHETIC_SECONDS)
+        .map(|i| {
+            // xorshift32
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            let noise = state as f32 / u32::MAX as f32 - 0.5;
+            let t = i as f32 / SYNTHETIC_SAMPLE_RATE as f32;
+            0.3 * (2.0 * std::f32::consts::PI * 220.0 * t).sin() + 0.1 * noise
+        })
+        .collect()
+}
+
+fn load_mono_audio(path: &str) -> Result<(Vec<f32>, u32), Box<dyn std::error::Error>> {
+    let audio: audio_file::Audio<f32> = audio_file::read(path, audio_file::ReadConfig::default())?;
+    let num_channels = (audio.num_channels as usize).max(1);
+
+    let samples = audio
+        .samples_interleaved
+        .chunks(num_channels)
+        .map(|frame| frame.iter().sum::<f32>() / num_channels as f32)
+        .collect();
+
+    Ok((samples, audio.sample_rate))
+}
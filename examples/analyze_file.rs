@@ -1,4 +1,4 @@
-use aic_sdk::{FileAnalyzer, Model};
+use aic_sdk::{FileAnalyzer, License, Model};
 use std::{
     env,
     io::{Error, ErrorKind},
@@ -16,7 +16,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         )
     })?;
 
-    let license = env::var("AIC_SDK_LICENSE").expect("AIC_SDK_LICENSE not set");
+    let license = License::from_env()?;
 
     let audio = load_mono_audio(&audio_path)?;
 
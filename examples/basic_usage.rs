@@ -1,5 +1,4 @@
-use aic_sdk::{Model, Processor, ProcessorConfig, ProcessorParameter, VadParameter};
-use std::env;
+use aic_sdk::{License, Model, Processor, ProcessorConfig, ProcessorParameter, VadParameter};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Display library version
@@ -10,7 +9,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     );
 
     // Get license key from environment variable
-    let license = env::var("AIC_SDK_LICENSE").expect("AIC_SDK_LICENSE environment variable");
+    let license = License::from_env()?;
 
     // Download the default model once and reuse the file
     // Select a model id at https://artifacts.ai-coustics.io/
@@ -66,7 +65,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Exercise the bearer-token refresh path. The license used here is not necessarily a JWT,
     // so an error is acceptable. This call exists mainly to cover the FFI signature (relevant
     // for the hand-maintained runtime-linking symbol table).
-    match proc_ctx.update_bearer_token(&license) {
+    match proc_ctx.update_bearer_token(license.as_str()) {
         Ok(()) => println!("Bearer token updated"),
         Err(e) => println!(
             "Bearer token update returned (expected for non-JWT keys): {}",
@@ -1,8 +1,6 @@
-use aic_sdk::{Model, Processor, ProcessorConfig};
+use aic_sdk::{License, Model, Processor, ProcessorConfig};
 use std::{
-    env,
     io::Write,
-    sync::Arc,
     time::{Duration, Instant},
 };
 use tokio::sync::{mpsc, watch};
@@ -29,10 +27,10 @@ struct SessionReport {
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("ai-coustics SDK version: {}", aic_sdk::get_sdk_version());
 
-    let license = env::var("AIC_SDK_LICENSE").expect("AIC_SDK_LICENSE not found");
+    let license = License::from_env()?;
 
     let model_path = Model::download(MODEL, "target")?;
-    let model = Arc::new(Model::from_file(&model_path)?);
+    let model = Model::from_file(&model_path)?;
     println!("Model loaded from {}\n", model_path.display());
 
     let config = ProcessorConfig::optimal(&model);
@@ -61,7 +59,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     handles.push(spawn_session(
         thread_id,
-        Arc::clone(&model),
+        model.clone(),
         license.clone(),
         config.clone(),
         period,
@@ -86,7 +84,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 thread_id += 1;
                 handles.push(spawn_session(
                     thread_id,
-                    Arc::clone(&model),
+                    model.clone(),
                     license.clone(),
                     config.clone(),
                     period,
@@ -197,8 +195,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 #[allow(clippy::too_many_arguments)]
 fn spawn_session(
     session_id: usize,
-    model: Arc<Model<'static>>,
-    license: String,
+    model: Model<'static>,
+    license: License,
     config: ProcessorConfig,
     period: Duration,
     safety_margin: Duration,
@@ -1,11 +1,11 @@
-use aic_sdk::{Model, Processor, include_model};
+use aic_sdk::{License, Model, Processor, embed_model};
 
-// The MODEL_PATH environment variable is set by build.rs
-static MODEL: &'static [u8] = include_model!(env!("MODEL_PATH"));
+// The model is downloaded by build.rs
+static MODEL: &'static [u8] = embed_model!("quail-vf-2.1-s-16khz");
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Get license key from environment variable
-    let license = std::env::var("AIC_SDK_LICENSE").expect("AIC_SDK_LICENSE environment variable");
+    let license = License::from_env()?;
 
     let model = Model::from_buffer(MODEL)?;
 
@@ -0,0 +1,208 @@
+use aic_sdk::{License, Model, Processor, ProcessorConfig, StreamProcessor};
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use ringbuf::{
+    HeapRb,
+    traits::{Consumer, Observer, Producer, Split},
+};
+use std::{
+    error::Error,
+    fs::File,
+    io::BufWriter,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+/// Enhances recorded audio and writes it to a WAV file.
+///
+/// The enhanced stream lags the input by the processor's output delay. The recorder drops
+/// that many frames at the start and flushes the tail out of the processor when it is
+/// finished, so the file has exactly as many frames as were recorded and lines up with them.
+struct EnhancedRecorder<'a> {
+    stream: StreamProcessor<'a>,
+    writer: hound::WavWriter<BufWriter<File>>,
+    block: Vec<f32>,
+    num_channels: usize,
+    /// Frames at the start of the enhanced stream that precede the recording
+    frames_to_skip: usize,
+    /// Frames recorded so far
+    recorded_frames: usize,
+    /// Enhanced frames written to the file so far
+    written_frames: usize,
+}
+
+impl<'a> EnhancedRecorder<'a> {
+    fn new(
+        stream: StreamProcessor<'a>,
+        writer: hound::WavWriter<BufWriter<File>>,
+        num_channels: usize,
+    ) -> Self {
+        Self {
+            block: vec![0.0; stream.block_len()],
+            frames_to_skip: stream.output_delay(),
+            stream,
+            writer,
+            num_channels,
+            recorded_frames: 0,
+            written_frames: 0,
+        }
+    }
+
+    /// Enhances recorded interleaved audio and writes all enhanced audio that is ready.
+    fn push(&mut self, input: &[f32]) -> Result<(), Box<dyn Error>> {
+        self.stream.push(input)?;
+        self.recorded_frames += input.len() / self.num_channels;
+        self.write_available()
+    }
+
+    /// Flushes the delayed tail out of the processor and finalizes the WAV file.
+    ///
+    /// Returns the number of frames written.
+    fn finish(mut self) -> Result<usize, Box<dyn Error>> {
+        // Feed silence until the last recorded frame has left the processor, i.e. for the
+        // output delay plus the frames still waiting for a full block.
+        let silence = vec![0.0f32; self.block.len()];
+        while self.written_frames < self.recorded_frames {
+            self.stream.push(&silence)?;
+            self.write_available()?;
+        }
+
+        self.writer.finalize()?;
+        Ok(self.written_frames)
+    }
+
+    fn write_available(&mut self) -> Result<(), Box<dyn Error>> {
+        while let Some(num_frames) = self.stream.pop(&mut self.block) {
+            let skipped = self.frames_to_skip.min(num_frames);
+            self.frames_to_skip -= skipped;
+
+            // Never write more frames than were recorded, the rest is the flushed silence
+            let frames = (num_frames - skipped).min(self.recorded_frames - self.written_frames);
+            let start = skipped * self.num_channels;
+            for &sample in &self.block[start..start + frames * self.num_channels] {
+                self.writer.write_sample(sample)?;
+            }
+            self.written_frames += frames;
+        }
+        Ok(())
+    }
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let output_path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "enhanced.wav".to_string());
+
+    // Get license key from environment variable
+    let license = License::from_env()?;
+
+    let model_path = Model::download("quail-vf-2.1-s-16khz", "target")?;
+    let model = Model::from_file(&model_path)?;
+
+    let host = cpal::default_host();
+    let input_device = host
+        .default_input_device()
+        .ok_or("No input device available")?;
+
+    // This example assumes that the device supports `f32` samples, which is the case for most
+    // hosts.
+    let input_config: cpal::StreamConfig = input_device.default_input_config()?.into();
+    let sample_rate = input_config.sample_rate.0;
+    let num_channels = input_config.channels as usize;
+
+    let config = ProcessorConfig {
+        sample_rate,
+        num_frames: model.optimal_num_frames(sample_rate),
+        ..ProcessorConfig::optimal(&model).with_num_channels(num_channels as u16)
+    };
+    let processor = Processor::new(&model, &license)?;
+    let stream_processor = StreamProcessor::new(processor, &config)?;
+
+    let spec = hound::WavSpec {
+        channels: config.num_channels,
+        sample_rate,
+        bits_per_sample: 32,
+        sample_format: hound::SampleFormat::Float,
+    };
+    let writer = hound::WavWriter::create(&output_path, spec)?;
+    let mut recorder = EnhancedRecorder::new(stream_processor, writer, num_channels);
+
+    // The audio callback must not block, so it only copies the recorded audio into a
+    // lock-free ring buffer holding one second of audio. Enhancing and writing the file happen
+    // on the main thread.
+    let ring = HeapRb::<f32>::new(sample_rate as usize * num_channels);
+    let (mut producer, mut consumer) = ring.split();
+    let dropped_frames = Arc::new(AtomicUsize::new(0));
+
+    let input_stream = {
+        let dropped_frames = Arc::clone(&dropped_frames);
+        input_device.build_input_stream(
+            &input_config,
+            move |data: &[f32], _: &cpal::InputCallbackInfo| {
+                // Only push whole chunks, so frames are never split up
+                if producer.vacant_len() >= data.len() {
+                    producer.push_slice(data);
+                } else {
+                    dropped_frames.fetch_add(data.len() / num_channels, Ordering::Relaxed);
+                }
+            },
+            |err| eprintln!("Input stream error: {err}"),
+            None,
+        )?
+    };
+
+    let running = Arc::new(AtomicBool::new(true));
+    {
+        let running = Arc::clone(&running);
+        ctrlc::set_handler(move || running.store(false, Ordering::Relaxed))?;
+    }
+
+    input_stream.play()?;
+    println!(
+        "Recording {num_channels} channel(s) at {sample_rate} Hz to {output_path}, press Ctrl-C to stop..."
+    );
+
+    let mut chunk = vec![0.0f32; recorder.block.len()];
+    while running.load(Ordering::Relaxed) {
+        drain_ring(&mut recorder, &mut consumer, &mut chunk, num_channels)?;
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    // Stop recording and enhance what is left in the ring buffer
+    drop(input_stream);
+    drain_ring(&mut recorder, &mut consumer, &mut chunk, num_channels)?;
+
+    let written_frames = recorder.finish()?;
+    println!(
+        "Wrote {:.2} s of enhanced audio to {output_path}",
+        written_frames as f64 / sample_rate as f64
+    );
+
+    let dropped_frames = dropped_frames.load(Ordering::Relaxed);
+    if dropped_frames > 0 {
+        eprintln!("Dropped {dropped_frames} frames because the recorder fell behind");
+    }
+
+    Ok(())
+}
+
+/// Moves all whole frames from the ring buffer into the recorder.
+fn drain_ring(
+    recorder: &mut EnhancedRecorder<'_>,
+    consumer: &mut impl Consumer<Item = f32>,
+    chunk: &mut [f32],
+    num_channels: usize,
+) -> Result<(), Box<dyn Error>> {
+    loop {
+        let len = consumer.occupied_len().min(chunk.len());
+        let len = len - len % num_channels;
+        if len == 0 {
+            return Ok(());
+        }
+
+        consumer.pop_slice(&mut chunk[..len]);
+        recorder.push(&chunk[..len])?;
+    }
+}
@@ -0,0 +1,125 @@
+use aic_sdk::{License, Model, Processor, ProcessorConfig, StreamProcessor};
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use ringbuf::{
+    HeapRb,
+    traits::{Consumer, Producer, Split},
+};
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Get license key from environment variable
+    let license = License::from_env()?;
+
+    let model_path = Model::download("quail-vf-2.1-s-16khz", "target")?;
+    let model = Model::from_file(&model_path)?;
+
+    let host = cpal::default_host();
+    let input_device = host
+        .default_input_device()
+        .ok_or("No input device available")?;
+    let output_device = host
+        .default_output_device()
+        .ok_or("No output device available")?;
+
+    // Both streams run at the input device's sample rate. This example assumes that the devices
+    // support `f32` samples, which is the case for most hosts.
+    let input_config: cpal::StreamConfig = input_device.default_input_config()?.into();
+    let output_config = cpal::StreamConfig {
+        channels: output_device.default_output_config()?.channels(),
+        sample_rate: input_config.sample_rate,
+        buffer_size: cpal::BufferSize::Default,
+    };
+    let sample_rate = input_config.sample_rate.0;
+    let input_channels = input_config.channels as usize;
+    let output_channels = output_config.channels as usize;
+
+    // Process at the device sample rate, in blocks of the model's optimal duration.
+    // The device callbacks can deliver any number of frames, the `StreamProcessor` collects them
+    // into blocks of exactly `num_frames` frames.
+    let config = ProcessorConfig {
+        sample_rate,
+        num_frames: model.optimal_num_frames(sample_rate),
+        ..ProcessorConfig::optimal(&model).with_num_channels(input_channels as u16)
+    };
+    let processor = Processor::new(&model, &license)?;
+    let mut stream_processor = StreamProcessor::new(processor, &config)?;
+    println!(
+        "Processing {} channel(s) at {} Hz in blocks of {} frames",
+        config.num_channels, config.sample_rate, config.num_frames
+    );
+
+    // The ring buffer hands the enhanced audio from the input to the output callback.
+    // It is primed with one block of silence so the output does not run dry while the input
+    // callback waits for a full block.
+    let block_len = stream_processor.block_len();
+    let ring = HeapRb::<f32>::new(8 * block_len);
+    let (mut producer, mut consumer) = ring.split();
+    producer.push_slice(&vec![0.0; block_len]);
+
+    // Printing from the audio callback would lock and allocate on the real-time thread, so it
+    // only counts failed calls and the main thread reports them.
+    let failures = Arc::new(AtomicU64::new(0));
+    let callback_failures = Arc::clone(&failures);
+
+    let mut block = vec![0.0f32; block_len];
+    let input_stream = input_device.build_input_stream(
+        &input_config,
+        move |data: &[f32], _: &cpal::InputCallbackInfo| {
+            if stream_processor.push(data).is_err() {
+                callback_failures.fetch_add(1, Ordering::Relaxed);
+                return;
+            }
+            while stream_processor.pop(&mut block).is_some() {
+                // Drop audio if the output stream falls behind
+                producer.push_slice(&block);
+            }
+        },
+        |err| eprintln!("Input stream error: {err}"),
+        None,
+    )?;
+
+    let mut frame = vec![0.0f32; input_channels];
+    let output_stream = output_device.build_output_stream(
+        &output_config,
+        move |data: &mut [f32], _: &cpal::OutputCallbackInfo| {
+            for out_frame in data.chunks_mut(output_channels) {
+                // Output silence if no enhanced audio is available yet
+                if consumer.pop_slice(&mut frame) < input_channels {
+                    frame.fill(0.0);
+                }
+                // Map the input channels onto the output channels, repeating the last input
+                // channel if the output has more channels
+                for (ch, sample) in out_frame.iter_mut().enumerate() {
+                    *sample = frame[ch.min(input_channels - 1)];
+                }
+            }
+        },
+        |err| eprintln!("Output stream error: {err}"),
+        None,
+    )?;
+
+    input_stream.play()?;
+    output_stream.play()?;
+
+    println!("Enhancing microphone input for 30 seconds...");
+    let mut reported = 0;
+    for _ in 0..30 {
+        std::thread::sleep(Duration::from_secs(1));
+        let failed = failures.load(Ordering::Relaxed);
+        if failed > reported {
+            eprintln!(
+                "Failed to process audio in {} callback(s)",
+                failed - reported
+            );
+            reported = failed;
+        }
+    }
+
+    Ok(())
+}
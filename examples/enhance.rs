@@ -0,0 +1,17 @@
+//! Enhances a buffer of mono audio with the high-level `Enhancer`.
+//!
+//! ```bash
+//! cargo run --example enhance --features download-model
+//! ```
+
+fn main() -> Result<(), aic_sdk::AicError> {
+    let license = aic_sdk::License::from_env()?;
+    let model_path = aic_sdk::Model::download("rook-s-48khz", "target")?;
+
+    let mut enhancer = aic_sdk::Enhancer::new(model_path, &license)?.with_num_channels(1)?;
+    let mut audio = vec![0.0f32; enhancer.sample_rate() as usize]; // One second of audio
+    enhancer.enhance(&mut audio)?;
+
+    println!("Enhanced {} samples", audio.len());
+    Ok(())
+}
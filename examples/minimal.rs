@@ -0,0 +1,51 @@
+//! Enhances silence with a model loaded from memory, using no optional crate features.
+//!
+//! This example doubles as a compile check for the minimal feature set: it must build with
+//! `--no-default-features` and only a linking feature, without pulling in networking or
+//! thread pools.
+//!
+//! ```bash
+//! cargo run --example minimal --no-default-features --features download-lib -- model.aicmodel
+//! ```
+
+use aic_sdk::{License, Model, Processor, ProcessorConfig};
+use std::env;
+
+/// Model buffers passed to [`Model::from_buffer`] must be 64-byte aligned.
+#[repr(C, align(64))]
+#[derive(Clone, Copy)]
+struct AlignedBlock([u8; 64]);
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let license = License::from_env()?;
+    let model_path = env::args()
+        .nth(1)
+        .expect("usage: minimal <path/to/model.aicmodel>");
+
+    // On embedded targets the model would typically be embedded with `include_model!`.
+    // Here it is read once and copied into an aligned buffer to keep the example runnable.
+    let bytes = std::fs::read(&model_path)?;
+    let mut blocks = vec![AlignedBlock([0; 64]); bytes.len().div_ceil(64)];
+    for (block, chunk) in blocks.iter_mut().zip(bytes.chunks(64)) {
+        block.0[..chunk.len()].copy_from_slice(chunk);
+    }
+    // SAFETY:
+    // - `AlignedBlock` is `repr(C)` and consists of 64 bytes without padding.
+    // - The slice covers `blocks.len() * 64 >= bytes.len()` initialized bytes.
+    let buffer =
+        unsafe { std::slice::from_raw_parts(blocks.as_ptr().cast::<u8>(), blocks.len() * 64) };
+
+    let model = Model::from_buffer(&buffer[..bytes.len()])?;
+    let config = ProcessorConfig::optimal(&model);
+    let mut processor = Processor::new(&model, &license)?.with_config(&config)?;
+
+    let mut audio = vec![0.0f32; config.num_channels as usize * config.num_frames];
+    processor.process_interleaved(&mut audio)?;
+
+    println!(
+        "Processed {} frames at {} Hz",
+        config.num_frames, config.sample_rate
+    );
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+//! Processes several independent streams concurrently, one processor per thread.
+//!
+//! The model is loaded once and shared: clones of a [`Model`] are cheap handles to the same
+//! weights, like an `Arc`. Each stream thread owns its own [`Processor`], because processing
+//! takes `&mut self`. A control thread changes the enhancement level of all streams while they
+//! are processed, through [`ProcessorContext`]s, which are `Send + Sync` and only need `&self`.
+//!
+//! ```bash
+//! cargo run --example multithread --features download-lib,download-model
+//! ```
+
+use aic_sdk::{License, Model, Processor, ProcessorConfig, ProcessorContext, ProcessorParameter};
+use std::{sync::Arc, thread, time::Duration};
+
+const MODEL: &str = "quail-vf-2.1-s-16khz";
+const NUM_STREAMS: usize = 4;
+const NUM_BLOCKS: usize = 500;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let license = License::from_env()?;
+
+    let model_path = Model::download(MODEL, "target")?;
+    let model = Model::from_file(&model_path)?;
+    let config = ProcessorConfig::optimal(&model);
+    println!("Model loaded from {}", model_path.display());
+
+    // Create the processors up front, so license or configuration errors surface here
+    let mut processors = Vec::with_capacity(NUM_STREAMS);
+    let mut contexts = Vec::with_capacity(NUM_STREAMS);
+    for _ in 0..NUM_STREAMS {
+        let processor = Processor::new(&model, &license)?.with_config(&config)?;
+        contexts.push(processor.processor_context());
+        processors.push(processor);
+    }
+    let contexts: Arc<[ProcessorContext]> = contexts.into();
+
+    let stream_threads: Vec<_> = processors
+        .into_iter()
+        .enumerate()
+        .map(|(stream, mut processor)| {
+            let num_samples = config.num_channels as usize * config.num_frames;
+            thread::spawn(move || -> Result<(), aic_sdk::AicError> {
+                let mut audio = vec![0.0f32; num_samples];
+                for block in 0..NUM_BLOCKS {
+                    // Stand-in for reading the next block of this stream
+                    for (i, sample) in audio.iter_mut().enumerate() {
+                        *sample = ((block * num_samples + i) as f32 * 0.01 * (stream + 1) as f32)
+                            .sin()
+                            * 0.1;
+                    }
+                    processor.process_interleaved(&mut audio)?;
+                }
+                println!("Stream {stream} processed {NUM_BLOCKS} blocks");
+                Ok(())
+            })
+        })
+        .collect();
+
+    // Control thread, e.g. a UI, adjusting all streams while they are processed
+    let control_contexts = Arc::clone(&contexts);
+    let control_thread = thread::spawn(move || -> Result<(), aic_sdk::AicError> {
+        for level in [1.0, 0.8, 0.6, 0.4] {
+            for context in control_contexts.iter() {
+                context.set_parameter(ProcessorParameter::EnhancementLevel, level)?;
+            }
+            println!("Enhancement level set to {level}");
+            thread::sleep(Duration::from_millis(50));
+        }
+        Ok(())
+    });
+
+    for handle in stream_threads {
+        handle.join().expect("stream thread panicked")?;
+    }
+    control_thread.join().expect("control thread panicked")?;
+
+    for (stream, context) in contexts.iter().enumerate() {
+        println!(
+            "Stream {stream}: enhancement level {}",
+            context.parameter(ProcessorParameter::EnhancementLevel)?
+        );
+    }
+
+    Ok(())
+}
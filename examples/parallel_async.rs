@@ -6,7 +6,7 @@
 // When running in parallel the total time is close to the slowest
 // single processor, which is what we verify and print.
 
-use aic_sdk::{Model, ProcessorAsync, ProcessorConfig};
+use aic_sdk::{License, Model, ProcessorAsync, ProcessorConfig};
 use std::time::Instant;
 
 const MODEL: &str = "quail-vf-2.1-l-16khz";
@@ -18,7 +18,7 @@ const ITERATIONS: usize = 50;
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("ai-coustics SDK version: {}", aic_sdk::get_sdk_version());
 
-    let license = std::env::var("AIC_SDK_LICENSE").expect("AIC_SDK_LICENSE not set");
+    let license = License::from_env()?;
 
     let model_path = Model::download(MODEL, "target")?;
     let model = Model::from_file(&model_path)?;
@@ -1,4 +1,4 @@
-use crate::{error::*, model::Model, processor::ProcessorConfig};
+use crate::{error::*, license::License, model::Model, processor::ProcessorConfig};
 
 use aic_sdk_sys::*;
 
@@ -94,14 +94,14 @@ impl From<AicAnalysisResult> for AnalysisResult {
 /// ```
 pub fn analyzer_pair<'a>(
     model: &Model<'a>,
-    license_key: &str,
+    license_key: impl Into<License>,
 ) -> Result<(Collector, Analyzer<'a>), AicError> {
     // Set the wrapper ID as soon as the user attempts to instantiate an analyzer
-    crate::set_wrapper_id();
+    crate::apply_wrapper_id();
 
     let mut collector_ptr: *mut AicCollector = ptr::null_mut();
     let mut analyzer_ptr: *mut AicAnalyzer = ptr::null_mut();
-    let c_license_key = CString::new(license_key).map_err(|_| AicError::LicenseFormatInvalid)?;
+    let c_license_key = license_key.into().to_c_string()?;
 
     // SAFETY:
     // - `collector_ptr` and `analyzer_ptr` point to stack storage for output.
@@ -610,66 +610,11 @@ unsafe impl<'a> Sync for Analyzer<'a> {}
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::{
-        fs,
-        path::{Path, PathBuf},
-        sync::{Mutex, OnceLock},
-    };
-
-    fn download_lock() -> &'static Mutex<()> {
-        static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
-        LOCK.get_or_init(|| Mutex::new(()))
-    }
-
-    fn find_existing_model(target_dir: &Path) -> Option<PathBuf> {
-        let entries = fs::read_dir(target_dir).ok()?;
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if path
-                .file_name()
-                .and_then(|n| n.to_str())
-                .map(|name| name.contains("tyto_l_16khz") && name.ends_with(".aicmodel"))
-                .unwrap_or(false)
-                && path.is_file()
-            {
-                return Some(path);
-            }
-        }
-        None
-    }
-
-    /// Downloads the default test model `tyto-l-16khz` into the crate's `target/` directory.
-    /// Returns the path to the downloaded model file.
-    fn get_tyto_l_16khz() -> Result<PathBuf, AicError> {
-        let target_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("target");
-
-        if let Some(existing) = find_existing_model(&target_dir) {
-            return Ok(existing);
-        }
-
-        let _guard = download_lock().lock().unwrap();
-        if let Some(existing) = find_existing_model(&target_dir) {
-            return Ok(existing);
-        }
-
-        if cfg!(feature = "download-model") {
-            Model::download("tyto-l-16khz", target_dir)
-        } else {
-            panic!(
-                "Model `tyto-l-16khz` not found in {} and `download-model` feature is disabled",
-                target_dir.display()
-            );
-        }
-    }
+    use crate::test_support::{get_tyto_l_16khz, license_key};
 
     fn load_test_model() -> Result<(Model<'static>, String), AicError> {
-        let license_key = std::env::var("AIC_SDK_LICENSE")
-            .expect("AIC_SDK_LICENSE environment variable must be set for tests");
-
-        let model_path = get_tyto_l_16khz()?;
-        let model = Model::from_file(&model_path)?;
-
-        Ok((model, license_key))
+        let model = Model::from_file(get_tyto_l_16khz()?)?;
+        Ok((model, license_key()))
     }
 
     fn test_analyzer_pair(
@@ -0,0 +1,157 @@
+//! Enhances a WAV file from the command line.
+//!
+//! ```bash
+//! export AIC_SDK_LICENSE=...
+//! cargo run --features cli --bin aic-enhance -- --model quail-vf-2.1-s-16khz in.wav out.wav
+//! ```
+//!
+//! Run with `--help` for all options.
+
+use aic_sdk::{Model, Processor, ProcessorConfig, ProcessorParameter, wav};
+
+use std::{error::Error, path::PathBuf, process::ExitCode};
+
+const USAGE: &str = "\
+Enhances speech in a WAV file with the ai-coustics SDK.
+
+Usage: aic-enhance [OPTIONS] --model <MODEL> <INPUT> <OUTPUT>
+
+Arguments:
+  <INPUT>   WAV file to enhance
+  <OUTPUT>  Path the enhanced WAV file is written to, in the format of the input
+
+Options:
+  -m, --model <MODEL>                  Model id, e.g. quail-vf-2.1-s-16khz, or path to an
+                                       .aicmodel file. Model ids are downloaded into the
+                                       model cache.
+  -e, --enhancement-level <LEVEL>      Enhancement level from 0.0 to 1.0 [default: 1.0]
+  -h, --help                           Print this help
+
+The license key is read from the AIC_SDK_LICENSE environment variable.";
+
+/// Parsed command line arguments.
+#[derive(Debug)]
+struct Args {
+    model: String,
+    enhancement_level: Option<f32>,
+    input: PathBuf,
+    output: PathBuf,
+}
+
+impl Args {
+    /// Parses the arguments, returns `Ok(None)` if help was requested.
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Option<Self>, String> {
+        let mut args = args.into_iter();
+        let mut model = None;
+        let mut enhancement_level = None;
+        let mut paths = Vec::new();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => return Ok(None),
+                "-m" | "--model" => {
+                    model = Some(args.next().ok_or("--model requires a value")?);
+                }
+                "-e" | "--enhancement-level" => {
+                    let value = args.next().ok_or("--enhancement-level requires a value")?;
+                    let level = value
+                        .parse()
+                        .map_err(|_| format!("invalid enhancement level `{value}`"))?;
+                    enhancement_level = Some(level);
+                }
+                _ if arg.starts_with('-') => {
+                    return Err(format!("unknown option `{arg}`"));
+                }
+                _ => paths.push(PathBuf::from(arg)),
+            }
+        }
+
+        let model = model.ok_or("--model is required")?;
+        let [input, output] = <[PathBuf; 2]>::try_from(paths)
+            .map_err(|_| "expected an input and an output file".to_string())?;
+
+        Ok(Some(Self {
+            model,
+            enhancement_level,
+            input,
+            output,
+        }))
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(err) => {
+            eprintln!("error: {err}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            let mut source = err.source();
+            while let Some(err) = source {
+                eprintln!("  caused by: {err}");
+                source = err.source();
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &Args) -> Result<(), Box<dyn Error>> {
+    let license = aic_sdk::License::from_env()?;
+
+    let model_path = if args.model.ends_with(".aicmodel") || PathBuf::from(&args.model).is_file() {
+        PathBuf::from(&args.model)
+    } else {
+        eprintln!("Downloading model {}...", args.model);
+        Model::download_cached(&args.model)?
+    };
+    let model = Model::from_file(&model_path)?;
+
+    let (spec, mut samples) = wav::read_interleaved(&args.input)?;
+    let num_channels = spec.channels as usize;
+    let num_frames = samples.len() / num_channels.max(1);
+
+    let config = ProcessorConfig {
+        sample_rate: spec.sample_rate,
+        num_channels: spec.channels,
+        num_frames: model.optimal_num_frames(spec.sample_rate),
+        allow_variable_frames: true,
+    };
+    let mut builder = Processor::builder(&model, &license).with_config(&config);
+    if let Some(level) = args.enhancement_level {
+        builder = builder.with_parameter(ProcessorParameter::EnhancementLevel, level);
+    }
+    let mut processor = builder.build()?.processor;
+    let delay = processor.try_processor_context()?.output_delay();
+
+    eprintln!(
+        "Enhancing {:.2} s of audio with {} ({num_channels} channel(s) at {} Hz)...",
+        num_frames as f64 / spec.sample_rate as f64,
+        model.id(),
+        spec.sample_rate
+    );
+    processor.for_each_block(&mut samples, |_, _| {})?;
+
+    // Flush the delayed tail out of the processor and drop the leading delay, so the output
+    // is aligned with the input and has the same length
+    for block in processor.drain() {
+        samples.extend(block?);
+    }
+    samples.drain(..(num_channels * delay).min(samples.len()));
+    samples.truncate(num_channels * num_frames);
+
+    wav::write_interleaved(&args.output, spec, &samples)?;
+    eprintln!("Wrote {}", args.output.display());
+
+    Ok(())
+}
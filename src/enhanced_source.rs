@@ -0,0 +1,226 @@
+use crate::{
+    error::AicError,
+    license::License,
+    model::Model,
+    processor::{Processor, ProcessorConfig},
+};
+
+use std::time::Duration;
+
+/// A [`rodio::Source`] that enhances the samples of another source on the fly.
+///
+/// Samples are pulled from the wrapped source in blocks of the model's optimal size at the
+/// source's sample rate, enhanced, and yielded one by one. The processor's output delay is
+/// compensated: the enhanced audio is aligned with the input and has the same length, at the
+/// cost of reading one output delay ahead of playback.
+///
+/// The channel count and sample rate of the wrapped source are read once on construction and
+/// must not change during playback.
+///
+/// If processing a block fails, the block is played unprocessed. The error is logged with the
+/// `log` feature and available through [`EnhancedSource::last_error`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use aic_sdk::{EnhancedSource, Model};
+/// # use rodio::Source;
+/// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+/// # let model = Model::from_file("/path/to/model.aicmodel")?;
+/// let file = std::io::BufReader::new(std::fs::File::open("noisy.wav").unwrap());
+/// let source = rodio::Decoder::new(file).unwrap().convert_samples::<f32>();
+/// let enhanced = EnhancedSource::new(source, &model, &license_key)?;
+/// # Ok::<(), aic_sdk::AicError>(())
+/// ```
+pub struct EnhancedSource<'a, S> {
+    source: S,
+    processor: Processor<'a>,
+    num_channels: u16,
+    sample_rate: u32,
+    /// Enhanced samples of the current block
+    block: Vec<f32>,
+    /// Index of the next sample in `block` to yield
+    position: usize,
+    /// Delayed samples at the start of the output that still have to be skipped
+    skip: usize,
+    /// Number of samples read from the wrapped source
+    samples_read: u64,
+    /// Number of enhanced samples yielded
+    samples_yielded: u64,
+    source_exhausted: bool,
+    /// Error of the last block that failed to process
+    last_error: Option<AicError>,
+}
+
+impl<'a, S> EnhancedSource<'a, S>
+where
+    S: rodio::Source<Item = f32>,
+{
+    /// Wraps `source` and enhances it with a new processor for `model`.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The source to enhance. Use [`rodio::Source::convert_samples`] to convert
+    ///   sources with other sample types.
+    /// * `model` - The model used for enhancement.
+    /// * `license_key` - License key for the ai-coustics SDK.
+    ///
+    /// # Returns
+    ///
+    /// Returns the enhanced source, or an [`AicError`] if the processor could not be created
+    /// for the source's sample rate and channel count.
+    pub fn new(
+        source: S,
+        model: &Model<'a>,
+        license_key: impl Into<License>,
+    ) -> Result<Self, AicError> {
+        let num_channels = source.channels();
+        let sample_rate = source.sample_rate();
+        let config = ProcessorConfig {
+            sample_rate,
+            num_channels,
+            num_frames: model.optimal_num_frames(sample_rate),
+            allow_variable_frames: false,
+        };
+
+        let processor = Processor::new(model, license_key)?.with_config(&config)?;
+        let delay = processor.try_processor_context()?.output_delay();
+        let block_len = num_channels as usize * config.num_frames;
+
+        Ok(Self {
+            source,
+            processor,
+            num_channels,
+            sample_rate,
+            block: vec![0.0; block_len],
+            position: block_len,
+            skip: num_channels as usize * delay,
+            samples_read: 0,
+            samples_yielded: 0,
+            source_exhausted: false,
+            last_error: None,
+        })
+    }
+
+    /// Returns a reference to the processor, e.g. to create a
+    /// [`ProcessorContext`](crate::ProcessorContext) and adjust parameters during playback.
+    pub fn processor(&self) -> &Processor<'a> {
+        &self.processor
+    }
+
+    /// Returns the error of the last block that could not be processed and was played
+    /// unprocessed, or `None` if all blocks were enhanced so far.
+    pub fn last_error(&self) -> Option<&AicError> {
+        self.last_error.as_ref()
+    }
+
+    /// Reads the next block from the wrapped source and enhances it. Once the source is
+    /// exhausted, silence is read to flush the delayed tail out of the processor.
+    fn process_next_block(&mut self) {
+        for sample in self.block.iter_mut() {
+            *sample = if self.source_exhausted {
+                0.0
+            } else if let Some(sample) = self.source.next() {
+                self.samples_read += 1;
+                sample
+            } else {
+                self.source_exhausted = true;
+                0.0
+            };
+        }
+
+        if let Err(err) = self.processor.process_interleaved(&mut self.block) {
+            #[cfg(feature = "log")]
+            log::warn!("Failed to enhance block, playing it unprocessed: {err}");
+            self.last_error = Some(err);
+        }
+        self.position = 0;
+    }
+}
+
+impl<S> Iterator for EnhancedSource<'_, S>
+where
+    S: rodio::Source<Item = f32>,
+{
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        loop {
+            if self.source_exhausted && self.samples_yielded >= self.samples_read {
+                return None;
+            }
+
+            if self.position == self.block.len() {
+                self.process_next_block();
+            }
+
+            let sample = self.block[self.position];
+            self.position += 1;
+
+            if self.skip > 0 {
+                self.skip -= 1;
+                continue;
+            }
+
+            self.samples_yielded += 1;
+            return Some(sample);
+        }
+    }
+}
+
+impl<S> rodio::Source for EnhancedSource<'_, S>
+where
+    S: rodio::Source<Item = f32>,
+{
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        self.num_channels
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        self.source.total_duration()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::load_test_model;
+    use rodio::{Source, buffer::SamplesBuffer};
+
+    #[test]
+    fn enhanced_source_keeps_format_and_length() {
+        let (model, license_key) = load_test_model().unwrap();
+
+        // Not a multiple of the block size, so the last block is padded
+        let samples: Vec<f32> = (0..2 * 10_007)
+            .map(|i| (i as f32 * 0.01).sin() * 0.5)
+            .collect();
+        let source = SamplesBuffer::new(2, 48000, samples.clone());
+
+        let mut enhanced = EnhancedSource::new(source, &model, &license_key).unwrap();
+        assert_eq!(enhanced.channels(), 2);
+        assert_eq!(enhanced.sample_rate(), 48000);
+
+        let output: Vec<f32> = enhanced.by_ref().collect();
+        assert_eq!(output.len(), samples.len());
+        assert!(output.iter().all(|sample| sample.is_finite()));
+        assert_eq!(enhanced.last_error(), None);
+    }
+
+    #[test]
+    fn empty_source_yields_nothing() {
+        let (model, license_key) = load_test_model().unwrap();
+        let source = SamplesBuffer::new(1, 48000, Vec::<f32>::new());
+
+        let mut enhanced = EnhancedSource::new(source, &model, &license_key).unwrap();
+        assert_eq!(enhanced.next(), None);
+    }
+}
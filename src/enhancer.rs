@@ -0,0 +1,266 @@
+use crate::{
+    error::AicError,
+    license::License,
+    model::Model,
+    processor::{Processor, ProcessorConfig},
+};
+
+use std::path::Path;
+
+/// Enhances audio with a single call, for the common case that needs no fine-tuning.
+///
+/// [`Enhancer`] owns a [`Model`] and a [`Processor`] and picks the model's optimal
+/// configuration. Audio must be interleaved and sampled at [`Enhancer::sample_rate`].
+/// Buffers passed to [`Enhancer::enhance`] can have any number of frames: they are
+/// processed in blocks of [`Enhancer::num_frames`] frames, with a shorter final block.
+///
+/// The number of channels is set with [`Enhancer::with_num_channels`], or inferred from the
+/// first buffer passed to [`Enhancer::enhance`]. See there for details.
+///
+/// For real-time processing, parameters, VAD or custom configurations, use [`Model`],
+/// [`Processor`] and [`ProcessorConfig`] directly, or access the processor with
+/// [`Enhancer::processor_mut`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use aic_sdk::Enhancer;
+/// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+/// let mut enhancer =
+///     Enhancer::new("/path/to/model.aicmodel", &license_key)?.with_num_channels(1)?;
+///
+/// let mut audio = vec![0.0f32; 5 * enhancer.sample_rate() as usize];
+/// enhancer.enhance(&mut audio)?;
+/// # Ok::<(), aic_sdk::AicError>(())
+/// ```
+pub struct Enhancer {
+    model: Model<'static>,
+    processor: Processor<'static>,
+    /// Optimal configuration of the model, initialized once the number of channels is known
+    config: ProcessorConfig,
+}
+
+impl Enhancer {
+    /// Loads the model at `model_path` and creates a processor for it.
+    ///
+    /// # Arguments
+    ///
+    /// * `model_path` - Filesystem path to a model file.
+    /// * `license_key` - License key for the ai-coustics SDK.
+    ///
+    /// # Returns
+    ///
+    /// Returns the enhancer, or an [`AicError`] if the model cannot be loaded or the license
+    /// key is invalid.
+    ///
+    /// # Warning
+    /// Do not call from audio processing threads as this allocates memory.
+    pub fn new<P: AsRef<Path>>(
+        model_path: P,
+        license_key: impl Into<License>,
+    ) -> Result<Self, AicError> {
+        Self::from_model(Model::from_file(model_path)?, license_key)
+    }
+
+    /// Creates an enhancer for an already loaded model.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The model used to enhance audio.
+    /// * `license_key` - License key for the ai-coustics SDK.
+    ///
+    /// # Returns
+    ///
+    /// Returns the enhancer, or an [`AicError`] if the license key is invalid.
+    ///
+    /// # Warning
+    /// Do not call from audio processing threads as this allocates memory.
+    pub fn from_model(
+        model: Model<'static>,
+        license_key: impl Into<License>,
+    ) -> Result<Self, AicError> {
+        let processor = Processor::new(&model, license_key)?;
+        let config = ProcessorConfig::optimal(&model).with_allow_variable_frames(true);
+
+        Ok(Self {
+            model,
+            processor,
+            config,
+        })
+    }
+
+    /// Sets the number of interleaved channels and initializes the processor.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_channels` - Number of audio channels (1 for mono, 2 for stereo, etc.)
+    ///
+    /// # Returns
+    ///
+    /// Returns the enhancer, or an [`AicError`] if the number of channels is not supported.
+    ///
+    /// # Warning
+    /// Do not call from audio processing threads as this allocates memory.
+    pub fn with_num_channels(mut self, num_channels: u16) -> Result<Self, AicError> {
+        self.initialize(num_channels)?;
+        Ok(self)
+    }
+
+    /// Enhances interleaved audio in place.
+    ///
+    /// If the number of channels was not set with [`Enhancer::with_num_channels`], it is
+    /// inferred from the first buffer, which must then contain exactly one block:
+    /// [`Enhancer::num_frames`] frames per channel. For example, a first buffer of
+    /// `2 * num_frames` samples sets up stereo processing. Later buffers can have any number
+    /// of frames.
+    ///
+    /// Like every processor, the enhancer delays its output by
+    /// [`Enhancer::output_delay`] frames. State is kept between calls, so consecutive buffers
+    /// are treated as one continuous stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `audio` - Interleaved audio at [`Enhancer::sample_rate`].
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success or an [`AicError`] if processing fails.
+    /// Returns [`AicError::AudioConfigMismatch`] if `audio` does not contain whole frames, or
+    /// if the number of channels cannot be inferred from the first buffer.
+    ///
+    /// # Warning
+    /// Do not call from audio processing threads as this allocates memory.
+    pub fn enhance(&mut self, audio: &mut [f32]) -> Result<(), AicError> {
+        if self.processor.config().is_none() {
+            self.initialize(infer_num_channels(audio.len(), self.config.num_frames)?)?;
+        }
+
+        self.processor.for_each_block(audio, |_, _| {})?;
+        Ok(())
+    }
+
+    fn initialize(&mut self, num_channels: u16) -> Result<(), AicError> {
+        self.config.num_channels = num_channels;
+        self.processor.reinitialize(&self.config)
+    }
+
+    /// Returns the sample rate in Hz that audio must be provided at.
+    pub fn sample_rate(&self) -> u32 {
+        self.config.sample_rate
+    }
+
+    /// Returns the number of frames per channel in a processing block.
+    pub fn num_frames(&self) -> usize {
+        self.config.num_frames
+    }
+
+    /// Returns the number of channels, or `None` if it is not known yet.
+    pub fn num_channels(&self) -> Option<u16> {
+        self.processor.num_channels()
+    }
+
+    /// Returns the delay of the enhanced audio in frames, or `None` before the number of
+    /// channels is known.
+    pub fn output_delay(&self) -> Option<usize> {
+        self.processor
+            .config()
+            .map(|_| self.processor.processor_context().output_delay())
+    }
+
+    /// Returns the model used by this enhancer.
+    pub fn model(&self) -> &Model<'static> {
+        &self.model
+    }
+
+    /// Returns the underlying processor, e.g. to read its context or VAD.
+    pub fn processor(&self) -> &Processor<'static> {
+        &self.processor
+    }
+
+    /// Returns the underlying processor mutably, e.g. to change its parameters.
+    pub fn processor_mut(&mut self) -> &mut Processor<'static> {
+        &mut self.processor
+    }
+}
+
+/// Returns the number of channels of a buffer holding exactly one block of `num_frames` frames.
+fn infer_num_channels(len: usize, num_frames: usize) -> Result<u16, AicError> {
+    if num_frames == 0 || len == 0 || !len.is_multiple_of(num_frames) {
+        return Err(AicError::AudioConfigMismatch);
+    }
+
+    u16::try_from(len / num_frames).map_err(|_| AicError::AudioConfigMismatch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{get_rook_s_48khz, license_key};
+
+    fn test_enhancer() -> Enhancer {
+        Enhancer::new(get_rook_s_48khz().unwrap(), license_key()).unwrap()
+    }
+
+    #[test]
+    fn num_channels_are_inferred_from_one_block() {
+        assert_eq!(infer_num_channels(480, 480), Ok(1));
+        assert_eq!(infer_num_channels(960, 480), Ok(2));
+        assert_eq!(
+            infer_num_channels(500, 480),
+            Err(AicError::AudioConfigMismatch)
+        );
+        assert_eq!(
+            infer_num_channels(0, 480),
+            Err(AicError::AudioConfigMismatch)
+        );
+        assert_eq!(
+            infer_num_channels(480, 0),
+            Err(AicError::AudioConfigMismatch)
+        );
+    }
+
+    #[test]
+    fn enhancer_uses_optimal_config() {
+        let enhancer = test_enhancer();
+        let optimal = ProcessorConfig::optimal(enhancer.model());
+
+        assert_eq!(enhancer.sample_rate(), optimal.sample_rate);
+        assert_eq!(enhancer.num_frames(), optimal.num_frames);
+        assert_eq!(enhancer.num_channels(), None);
+        assert_eq!(enhancer.output_delay(), None);
+    }
+
+    #[test]
+    fn first_buffer_sets_num_channels() {
+        let mut enhancer = test_enhancer();
+
+        let mut audio = vec![0.0f32; 2 * enhancer.num_frames()];
+        enhancer.enhance(&mut audio).unwrap();
+        assert_eq!(enhancer.num_channels(), Some(2));
+        assert!(enhancer.output_delay().is_some());
+
+        // Later buffers can have any number of frames
+        let mut audio = vec![0.0f32; 2 * (3 * enhancer.num_frames() + 7)];
+        enhancer.enhance(&mut audio).unwrap();
+    }
+
+    #[test]
+    fn explicit_num_channels_allow_any_first_buffer() {
+        let mut enhancer = test_enhancer().with_num_channels(1).unwrap();
+        assert_eq!(enhancer.num_channels(), Some(1));
+
+        let mut audio = vec![0.0f32; enhancer.sample_rate() as usize];
+        enhancer.enhance(&mut audio).unwrap();
+    }
+
+    #[test]
+    fn partial_frames_are_rejected() {
+        let mut enhancer = test_enhancer().with_num_channels(2).unwrap();
+
+        let mut audio = vec![0.0f32; 2 * enhancer.num_frames() + 1];
+        assert_eq!(
+            enhancer.enhance(&mut audio),
+            Err(AicError::AudioConfigMismatch)
+        );
+    }
+}
@@ -2,7 +2,21 @@ use thiserror::Error;
 
 use aic_sdk_sys::AicErrorCode::{self, *};
 
+use std::{fmt, path::PathBuf, sync::Arc};
+
 /// Error type for AIC SDK operations.
+///
+/// # Recoverability
+///
+/// Most errors are terminal for the operation that returned them and require a change in the
+/// input, configuration or environment before retrying makes sense:
+///
+/// - License errors ([`AicError::is_license_error`]) require a valid license key.
+/// - Audio configuration and parameter errors require different arguments.
+/// - Model errors require a different model file.
+///
+/// Errors for which [`AicError::is_transient`] returns `true` may succeed when the same
+/// operation is retried later.
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
 pub enum AicError {
     #[error(
@@ -13,6 +27,13 @@ pub enum AicError {
         "Processor must be initialized before calling this operation. Call `Processor::initialize` first."
     )]
     ProcessorNotInitialized,
+    /// Returned by [`Processor::initialize`](crate::Processor::initialize) if the processor
+    /// was already initialized. Use [`Processor::reinitialize`](crate::Processor::reinitialize)
+    /// to apply a new configuration.
+    #[error(
+        "Processor is already initialized. Call `Processor::reinitialize` to apply a new configuration."
+    )]
+    AlreadyInitialized,
     #[error(
         "Audio configuration (samplerate, num_channels, num_frames) is not supported by the model"
     )]
@@ -33,36 +54,125 @@ pub enum AicError {
     LicenseVersionUnsupported,
     #[error("License key has expired. Renew your license to continue.")]
     LicenseExpired,
+    /// Returned by [`License::from_env`](crate::License::from_env) if the
+    /// `AIC_SDK_LICENSE` environment variable is not set.
+    #[error("No license key provided. Set the `AIC_SDK_LICENSE` environment variable.")]
+    LicenseNotSet,
     #[error(
         "Updating the token is only supported when both the original and new keys are JWT-form licenses."
     )]
     TokenUpdateUnsupported,
     #[error("The model file is invalid or corrupted. Verify the file is correct.")]
     ModelInvalid,
-    #[error("The model file version is not compatible with this SDK version.")]
-    ModelVersionUnsupported,
+    /// The model file version does not match
+    /// [`get_compatible_model_version`](crate::get_compatible_model_version). Model files embed
+    /// their version, which the SDK checks when the [`Model`](crate::Model) is loaded.
+    ///
+    /// Holds the compatible model version, recorded when the error is returned by the SDK.
+    #[error(
+        "The model file version is not compatible with this SDK version. Use a model file with version {0}."
+    )]
+    ModelVersionUnsupported(u32),
     #[error("The model type is not supported by this operation.")]
     ModelTypeUnsupported,
     #[error("The path to the model file is invalid")]
     ModelFilePathInvalid,
+    /// Returned by [`Model::from_file`](crate::Model::from_file) if no file exists at the
+    /// given path. Checked before the model is handed to the SDK.
+    #[error("Model file not found at {}", .0.display())]
+    ModelFileNotFound(PathBuf),
     #[error(
         "The model file cannot be opened due to a filesystem error. Verify that the file exists."
     )]
     FileSystemError,
     #[error("The model data is not aligned to 64 bytes.")]
     ModelDataUnaligned,
-    #[error("Model download error: {0}")]
-    ModelDownload(String),
+    #[error("Model download error")]
+    ModelDownload(#[source] ModelDownloadError),
     #[error("Unknown error code: {0}")]
     Unknown(AicErrorCode::Type),
 }
 
+impl AicError {
+    /// Returns `true` if the error is caused by the license key.
+    ///
+    /// This covers [`AicError::LicenseFormatInvalid`], [`AicError::LicenseVersionUnsupported`],
+    /// [`AicError::LicenseExpired`] and [`AicError::LicenseNotSet`]. None of them are resolved
+    /// by retrying; a valid license key is required.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// match Processor::new(&model, &license_key) {
+    ///     Ok(processor) => { /* ... */ }
+    ///     Err(err) if err.is_license_error() => eprintln!("Please check your license: {err}"),
+    ///     Err(err) => return Err(err),
+    /// }
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn is_license_error(&self) -> bool {
+        matches!(
+            self,
+            AicError::LicenseFormatInvalid
+                | AicError::LicenseVersionUnsupported
+                | AicError::LicenseExpired
+                | AicError::LicenseNotSet
+        )
+    }
+
+    /// Returns `true` if the operation may succeed when retried later.
+    ///
+    /// Currently only [`AicError::ModelDownload`] errors caused by network or server problems
+    /// are considered transient. Missing models, checksum mismatches and filesystem errors are
+    /// terminal.
+    pub fn is_transient(&self) -> bool {
+        matches!(self, AicError::ModelDownload(err) if err.is_transient())
+    }
+
+    /// Returns the error code reported by the C library, if the error originates from it.
+    ///
+    /// Returns `None` for errors raised by the Rust wrapper itself, such as
+    /// [`AicError::AlreadyInitialized`], [`AicError::LicenseNotSet`],
+    /// [`AicError::ModelFileNotFound`] or [`AicError::ModelDownload`]. Include this code when
+    /// reporting issues.
+    pub fn raw_code(&self) -> Option<i32> {
+        let code = match self {
+            AicError::ParameterOutOfRange => AIC_ERROR_CODE_PARAMETER_OUT_OF_RANGE,
+            AicError::ProcessorNotInitialized => AIC_ERROR_CODE_PROCESSOR_NOT_INITIALIZED,
+            AicError::AudioConfigUnsupported => AIC_ERROR_CODE_AUDIO_CONFIG_UNSUPPORTED,
+            AicError::AudioConfigMismatch => AIC_ERROR_CODE_AUDIO_CONFIG_MISMATCH,
+            AicError::EnhancementNotAllowed => AIC_ERROR_CODE_ENHANCEMENT_NOT_ALLOWED,
+            AicError::Internal => AIC_ERROR_CODE_INTERNAL_ERROR,
+            AicError::LicenseFormatInvalid => AIC_ERROR_CODE_LICENSE_FORMAT_INVALID,
+            AicError::LicenseVersionUnsupported => AIC_ERROR_CODE_LICENSE_VERSION_UNSUPPORTED,
+            AicError::LicenseExpired => AIC_ERROR_CODE_LICENSE_EXPIRED,
+            AicError::TokenUpdateUnsupported => AIC_ERROR_CODE_TOKEN_UPDATE_UNSUPPORTED,
+            AicError::ModelInvalid => AIC_ERROR_CODE_MODEL_INVALID,
+            AicError::ModelVersionUnsupported(_) => AIC_ERROR_CODE_MODEL_VERSION_UNSUPPORTED,
+            AicError::ModelTypeUnsupported => AIC_ERROR_CODE_MODEL_TYPE_UNSUPPORTED,
+            AicError::ModelFilePathInvalid => AIC_ERROR_CODE_MODEL_FILE_PATH_INVALID,
+            AicError::FileSystemError => AIC_ERROR_CODE_FILE_SYSTEM_ERROR,
+            AicError::ModelDataUnaligned => AIC_ERROR_CODE_MODEL_DATA_UNALIGNED,
+            AicError::AlreadyInitialized
+            | AicError::LicenseNotSet
+            | AicError::ModelFileNotFound(_)
+            | AicError::ModelDownload(_) => return None,
+            AicError::Unknown(code) => *code,
+        };
+        Some(code as i32)
+    }
+}
+
 impl From<AicErrorCode::Type> for AicError {
     fn from(error_code: AicErrorCode::Type) -> Self {
         match error_code {
             AIC_ERROR_CODE_NULL_POINTER => {
-                // This should never happen in our Rust wrapper, but if it does,
-                // it indicates a serious bug in our wrapper logic
+                // The safe API never hands null pointers to the C library, so this code can only
+                // be caused by a bug in the wrapper. Continuing could mean operating on an
+                // invalid handle, so we fail loudly instead of returning an error.
                 panic!(
                     "Unexpected null pointer error from C library - this is a bug in the Rust wrapper"
                 );
@@ -78,7 +188,11 @@ impl From<AicErrorCode::Type> for AicError {
             AIC_ERROR_CODE_LICENSE_EXPIRED => AicError::LicenseExpired,
             AIC_ERROR_CODE_TOKEN_UPDATE_UNSUPPORTED => AicError::TokenUpdateUnsupported,
             AIC_ERROR_CODE_MODEL_INVALID => AicError::ModelInvalid,
-            AIC_ERROR_CODE_MODEL_VERSION_UNSUPPORTED => AicError::ModelVersionUnsupported,
+            AIC_ERROR_CODE_MODEL_VERSION_UNSUPPORTED => {
+                // Queried here rather than when formatting, so displaying the error never calls
+                // into the C library. The SDK just returned this code, so the library is loaded.
+                AicError::ModelVersionUnsupported(crate::get_compatible_model_version())
+            }
             AIC_ERROR_CODE_MODEL_TYPE_UNSUPPORTED => AicError::ModelTypeUnsupported,
             AIC_ERROR_CODE_MODEL_FILE_PATH_INVALID => AicError::ModelFilePathInvalid,
             AIC_ERROR_CODE_FILE_SYSTEM_ERROR => AicError::FileSystemError,
@@ -88,6 +202,96 @@ impl From<AicErrorCode::Type> for AicError {
     }
 }
 
+/// Error that occurred while downloading a model.
+///
+/// The underlying error is available through [`std::error::Error::source`] and
+/// [`ModelDownloadError::downcast_ref`]. Errors returned by [`Model::download`](crate::Model)
+/// wrap an `aic_model_downloader::Error`.
+#[derive(Clone)]
+pub struct ModelDownloadError {
+    error: Arc<dyn std::error::Error + Send + Sync>,
+    transient: bool,
+}
+
+impl ModelDownloadError {
+    #[cfg_attr(not(feature = "download-model"), allow(dead_code))]
+    pub(crate) fn new(
+        error: impl std::error::Error + Send + Sync + 'static,
+        transient: bool,
+    ) -> Self {
+        Self {
+            error: Arc::new(error),
+            transient,
+        }
+    }
+
+    /// Returns a reference to the underlying error if it is of type `E`.
+    pub fn downcast_ref<E: std::error::Error + 'static>(&self) -> Option<&E> {
+        self.error.downcast_ref()
+    }
+
+    /// Returns `true` if the download may succeed when retried later.
+    pub fn is_transient(&self) -> bool {
+        self.transient
+    }
+}
+
+impl fmt::Debug for ModelDownloadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.error, f)
+    }
+}
+
+impl fmt::Display for ModelDownloadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+impl std::error::Error for ModelDownloadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+// The underlying errors do not implement `PartialEq`, so errors are compared by their message.
+impl PartialEq for ModelDownloadError {
+    fn eq(&self, other: &Self) -> bool {
+        self.transient == other.transient && self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for ModelDownloadError {}
+
+#[cfg(feature = "download-model")]
+impl From<aic_model_downloader::Error> for AicError {
+    fn from(error: aic_model_downloader::Error) -> Self {
+        let transient = error.is_transient();
+        AicError::ModelDownload(ModelDownloadError::new(error, transient))
+    }
+}
+
+/// Error returned when parsing a [`ProcessorParameter`](crate::ProcessorParameter) or
+/// [`VadParameter`](crate::VadParameter) from an unknown name.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("Unknown parameter name `{name}`")]
+pub struct ParseParameterError {
+    name: String,
+}
+
+impl ParseParameterError {
+    pub(crate) fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+        }
+    }
+
+    /// Returns the name that could not be parsed.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
 /// Helper function to convert C error codes into Result.
 pub(crate) fn handle_error(error_code: AicErrorCode::Type) -> Result<(), AicError> {
     match error_code {
@@ -99,3 +303,116 @@ pub(crate) fn handle_error(error_code: AicErrorCode::Type) -> Result<(), AicErro
 pub(crate) fn assert_success(error_code: AicErrorCode::Type, message: &str) {
     assert_eq!(error_code, AIC_ERROR_CODE_SUCCESS, "{}", message);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+
+    #[test]
+    fn license_errors_are_classified() {
+        assert!(AicError::LicenseFormatInvalid.is_license_error());
+        assert!(AicError::LicenseVersionUnsupported.is_license_error());
+        assert!(AicError::LicenseExpired.is_license_error());
+        assert!(AicError::LicenseNotSet.is_license_error());
+
+        assert!(!AicError::AudioConfigMismatch.is_license_error());
+        assert!(!AicError::ModelInvalid.is_license_error());
+    }
+
+    #[test]
+    fn only_download_errors_are_transient() {
+        let connection_reset = io::Error::from(io::ErrorKind::ConnectionReset);
+        assert!(
+            AicError::ModelDownload(ModelDownloadError::new(connection_reset, true)).is_transient()
+        );
+        let not_found = io::Error::from(io::ErrorKind::NotFound);
+        assert!(!AicError::ModelDownload(ModelDownloadError::new(not_found, false)).is_transient());
+
+        assert!(!AicError::LicenseExpired.is_transient());
+        assert!(!AicError::Internal.is_transient());
+        assert!(!AicError::AudioConfigUnsupported.is_transient());
+    }
+
+    #[test]
+    fn known_codes_round_trip() {
+        let codes = [
+            AIC_ERROR_CODE_PARAMETER_OUT_OF_RANGE,
+            AIC_ERROR_CODE_PROCESSOR_NOT_INITIALIZED,
+            AIC_ERROR_CODE_AUDIO_CONFIG_UNSUPPORTED,
+            AIC_ERROR_CODE_AUDIO_CONFIG_MISMATCH,
+            AIC_ERROR_CODE_ENHANCEMENT_NOT_ALLOWED,
+            AIC_ERROR_CODE_INTERNAL_ERROR,
+            AIC_ERROR_CODE_LICENSE_FORMAT_INVALID,
+            AIC_ERROR_CODE_LICENSE_VERSION_UNSUPPORTED,
+            AIC_ERROR_CODE_LICENSE_EXPIRED,
+            AIC_ERROR_CODE_TOKEN_UPDATE_UNSUPPORTED,
+            AIC_ERROR_CODE_MODEL_INVALID,
+            AIC_ERROR_CODE_MODEL_VERSION_UNSUPPORTED,
+            AIC_ERROR_CODE_MODEL_FILE_PATH_INVALID,
+            AIC_ERROR_CODE_FILE_SYSTEM_ERROR,
+            AIC_ERROR_CODE_MODEL_DATA_UNALIGNED,
+            AIC_ERROR_CODE_MODEL_TYPE_UNSUPPORTED,
+        ];
+
+        for code in codes {
+            let error = AicError::from(code);
+            assert!(!matches!(error, AicError::Unknown(_)), "{code} is unmapped");
+            assert_eq!(error.raw_code(), Some(code as i32));
+        }
+    }
+
+    #[test]
+    fn model_version_error_names_the_compatible_version() {
+        let version = crate::get_compatible_model_version();
+        let error = AicError::from(AIC_ERROR_CODE_MODEL_VERSION_UNSUPPORTED);
+        assert_eq!(error, AicError::ModelVersionUnsupported(version));
+
+        let message = error.to_string();
+
+        assert!(
+            message.contains(&format!("version {version}.")),
+            "{message}"
+        );
+    }
+
+    #[test]
+    fn unknown_codes_are_preserved() {
+        let code: AicErrorCode::Type = 999;
+        let error = AicError::from(code);
+        assert_eq!(error, AicError::Unknown(999));
+        assert_eq!(error.raw_code(), Some(999));
+    }
+
+    #[test]
+    fn wrapper_errors_have_no_raw_code() {
+        let error = ModelDownloadError::new(io::Error::from(io::ErrorKind::TimedOut), true);
+        assert_eq!(AicError::ModelDownload(error).raw_code(), None);
+    }
+
+    #[cfg(feature = "download-model")]
+    #[test]
+    fn download_errors_keep_their_source() {
+        use std::error::Error as _;
+
+        let error = AicError::from(aic_model_downloader::Error::Io(io::Error::other(
+            "disk full",
+        )));
+
+        assert_eq!(error.to_string(), "Model download error");
+        let source = error.source().unwrap();
+        assert_eq!(source.to_string(), "I/O error");
+        assert_eq!(source.source().unwrap().to_string(), "disk full");
+        assert!(matches!(
+            &error,
+            AicError::ModelDownload(err)
+                if matches!(err.downcast_ref(), Some(aic_model_downloader::Error::Io(_)))
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "null pointer")]
+    fn null_pointer_code_panics() {
+        let _ = AicError::from(AIC_ERROR_CODE_NULL_POINTER);
+    }
+}
@@ -0,0 +1,198 @@
+//! Offline enhancement of audio files.
+//!
+//! [`enhance_file`] decodes an audio file with [symphonia](https://docs.rs/symphonia),
+//! enhances it and writes the result as a 32-bit float WAV file with
+//! [`wav::write_interleaved`](crate::wav::write_interleaved). Supported input formats include
+//! WAV, FLAC, MP3, Ogg Vorbis and MKV.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use aic_sdk::Model;
+//! # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+//! # let model = Model::from_file("/path/to/model.aicmodel")?;
+//! aic_sdk::file::enhance_file("noisy.mp3", "enhanced.wav", &license_key, &model)?;
+//! # Ok::<(), aic_sdk::file::FileError>(())
+//! ```
+
+use crate::{
+    error::AicError,
+    license::License,
+    model::Model,
+    processor::{Processor, ProcessorConfig},
+    wav::{self, SampleFormat, WavSpec},
+};
+
+use std::{fs::File, io, path::Path};
+
+use symphonia::core::{
+    audio::SampleBuffer,
+    codecs::{CODEC_TYPE_NULL, DecoderOptions},
+    errors::Error as SymphoniaError,
+    formats::FormatOptions,
+    io::MediaSourceStream,
+    meta::MetadataOptions,
+    probe::Hint,
+};
+use thiserror::Error;
+
+/// Error type for [`enhance_file`].
+#[derive(Debug, Error)]
+pub enum FileError {
+    #[error("Failed to open the input file")]
+    Io(#[from] io::Error),
+    #[error("Failed to decode the input file")]
+    Decode(#[from] SymphoniaError),
+    #[error("The input file does not contain an audio track")]
+    NoAudioTrack,
+    #[error("Failed to write the output file")]
+    Encode(#[from] crate::wav::WavError),
+    #[error(transparent)]
+    Enhancement(#[from] AicError),
+}
+
+/// Decoded interleaved audio.
+struct DecodedAudio {
+    samples: Vec<f32>,
+    sample_rate: u32,
+    num_channels: u16,
+}
+
+/// Enhances an audio file and writes the result as a WAV file.
+///
+/// The audio is enhanced at the sample rate of the input file and keeps its channel count.
+/// The processor's output delay is compensated, so the output is aligned with the input and
+/// has the same length.
+///
+/// # Arguments
+///
+/// * `input_path` - Path to the audio file to enhance.
+/// * `output_path` - Path the enhanced WAV file is written to. Existing files are overwritten.
+/// * `license_key` - License key for the ai-coustics SDK.
+/// * `model` - The model used for enhancement.
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success or a [`FileError`] if decoding, enhancement or encoding fails.
+/// Returns [`FileError::Enhancement`] with [`AicError::AudioConfigUnsupported`] if the sample
+/// rate of the input file is not supported.
+pub fn enhance_file<P: AsRef<Path>, Q: AsRef<Path>>(
+    input_path: P,
+    output_path: Q,
+    license_key: impl Into<License>,
+    model: &Model,
+) -> Result<(), FileError> {
+    let mut audio = decode(input_path.as_ref())?;
+    enhance(&mut audio, &license_key.into(), model)?;
+    encode(&audio, output_path.as_ref())
+}
+
+fn decode(path: &Path) -> Result<DecodedAudio, FileError> {
+    let source = MediaSourceStream::new(Box::new(File::open(path)?), Default::default());
+
+    let mut hint = Hint::new();
+    if let Some(extension) = path.extension().and_then(|ext| ext.to_str()) {
+        hint.with_extension(extension);
+    }
+
+    let mut format = symphonia::default::get_probe()
+        .format(
+            &hint,
+            source,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )?
+        .format;
+
+    let track = format
+        .tracks()
+        .iter()
+        .find(|track| track.codec_params.codec != CODEC_TYPE_NULL)
+        .ok_or(FileError::NoAudioTrack)?;
+    let track_id = track.id;
+    let mut decoder =
+        symphonia::default::get_codecs().make(&track.codec_params, &DecoderOptions::default())?;
+
+    let mut audio = DecodedAudio {
+        samples: Vec::new(),
+        sample_rate: track.codec_params.sample_rate.unwrap_or(0),
+        num_channels: track
+            .codec_params
+            .channels
+            .map_or(0, |channels| channels.count() as u16),
+    };
+
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            // Symphonia signals the end of the stream with an `UnexpectedEof` error
+            Err(SymphoniaError::IoError(err)) if err.kind() == io::ErrorKind::UnexpectedEof => {
+                break;
+            }
+            Err(err) => return Err(err.into()),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+
+        let decoded = match decoder.decode(&packet) {
+            Ok(decoded) => decoded,
+            // Skip corrupted packets instead of failing the whole file
+            Err(SymphoniaError::DecodeError(_)) => continue,
+            Err(err) => return Err(err.into()),
+        };
+
+        let spec = *decoded.spec();
+        audio.sample_rate = spec.rate;
+        audio.num_channels = spec.channels.count() as u16;
+
+        let mut buffer = SampleBuffer::<f32>::new(decoded.capacity() as u64, spec);
+        buffer.copy_interleaved_ref(decoded);
+        audio.samples.extend_from_slice(buffer.samples());
+    }
+
+    if audio.num_channels == 0 {
+        return Err(FileError::NoAudioTrack);
+    }
+
+    Ok(audio)
+}
+
+fn enhance(audio: &mut DecodedAudio, license_key: &License, model: &Model) -> Result<(), AicError> {
+    let num_channels = audio.num_channels as usize;
+    let config = ProcessorConfig {
+        sample_rate: audio.sample_rate,
+        num_channels: audio.num_channels,
+        num_frames: model.optimal_num_frames(audio.sample_rate),
+        allow_variable_frames: false,
+    };
+    let mut processor = Processor::new(model, license_key)?.with_config(&config)?;
+    let delay = processor.try_processor_context()?.output_delay();
+
+    // Append silence to flush the delayed tail out of the processor, then pad to whole blocks.
+    let num_frames = audio.samples.len() / num_channels;
+    let block_len = num_channels * config.num_frames;
+    let padded_len = (num_channels * (num_frames + delay)).div_ceil(block_len) * block_len;
+    audio.samples.resize(padded_len, 0.0);
+
+    for block in audio.samples.chunks_exact_mut(block_len) {
+        processor.process_interleaved(block)?;
+    }
+
+    // Drop the leading delay so the output is aligned with the input.
+    audio.samples.drain(..num_channels * delay);
+    audio.samples.truncate(num_channels * num_frames);
+
+    Ok(())
+}
+
+fn encode(audio: &DecodedAudio, path: &Path) -> Result<(), FileError> {
+    let spec = WavSpec {
+        channels: audio.num_channels,
+        sample_rate: audio.sample_rate,
+        bits_per_sample: 32,
+        sample_format: SampleFormat::Float,
+    };
+
+    Ok(wav::write_interleaved(path, spec, &audio.samples)?)
+}
@@ -1,4 +1,6 @@
-use crate::{AicError, AnalysisResult, Analyzer, Collector, Model, ProcessorConfig, analyzer_pair};
+use crate::{
+    AicError, AnalysisResult, Analyzer, Collector, License, Model, ProcessorConfig, analyzer_pair,
+};
 
 /// Analyzes complete mono audio buffers.
 ///
@@ -49,7 +51,10 @@ impl<'model, 'a> FileAnalyzer<'model, 'a> {
     /// let results = analyzer.analyze(&audio, sample_rate, None)?;
     /// # Ok::<(), aic_sdk::AicError>(())
     /// ```
-    pub fn new(model: &'model Model<'a>, license_key: &str) -> Result<Self, AicError> {
+    pub fn new(
+        model: &'model Model<'a>,
+        license_key: impl Into<License>,
+    ) -> Result<Self, AicError> {
         let (collector, analyzer) = analyzer_pair(model, license_key)?;
 
         Ok(Self {
@@ -216,64 +221,11 @@ impl<'model, 'a> FileAnalyzer<'model, 'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::{
-        fs,
-        path::{Path, PathBuf},
-        sync::{Mutex, OnceLock},
-    };
-
-    fn download_lock() -> &'static Mutex<()> {
-        static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
-        LOCK.get_or_init(|| Mutex::new(()))
-    }
-
-    fn find_existing_model(target_dir: &Path) -> Option<PathBuf> {
-        let entries = fs::read_dir(target_dir).ok()?;
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if path
-                .file_name()
-                .and_then(|n| n.to_str())
-                .map(|name| name.contains("tyto_l_16khz") && name.ends_with(".aicmodel"))
-                .unwrap_or(false)
-                && path.is_file()
-            {
-                return Some(path);
-            }
-        }
-        None
-    }
-
-    fn get_tyto_l_16khz() -> Result<PathBuf, AicError> {
-        let target_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("target");
-
-        if let Some(existing) = find_existing_model(&target_dir) {
-            return Ok(existing);
-        }
-
-        let _guard = download_lock().lock().unwrap();
-        if let Some(existing) = find_existing_model(&target_dir) {
-            return Ok(existing);
-        }
-
-        if cfg!(feature = "download-model") {
-            Model::download("tyto-l-16khz", target_dir)
-        } else {
-            panic!(
-                "Model `tyto-l-16khz` not found in {} and `download-model` feature is disabled",
-                target_dir.display()
-            );
-        }
-    }
+    use crate::test_support::{get_tyto_l_16khz, license_key};
 
     fn load_test_model() -> Result<(Model<'static>, String), AicError> {
-        let license_key = std::env::var("AIC_SDK_LICENSE")
-            .expect("AIC_SDK_LICENSE environment variable must be set for tests");
-
-        let model_path = get_tyto_l_16khz()?;
-        let model = Model::from_file(&model_path)?;
-
-        Ok((model, license_key))
+        let model = Model::from_file(get_tyto_l_16khz()?)?;
+        Ok((model, license_key()))
     }
 
     fn assert_score_range(result: &AnalysisResult) {
@@ -0,0 +1,403 @@
+//! Conversions between the audio buffer layouts supported by [`Processor`](crate::Processor).
+//!
+//! The processor accepts three layouts:
+//!
+//! - **Interleaved:** one buffer, samples alternate between channels
+//!   (`[ch0_f0, ch1_f0, ch0_f1, ch1_f1, ...]`).
+//! - **Sequential:** one buffer, all samples of a channel stored back to back
+//!   (`[ch0_f0, ch0_f1, ..., ch1_f0, ch1_f1, ...]`).
+//! - **Planar:** a separate buffer per channel (`[[ch0_f0, ch0_f1, ...], [ch1_f0, ch1_f1, ...]]`).
+//!
+//! The functions in this module convert between them without allocating. They work with any
+//! number of frames and return [`AicError::AudioConfigMismatch`] if the buffer lengths do not
+//! describe the same number of channels and frames. [`ScratchBuffers`] owns preallocated
+//! storage for the converted audio, for callers that convert every block on an audio thread.
+//!
+//! # Example
+//!
+//! ```rust
+//! use aic_sdk::layout;
+//!
+//! let interleaved = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+//! let mut planar = [[0.0; 3]; 2];
+//! layout::interleaved_to_planar(&interleaved, &mut planar)?;
+//! assert_eq!(planar, [[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]]);
+//! # Ok::<(), aic_sdk::AicError>(())
+//! ```
+
+use crate::error::AicError;
+
+/// Copies an interleaved buffer into separate per-channel buffers.
+///
+/// The number of channels is given by `planar.len()`.
+///
+/// # Returns
+///
+/// Returns [`AicError::AudioConfigMismatch`] if `planar` is empty, if `interleaved` is not a
+/// multiple of the number of channels, or if any channel buffer does not hold exactly
+/// `interleaved.len() / planar.len()` frames.
+///
+/// # Real-time safety
+///
+/// Real-time safe. Does not allocate.
+pub fn interleaved_to_planar<V: AsMut<[f32]>>(
+    interleaved: &[f32],
+    planar: &mut [V],
+) -> Result<(), AicError> {
+    let num_channels = planar.len();
+    let num_frames = planar_num_frames(interleaved.len(), num_channels)?;
+
+    for (ch, channel) in planar.iter_mut().enumerate() {
+        let channel = channel.as_mut();
+        if channel.len() != num_frames {
+            return Err(AicError::AudioConfigMismatch);
+        }
+        for (frame, sample) in channel.iter_mut().enumerate() {
+            *sample = interleaved[frame * num_channels + ch];
+        }
+    }
+
+    Ok(())
+}
+
+/// Copies separate per-channel buffers into an interleaved buffer.
+///
+/// The number of channels is given by `planar.len()`.
+///
+/// # Returns
+///
+/// Returns [`AicError::AudioConfigMismatch`] if `planar` is empty, if `interleaved` is not a
+/// multiple of the number of channels, or if any channel buffer does not hold exactly
+/// `interleaved.len() / planar.len()` frames.
+///
+/// # Real-time safety
+///
+/// Real-time safe. Does not allocate.
+pub fn planar_to_interleaved<V: AsRef<[f32]>>(
+    planar: &[V],
+    interleaved: &mut [f32],
+) -> Result<(), AicError> {
+    let num_channels = planar.len();
+    let num_frames = planar_num_frames(interleaved.len(), num_channels)?;
+
+    for (ch, channel) in planar.iter().enumerate() {
+        let channel = channel.as_ref();
+        if channel.len() != num_frames {
+            return Err(AicError::AudioConfigMismatch);
+        }
+        for (frame, &sample) in channel.iter().enumerate() {
+            interleaved[frame * num_channels + ch] = sample;
+        }
+    }
+
+    Ok(())
+}
+
+/// Copies an interleaved buffer into a sequential buffer.
+///
+/// # Returns
+///
+/// Returns [`AicError::AudioConfigMismatch`] if `num_channels` is zero, if the buffers differ
+/// in length, or if their length is not a multiple of `num_channels`.
+///
+/// # Real-time safety
+///
+/// Real-time safe. Does not allocate.
+pub fn interleaved_to_sequential(
+    interleaved: &[f32],
+    sequential: &mut [f32],
+    num_channels: usize,
+) -> Result<(), AicError> {
+    let num_frames = contiguous_num_frames(interleaved.len(), sequential.len(), num_channels)?;
+
+    for frame in 0..num_frames {
+        for ch in 0..num_channels {
+            sequential[ch * num_frames + frame] = interleaved[frame * num_channels + ch];
+        }
+    }
+
+    Ok(())
+}
+
+/// Copies a sequential buffer into an interleaved buffer.
+///
+/// # Returns
+///
+/// Returns [`AicError::AudioConfigMismatch`] if `num_channels` is zero, if the buffers differ
+/// in length, or if their length is not a multiple of `num_channels`.
+///
+/// # Real-time safety
+///
+/// Real-time safe. Does not allocate.
+pub fn sequential_to_interleaved(
+    sequential: &[f32],
+    interleaved: &mut [f32],
+    num_channels: usize,
+) -> Result<(), AicError> {
+    let num_frames = contiguous_num_frames(sequential.len(), interleaved.len(), num_channels)?;
+
+    for frame in 0..num_frames {
+        for ch in 0..num_channels {
+            interleaved[frame * num_channels + ch] = sequential[ch * num_frames + frame];
+        }
+    }
+
+    Ok(())
+}
+
+/// Preallocated interleaved and planar storage for converting blocks between layouts.
+///
+/// All storage is allocated in [`ScratchBuffers::new`]. The conversion methods only copy into
+/// it, so they can be called from audio processing threads.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use aic_sdk::{layout::{self, ScratchBuffers}, Model, Processor, ProcessorConfig};
+/// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+/// # let model = Model::from_file("/path/to/model.aicmodel")?;
+/// let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+/// let mut processor = Processor::new(&model, &license_key)?.with_config(&config)?;
+/// let mut scratch = ScratchBuffers::new(config.num_channels as usize, config.num_frames);
+///
+/// // In the audio callback:
+/// # let mut host_buffer = vec![0.0f32; 2 * config.num_frames];
+/// let planar = scratch.deinterleave_into(&host_buffer)?;
+/// processor.process_planar(planar)?;
+/// layout::planar_to_interleaved(scratch.planar(), &mut host_buffer)?;
+/// # Ok::<(), aic_sdk::AicError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScratchBuffers {
+    num_channels: usize,
+    num_frames: usize,
+    interleaved: Vec<f32>,
+    planar: Vec<Vec<f32>>,
+}
+
+impl ScratchBuffers {
+    /// Allocates zeroed storage for one block of `num_channels` channels with `num_frames`
+    /// frames each.
+    ///
+    /// # Warning
+    /// Do not call from audio processing threads as this allocates memory.
+    pub fn new(num_channels: usize, num_frames: usize) -> Self {
+        Self {
+            num_channels,
+            num_frames,
+            interleaved: vec![0.0; num_channels * num_frames],
+            planar: vec![vec![0.0; num_frames]; num_channels],
+        }
+    }
+
+    /// Returns the number of channels of a block.
+    pub fn num_channels(&self) -> usize {
+        self.num_channels
+    }
+
+    /// Returns the number of frames per channel of a block.
+    pub fn num_frames(&self) -> usize {
+        self.num_frames
+    }
+
+    /// Copies an interleaved block into the planar storage.
+    ///
+    /// # Arguments
+    ///
+    /// * `interleaved` - One block of interleaved audio.
+    ///
+    /// # Returns
+    ///
+    /// Returns the planar storage, or [`AicError::AudioConfigMismatch`] if `interleaved` does
+    /// not hold exactly `num_channels * num_frames` samples or there are no channels.
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Does not allocate.
+    pub fn deinterleave_into(&mut self, interleaved: &[f32]) -> Result<&mut [Vec<f32>], AicError> {
+        if interleaved.len() != self.interleaved.len() {
+            return Err(AicError::AudioConfigMismatch);
+        }
+        interleaved_to_planar(interleaved, &mut self.planar)?;
+        Ok(&mut self.planar)
+    }
+
+    /// Copies a planar block into the interleaved storage.
+    ///
+    /// # Arguments
+    ///
+    /// * `planar` - One block of audio with a buffer per channel.
+    ///
+    /// # Returns
+    ///
+    /// Returns the interleaved storage, or [`AicError::AudioConfigMismatch`] if `planar` does
+    /// not hold `num_channels` buffers of `num_frames` samples or there are no channels.
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Does not allocate.
+    pub fn interleave_into<V: AsRef<[f32]>>(
+        &mut self,
+        planar: &[V],
+    ) -> Result<&mut [f32], AicError> {
+        if planar.len() != self.num_channels {
+            return Err(AicError::AudioConfigMismatch);
+        }
+        planar_to_interleaved(planar, &mut self.interleaved)?;
+        Ok(&mut self.interleaved)
+    }
+
+    /// Returns the planar storage.
+    pub fn planar(&self) -> &[Vec<f32>] {
+        &self.planar
+    }
+
+    /// Returns the planar storage mutably.
+    pub fn planar_mut(&mut self) -> &mut [Vec<f32>] {
+        &mut self.planar
+    }
+
+    /// Returns the interleaved storage.
+    pub fn interleaved(&self) -> &[f32] {
+        &self.interleaved
+    }
+
+    /// Returns the interleaved storage mutably.
+    pub fn interleaved_mut(&mut self) -> &mut [f32] {
+        &mut self.interleaved
+    }
+}
+
+fn planar_num_frames(interleaved_len: usize, num_channels: usize) -> Result<usize, AicError> {
+    if num_channels == 0 || !interleaved_len.is_multiple_of(num_channels) {
+        return Err(AicError::AudioConfigMismatch);
+    }
+    Ok(interleaved_len / num_channels)
+}
+
+fn contiguous_num_frames(
+    src_len: usize,
+    dst_len: usize,
+    num_channels: usize,
+) -> Result<usize, AicError> {
+    if num_channels == 0 || src_len != dst_len || !src_len.is_multiple_of(num_channels) {
+        return Err(AicError::AudioConfigMismatch);
+    }
+    Ok(src_len / num_channels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INTERLEAVED: [f32; 8] = [0.0, 10.0, 1.0, 11.0, 2.0, 12.0, 3.0, 13.0];
+    const SEQUENTIAL: [f32; 8] = [0.0, 1.0, 2.0, 3.0, 10.0, 11.0, 12.0, 13.0];
+
+    #[test]
+    fn interleaved_planar_round_trip() {
+        let mut planar = vec![vec![0.0; 4]; 2];
+        interleaved_to_planar(&INTERLEAVED, &mut planar).unwrap();
+        assert_eq!(planar, [[0.0, 1.0, 2.0, 3.0], [10.0, 11.0, 12.0, 13.0]]);
+
+        let mut interleaved = [0.0; 8];
+        planar_to_interleaved(&planar, &mut interleaved).unwrap();
+        assert_eq!(interleaved, INTERLEAVED);
+    }
+
+    #[test]
+    fn interleaved_sequential_round_trip() {
+        let mut sequential = [0.0; 8];
+        interleaved_to_sequential(&INTERLEAVED, &mut sequential, 2).unwrap();
+        assert_eq!(sequential, SEQUENTIAL);
+
+        let mut interleaved = [0.0; 8];
+        sequential_to_interleaved(&sequential, &mut interleaved, 2).unwrap();
+        assert_eq!(interleaved, INTERLEAVED);
+    }
+
+    #[test]
+    fn single_channel_is_copied_unchanged() {
+        let mut sequential = [0.0; 8];
+        interleaved_to_sequential(&INTERLEAVED, &mut sequential, 1).unwrap();
+        assert_eq!(sequential, INTERLEAVED);
+
+        let mut planar = [[0.0; 8]];
+        interleaved_to_planar(&INTERLEAVED, &mut planar).unwrap();
+        assert_eq!(planar[0], INTERLEAVED);
+    }
+
+    #[test]
+    fn empty_buffers_are_accepted() {
+        let mut planar = [[0.0f32; 0]; 2];
+        interleaved_to_planar(&[], &mut planar).unwrap();
+        planar_to_interleaved(&planar, &mut []).unwrap();
+        interleaved_to_sequential(&[], &mut [], 2).unwrap();
+        sequential_to_interleaved(&[], &mut [], 2).unwrap();
+    }
+
+    #[test]
+    fn zero_channels_are_rejected() {
+        let mut planar: [[f32; 4]; 0] = [];
+        assert_eq!(
+            interleaved_to_planar(&INTERLEAVED, &mut planar),
+            Err(AicError::AudioConfigMismatch)
+        );
+        assert_eq!(
+            interleaved_to_sequential(&INTERLEAVED, &mut [0.0; 8], 0),
+            Err(AicError::AudioConfigMismatch)
+        );
+    }
+
+    #[test]
+    fn scratch_buffers_round_trip() {
+        let mut scratch = ScratchBuffers::new(2, 4);
+
+        let planar = scratch.deinterleave_into(&INTERLEAVED).unwrap();
+        assert_eq!(*planar, [[0.0, 1.0, 2.0, 3.0], [10.0, 11.0, 12.0, 13.0]]);
+
+        let planar = scratch.planar().to_vec();
+        assert_eq!(*scratch.interleave_into(&planar).unwrap(), INTERLEAVED);
+        assert_eq!(scratch.interleaved(), INTERLEAVED);
+    }
+
+    #[test]
+    fn scratch_buffers_reject_other_block_sizes() {
+        let mut scratch = ScratchBuffers::new(2, 4);
+
+        assert_eq!(
+            scratch.deinterleave_into(&INTERLEAVED[..6]),
+            Err(AicError::AudioConfigMismatch)
+        );
+        assert_eq!(
+            scratch.interleave_into(&[[0.0; 8]]),
+            Err(AicError::AudioConfigMismatch)
+        );
+        assert_eq!(
+            scratch.interleave_into(&[[0.0; 3], [0.0; 3]]),
+            Err(AicError::AudioConfigMismatch)
+        );
+    }
+
+    #[test]
+    fn mismatched_lengths_are_rejected() {
+        // Interleaved length is not a multiple of the channel count
+        let mut planar = vec![vec![0.0; 2]; 3];
+        assert_eq!(
+            interleaved_to_planar(&INTERLEAVED, &mut planar),
+            Err(AicError::AudioConfigMismatch)
+        );
+
+        // Channel buffers of different lengths
+        let planar = [vec![0.0; 4], vec![0.0; 3]];
+        assert_eq!(
+            planar_to_interleaved(&planar, &mut [0.0; 8]),
+            Err(AicError::AudioConfigMismatch)
+        );
+
+        // Source and destination of different lengths
+        assert_eq!(
+            sequential_to_interleaved(&SEQUENTIAL, &mut [0.0; 6], 2),
+            Err(AicError::AudioConfigMismatch)
+        );
+    }
+}
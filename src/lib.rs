@@ -2,44 +2,111 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 use aic_sdk_sys::{aic_get_compatible_model_version, aic_get_sdk_version, aic_set_sdk_wrapper_id};
-use std::{ffi::CStr, sync::Once};
+use std::{
+    ffi::CStr,
+    fmt,
+    sync::{Once, OnceLock},
+};
 
 #[cfg(feature = "runtime-linking")]
 use std::path::Path;
 
 mod analyzer;
+#[cfg(feature = "rodio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rodio")))]
+mod enhanced_source;
+mod enhancer;
 mod error;
+#[cfg(feature = "file")]
+#[cfg_attr(docsrs, doc(cfg(feature = "file")))]
+pub mod file;
 mod file_analyzer;
+pub mod layout;
+mod license;
 mod model;
+mod parameter_automation;
+mod preset;
 mod processor;
 #[cfg(feature = "async")]
 #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
 mod processor_async;
+mod processor_builder;
+mod processor_metrics;
+#[cfg(feature = "resample")]
+#[cfg_attr(docsrs, doc(cfg(feature = "resample")))]
+mod resampling_processor;
+mod stream_processor;
+#[cfg(test)]
+pub(crate) mod test_support;
+pub mod testing;
 mod vad;
+#[cfg(feature = "wav")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wav")))]
+pub mod wav;
 
 pub use analyzer::*;
+#[cfg(feature = "rodio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rodio")))]
+pub use enhanced_source::*;
+pub use enhancer::*;
 pub use error::*;
 pub use file_analyzer::*;
+pub use license::*;
 pub use model::*;
+pub use parameter_automation::*;
+pub use preset::*;
 pub use processor::*;
 #[cfg(feature = "async")]
 #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
 pub use processor_async::*;
+pub use processor_builder::*;
+pub use processor_metrics::*;
+#[cfg(feature = "resample")]
+#[cfg_attr(docsrs, doc(cfg(feature = "resample")))]
+pub use resampling_processor::*;
+pub use stream_processor::*;
 pub use vad::*;
 
 #[cfg(feature = "runtime-linking")]
 #[cfg_attr(docsrs, doc(cfg(feature = "runtime-linking")))]
 pub use aic_sdk_sys::DynamicLoadingError;
 
-static SET_WRAPPER_ID: Once = Once::new();
+/// Wrapper ID reported by this crate unless [`set_wrapper_id`] is called.
+const DEFAULT_WRAPPER_ID: u32 = 2;
+
+static WRAPPER_ID: OnceLock<u32> = OnceLock::new();
+static APPLY_WRAPPER_ID: Once = Once::new();
+
+/// Sets the wrapper ID reported to ai-coustics for telemetry attribution.
+///
+/// SDKs and applications built on top of this crate can use this to report their own ID instead
+/// of the ID of this crate. The ID is process-global and can only be set once. It must be set
+/// before the first [`Processor`] or [`Analyzer`] is created; afterwards the ID is fixed.
+///
+/// # Returns
+///
+/// Returns `true` if the ID will be reported, or `false` if an ID was already set or a
+/// processor or analyzer was already created.
+///
+/// # Example
+///
+/// ```rust
+/// aic_sdk::set_wrapper_id(42);
+/// ```
+pub fn set_wrapper_id(id: u32) -> bool {
+    WRAPPER_ID.set(id).is_ok()
+}
+
+/// Reports the wrapper ID to the SDK. Called before the first processor or analyzer is created.
+pub(crate) fn apply_wrapper_id() {
+    APPLY_WRAPPER_ID.call_once(|| {
+        // Fixes the ID, so later calls to `set_wrapper_id` are rejected.
+        let id = *WRAPPER_ID.get_or_init(|| DEFAULT_WRAPPER_ID);
 
-/// Sets the SDK wrapper ID.
-pub(crate) fn set_wrapper_id() {
-    SET_WRAPPER_ID.call_once(|| unsafe {
         // SAFETY:
         // - This FFI call has no safety requirements.
         // - This function can be called from any thread; `Once` serializes this wrapper's call.
-        aic_set_sdk_wrapper_id(2);
+        unsafe { aic_set_sdk_wrapper_id(id) };
     });
 }
 
@@ -94,7 +161,105 @@ pub fn get_sdk_version() -> &'static str {
     unsafe { CStr::from_ptr(version_ptr).to_str().unwrap_or("unknown") }
 }
 
+/// Identifies the ai-coustics SDK library linked into this binary.
+///
+/// Returned by [`get_sdk_build_info`]. Include it in support requests: its `Display` output
+/// is a single line with all fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SdkBuildInfo {
+    /// Version reported by the linked library, see [`get_sdk_version`].
+    pub version: &'static str,
+    /// SDK version pinned by `aic-sdk-sys` at build time. Differs from `version` if a
+    /// different library is linked, e.g. through `AIC_LIB_PATH` or runtime linking.
+    pub artifact_version: &'static str,
+    /// How the library is linked: `"static"`, `"dynamic"` or `"runtime"`.
+    pub linking: &'static str,
+    /// SHA-256 checksum of the SDK archive the library was extracted from, or `None` if the
+    /// library was not downloaded at build time.
+    pub archive_sha256: Option<&'static str>,
+}
+
+impl fmt::Display for SdkBuildInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ai-coustics SDK {} (artifact {}, {} linking, archive sha256 {})",
+            self.version,
+            self.artifact_version,
+            self.linking,
+            self.archive_sha256.unwrap_or("unknown")
+        )
+    }
+}
+
+/// Returns build information about the linked ai-coustics SDK library.
+///
+/// The C library only reports its version. The remaining fields are recorded by the
+/// `aic-sdk-sys` build script when this crate is compiled.
+///
+/// # Example
+///
+/// ```rust
+/// println!("{}", aic_sdk::get_sdk_build_info());
+/// ```
+pub fn get_sdk_build_info() -> SdkBuildInfo {
+    SdkBuildInfo {
+        version: get_sdk_version(),
+        artifact_version: aic_sdk_sys::build_info::ARTIFACT_VERSION,
+        linking: aic_sdk_sys::build_info::LINKING,
+        archive_sha256: aic_sdk_sys::build_info::ARCHIVE_SHA256,
+    }
+}
+
+/// Returns the version of the ai-coustics SDK library.
+#[deprecated(note = "Use `get_sdk_version` instead")]
+pub fn get_version() -> &'static str {
+    get_sdk_version()
+}
+
+/// Returns the version of the ai-coustics SDK library as `(major, minor, patch)`.
+///
+/// Use this for programmatic compatibility checks. Pre-release and build metadata suffixes
+/// (e.g. `-beta.1`) are ignored.
+///
+/// # Returns
+///
+/// Returns the parsed version, or `None` if [`get_sdk_version`] does not start with a
+/// `major.minor.patch` version.
+///
+/// # Example
+///
+/// ```rust
+/// if let Some((major, minor, _)) = aic_sdk::get_sdk_version_semver() {
+///     println!("ai-coustics SDK {major}.{minor}");
+/// }
+/// ```
+pub fn get_sdk_version_semver() -> Option<(u32, u32, u32)> {
+    parse_semver(get_sdk_version())
+}
+
+/// Parses the `major.minor.patch` prefix of a version string.
+fn parse_semver(version: &str) -> Option<(u32, u32, u32)> {
+    let version = version.strip_prefix('v').unwrap_or(version);
+    let core = version.split(['-', '+']).next()?;
+
+    let mut parts = core.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    let patch = parts.next()?.parse().ok()?;
+
+    if parts.next().is_some() {
+        return None;
+    }
+
+    Some((major, minor, patch))
+}
+
 /// Returns the model version number compatible with this SDK build.
+///
+/// Loading a model file with a different version fails with
+/// [`AicError::ModelVersionUnsupported`]. The SDK does not expose the version embedded in
+/// a loaded model, so every successfully loaded [`Model`] has this version.
 pub fn get_compatible_model_version() -> u32 {
     // SAFETY:
     // - FFI call takes no arguments and returns a plain integer.
@@ -104,12 +269,76 @@ pub fn get_compatible_model_version() -> u32 {
 
 /// This function is only used to identify SDKs by ai-coustics and should not be called by users of this crate.
 ///
+/// Sets the same ID as [`set_wrapper_id`], so it is only reported if it is set before the
+/// first [`Processor`] or [`Analyzer`] is created.
+///
 /// # Safety
 ///
 /// - Don't call this function unless you know what you're doing.
+#[deprecated(note = "Use `set_wrapper_id` instead")]
 pub unsafe fn set_sdk_id(id: u32) {
-    // SAFETY:
-    // - This FFI call has no safety requirements.
-    // - This function can be called from any thread.
-    unsafe { aic_set_sdk_wrapper_id(id) }
+    set_wrapper_id(id);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrapper_id_is_fixed_once_applied() {
+        apply_wrapper_id();
+
+        assert!(!set_wrapper_id(42));
+        assert!(WRAPPER_ID.get().is_some());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn set_sdk_id_does_not_bypass_the_wrapper_id() {
+        apply_wrapper_id();
+        let id = *WRAPPER_ID.get().unwrap();
+
+        // SAFETY: Only changes the reported wrapper ID.
+        unsafe { set_sdk_id(id + 1) };
+        assert_eq!(WRAPPER_ID.get(), Some(&id));
+    }
+
+    #[test]
+    fn sdk_version_semver_matches_version_string() {
+        let version = get_sdk_version();
+        let (major, minor, patch) = get_sdk_version_semver().unwrap();
+
+        assert!(
+            version.starts_with(&format!("{major}.{minor}.{patch}")),
+            "{version} does not start with {major}.{minor}.{patch}"
+        );
+    }
+
+    #[test]
+    fn sdk_build_info_matches_the_pinned_library() {
+        let info = get_sdk_build_info();
+
+        assert_eq!(info.version, get_sdk_version());
+        // Only a library downloaded by the build script is known to be the pinned one
+        if info.archive_sha256.is_some() {
+            assert!(info.version.starts_with(info.artifact_version), "{info}");
+        }
+        assert!(["static", "dynamic", "runtime"].contains(&info.linking));
+    }
+
+    #[test]
+    fn parse_semver_handles_suffixes() {
+        assert_eq!(parse_semver("0.21.2"), Some((0, 21, 2)));
+        assert_eq!(parse_semver("v1.2.3"), Some((1, 2, 3)));
+        assert_eq!(parse_semver("1.2.3-beta.1"), Some((1, 2, 3)));
+        assert_eq!(parse_semver("1.2.3+build.5"), Some((1, 2, 3)));
+    }
+
+    #[test]
+    fn parse_semver_rejects_invalid_versions() {
+        assert_eq!(parse_semver("unknown"), None);
+        assert_eq!(parse_semver("1.2"), None);
+        assert_eq!(parse_semver("1.2.3.4"), None);
+        assert_eq!(parse_semver("1.x.3"), None);
+    }
 }
@@ -0,0 +1,288 @@
+use crate::{error::AicError, model::Model, processor::Processor};
+
+use std::{env, ffi::CString, fmt, path::Path};
+
+/// Environment variable read by [`License::from_env`].
+pub const LICENSE_ENV: &str = "AIC_SDK_LICENSE";
+
+/// A license key for the ai-coustics SDK.
+///
+/// All functions that create a [`Processor`](crate::Processor) or
+/// [`Analyzer`](crate::Analyzer) accept a `License`, as well as a `&str`, `&&str`, `String` or
+/// `&String` that is converted into one. Keys created with [`License::new`],
+/// [`License::from_env`] or [`License::from_file`] are validated when they are created, so a
+/// key containing a NUL byte is reported before it is used. Keys converted from strings are
+/// validated when they are used.
+///
+/// Surrounding whitespace, e.g. a trailing newline in a key file, is removed. The key is never
+/// printed by [`Debug`](fmt::Debug).
+///
+/// Generate your key at [developers.ai-coustics.com](https://developers.ai-coustics.com/).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use aic_sdk::{License, Model, Processor};
+/// # let model = Model::from_file("/path/to/model.aicmodel")?;
+/// let license = License::from_env()?;
+/// let processor = Processor::new(&model, &license)?;
+/// # Ok::<(), aic_sdk::AicError>(())
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct License(String);
+
+impl License {
+    /// Creates a license from a key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The license key.
+    ///
+    /// # Returns
+    ///
+    /// Returns the license, or [`AicError::LicenseFormatInvalid`] if the key is empty or
+    /// contains a NUL byte.
+    pub fn new(key: &str) -> Result<Self, AicError> {
+        let license = License::from(key);
+        license.to_c_string()?;
+        Ok(license)
+    }
+
+    /// Reads the license key from the `AIC_SDK_LICENSE` environment variable.
+    ///
+    /// # Returns
+    ///
+    /// Returns the license, [`AicError::LicenseNotSet`] if the variable is not set, or
+    /// [`AicError::LicenseFormatInvalid`] if it is not valid Unicode, is empty or contains a
+    /// NUL byte.
+    pub fn from_env() -> Result<Self, AicError> {
+        Self::from_var(env::var(LICENSE_ENV))
+    }
+
+    fn from_var(var: Result<String, env::VarError>) -> Result<Self, AicError> {
+        match var {
+            Ok(key) => Self::new(&key),
+            Err(env::VarError::NotPresent) => Err(AicError::LicenseNotSet),
+            Err(env::VarError::NotUnicode(_)) => Err(AicError::LicenseFormatInvalid),
+        }
+    }
+
+    /// Reads the license key from a file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of a text file containing only the license key.
+    ///
+    /// # Returns
+    ///
+    /// Returns the license, [`AicError::FileSystemError`] if the file cannot be read, or
+    /// [`AicError::LicenseFormatInvalid`] if it is not valid UTF-8, is empty or contains a NUL
+    /// byte.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, AicError> {
+        let bytes = std::fs::read(path).map_err(|_| AicError::FileSystemError)?;
+        let key = String::from_utf8(bytes).map_err(|_| AicError::LicenseFormatInvalid)?;
+        Self::new(&key)
+    }
+
+    /// Checks whether the SDK accepts this license, before any audio is processed.
+    ///
+    /// The SDK does not expose license metadata such as the expiry date. This creates a
+    /// temporary processor for `model` and reports why the license was rejected, if it was.
+    /// A license that is valid now can still expire later, in which case processing starts to
+    /// fail with a license error.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The model the license will be used with.
+    ///
+    /// # Returns
+    ///
+    /// Returns the status of the license, or an [`AicError`] if the check failed for a reason
+    /// unrelated to the license.
+    ///
+    /// # Warning
+    /// Do not call from audio processing threads as this allocates memory.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{License, LicenseStatus, Model};
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// let license = License::from_env()?;
+    /// match license.status(&model)? {
+    ///     LicenseStatus::Valid => println!("License is valid"),
+    ///     LicenseStatus::Expired => eprintln!("License has expired, please renew it"),
+    ///     status => eprintln!("License was rejected: {status:?}"),
+    /// }
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn status(&self, model: &Model) -> Result<LicenseStatus, AicError> {
+        match Processor::new(model, self) {
+            Ok(_) => Ok(LicenseStatus::Valid),
+            Err(err) => LicenseStatus::from_error(&err).ok_or(err),
+        }
+    }
+
+    /// Returns the license key.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Converts the key for passing it to the SDK.
+    pub(crate) fn to_c_string(&self) -> Result<CString, AicError> {
+        if self.0.is_empty() {
+            return Err(AicError::LicenseFormatInvalid);
+        }
+        CString::new(self.0.as_str()).map_err(|_| AicError::LicenseFormatInvalid)
+    }
+}
+
+/// Result of checking a license with [`License::status`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LicenseStatus {
+    /// The license is accepted by the SDK.
+    Valid,
+    /// The license key is malformed, see [`AicError::LicenseFormatInvalid`].
+    FormatInvalid,
+    /// The license is not compatible with this SDK version, see
+    /// [`AicError::LicenseVersionUnsupported`].
+    VersionUnsupported,
+    /// The license has expired, see [`AicError::LicenseExpired`].
+    Expired,
+}
+
+impl LicenseStatus {
+    /// Returns `true` if the license is accepted by the SDK.
+    pub fn is_valid(self) -> bool {
+        self == LicenseStatus::Valid
+    }
+
+    /// Returns the status corresponding to a license error, or `None` if `err` is not a
+    /// license error.
+    pub fn from_error(err: &AicError) -> Option<Self> {
+        match err {
+            AicError::LicenseFormatInvalid => Some(LicenseStatus::FormatInvalid),
+            AicError::LicenseVersionUnsupported => Some(LicenseStatus::VersionUnsupported),
+            AicError::LicenseExpired => Some(LicenseStatus::Expired),
+            _ => None,
+        }
+    }
+}
+
+impl From<&str> for License {
+    fn from(key: &str) -> Self {
+        License(key.trim().to_owned())
+    }
+}
+
+impl From<&&str> for License {
+    fn from(key: &&str) -> Self {
+        License::from(*key)
+    }
+}
+
+impl From<&String> for License {
+    fn from(key: &String) -> Self {
+        License::from(key.as_str())
+    }
+}
+
+impl From<String> for License {
+    fn from(key: String) -> Self {
+        License::from(key.as_str())
+    }
+}
+
+impl From<&License> for License {
+    fn from(license: &License) -> Self {
+        license.clone()
+    }
+}
+
+// Never prints the key itself.
+impl fmt::Debug for License {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("License")
+            .field("len", &self.0.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_are_trimmed() {
+        let license = License::new("  key\n").unwrap();
+        assert_eq!(license.as_str(), "key");
+        assert_eq!(License::from(String::from("key\r\n")), license);
+        let key: &str = "\tkey";
+        assert_eq!(License::from(&key), license);
+    }
+
+    #[test]
+    fn invalid_keys_are_rejected_early() {
+        assert_eq!(
+            License::new("invalid\0license"),
+            Err(AicError::LicenseFormatInvalid)
+        );
+        assert_eq!(License::new(" \n"), Err(AicError::LicenseFormatInvalid));
+    }
+
+    #[test]
+    fn converted_keys_are_validated_on_use() {
+        let license = License::from("invalid\0license");
+        assert_eq!(license.to_c_string(), Err(AicError::LicenseFormatInvalid));
+    }
+
+    #[test]
+    fn missing_env_var_is_not_a_format_error() {
+        assert_eq!(
+            License::from_var(Err(env::VarError::NotPresent)),
+            Err(AicError::LicenseNotSet)
+        );
+        assert_eq!(
+            License::from_var(Ok(" env-key\n".to_owned()))
+                .unwrap()
+                .as_str(),
+            "env-key"
+        );
+        assert_eq!(LicenseStatus::from_error(&AicError::LicenseNotSet), None);
+    }
+
+    #[test]
+    fn keys_are_read_from_files() {
+        let path = std::env::temp_dir().join("aic-sdk-license-test.txt");
+        std::fs::write(&path, "file-key\n").unwrap();
+        assert_eq!(License::from_file(&path).unwrap().as_str(), "file-key");
+
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(License::from_file(&path), Err(AicError::FileSystemError));
+    }
+
+    #[test]
+    fn license_errors_map_to_statuses() {
+        assert_eq!(
+            LicenseStatus::from_error(&AicError::LicenseExpired),
+            Some(LicenseStatus::Expired)
+        );
+        assert_eq!(
+            LicenseStatus::from_error(&AicError::LicenseFormatInvalid),
+            Some(LicenseStatus::FormatInvalid)
+        );
+        assert_eq!(
+            LicenseStatus::from_error(&AicError::LicenseVersionUnsupported),
+            Some(LicenseStatus::VersionUnsupported)
+        );
+        assert_eq!(LicenseStatus::from_error(&AicError::ModelInvalid), None);
+        assert!(LicenseStatus::Valid.is_valid());
+        assert!(!LicenseStatus::Expired.is_valid());
+    }
+
+    #[test]
+    fn debug_does_not_print_the_key() {
+        let license = License::new("secret").unwrap();
+        assert!(!format!("{license:?}").contains("secret"));
+    }
+}
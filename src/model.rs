@@ -1,14 +1,24 @@
-use crate::error::*;
+use crate::{error::*, processor::ProcessorConfig};
 
 use aic_sdk_sys::*;
 
+#[cfg(feature = "download-model")]
+pub use aic_model_downloader::{DownloadOptions, ModelInfo, ModelVersion, VerifyMode};
+
 use std::{
     ffi::{CStr, CString},
+    fmt,
     marker::PhantomData,
     path::Path,
     ptr,
+    sync::Arc,
 };
 
+/// Alignment in bytes required for buffers passed to [`Model::from_buffer`].
+///
+/// [`include_model!`](macro@crate::include_model) aligns embedded model files to this value.
+pub const MODEL_ALIGNMENT: usize = 64;
+
 /// High-level wrapper for the ai-coustics audio enhancement model.
 ///
 /// This struct provides a safe, Rust-friendly interface to the underlying C library.
@@ -17,8 +27,9 @@ use std::{
 ///
 /// # Sharing and Multi-threading
 ///
-/// `Model` is `Send` and `Sync`, so you can share it across threads. It does not implement
-/// `Clone`, so wrap it in an `Arc` if you need shared ownership.
+/// `Model` is `Send` and `Sync`, so you can share it across threads. Cloning a `Model` is cheap:
+/// all clones share the same underlying model and its weights, which are freed once the last
+/// clone is dropped. Processors created from any clone use the same weights.
 ///
 /// # Example
 ///
@@ -38,13 +49,13 @@ use std::{
 ///
 /// ```rust,no_run
 /// # use aic_sdk::{Model, ProcessorConfig, Processor};
-/// # use std::{thread, sync::Arc};
-/// let model = Arc::new(Model::from_file("/path/to/model.aicmodel")?);
+/// # use std::thread;
+/// let model = Model::from_file("/path/to/model.aicmodel")?;
 ///
 /// // Spawn multiple threads, each with its own processor but sharing the same model
 /// let handles: Vec<_> = (0..4)
 ///     .map(|i| {
-///         let model_clone = Arc::clone(&model);
+///         let model_clone = model.clone();
 ///         thread::spawn(move || {
 ///             let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
 ///             let mut processor = Processor::new(&model_clone, &license_key).unwrap();
@@ -58,14 +69,81 @@ use std::{
 /// }
 /// # Ok::<(), aic_sdk::AicError>(())
 /// ```
+#[derive(Clone)]
 pub struct Model<'a> {
-    /// Raw pointer to the C model structure
-    ptr: *mut AicModel,
+    /// Handle to the C model structure, shared between clones
+    handle: Arc<ModelHandle>,
     /// Marker to tie the lifetime of the model to the lifetime of its weights
     marker: PhantomData<&'a [u8]>,
 }
 
+/// Owns the raw pointer to the C model structure and destroys it when the last [`Model`]
+/// clone is dropped.
+///
+/// For models created with [`Model::from_buffer_owned`], it also owns the model data. Fields
+/// are dropped after [`Drop::drop`] ran, so the data outlives the C model structure.
+struct ModelHandle(
+    *mut AicModel,
+    // Never read, only kept alive for the SDK, which reads the model data through the pointer
+    #[allow(dead_code)] Option<ModelBuffer>,
+);
+
+/// Model data owned by a [`ModelHandle`].
+pub(crate) enum ModelBuffer {
+    /// A buffer that was already aligned to [`MODEL_ALIGNMENT`] bytes.
+    Vec(Vec<u8>),
+    /// A copy of an unaligned buffer. Only the first `len` bytes are model data.
+    Aligned(Box<[AlignedChunk]>, usize),
+    /// A memory-mapped model file. Mappings start at a page boundary, so they are always
+    /// aligned.
+    #[cfg(feature = "mmap")]
+    Mmap(memmap2::Mmap),
+}
+
+#[repr(C, align(64))]
+#[derive(Clone, Copy)]
+pub(crate) struct AlignedChunk([u8; MODEL_ALIGNMENT]);
+
+const _: () = assert!(std::mem::align_of::<AlignedChunk>() == MODEL_ALIGNMENT);
+
+impl ModelBuffer {
+    /// Takes ownership of `buffer`, copying it only if it is not aligned.
+    pub(crate) fn new(buffer: Vec<u8>) -> Self {
+        if (buffer.as_ptr() as usize).is_multiple_of(MODEL_ALIGNMENT) {
+            return ModelBuffer::Vec(buffer);
+        }
+
+        let mut chunks =
+            vec![AlignedChunk([0; MODEL_ALIGNMENT]); buffer.len().div_ceil(MODEL_ALIGNMENT)];
+        for (chunk, data) in chunks.iter_mut().zip(buffer.chunks(MODEL_ALIGNMENT)) {
+            chunk.0[..data.len()].copy_from_slice(data);
+        }
+        ModelBuffer::Aligned(chunks.into_boxed_slice(), buffer.len())
+    }
+
+    pub(crate) fn as_slice(&self) -> &[u8] {
+        match self {
+            ModelBuffer::Vec(buffer) => buffer,
+            // SAFETY:
+            // - `AlignedChunk` is `repr(C)` around a byte array, so the chunks are contiguous
+            //   initialized bytes without padding.
+            // - `len` is at most `chunks.len() * MODEL_ALIGNMENT`.
+            ModelBuffer::Aligned(chunks, len) => unsafe {
+                std::slice::from_raw_parts(chunks.as_ptr().cast::<u8>(), *len)
+            },
+            #[cfg(feature = "mmap")]
+            ModelBuffer::Mmap(mmap) => mmap,
+        }
+    }
+}
+
 impl<'a> Model<'a> {
+    /// Lowest sample rate in Hz a processor can be initialized with.
+    pub const MIN_SAMPLE_RATE: u32 = 8000;
+
+    /// Highest sample rate in Hz a processor can be initialized with.
+    pub const MAX_SAMPLE_RATE: u32 = 192_000;
+
     /// Creates a new audio enhancement model instance.
     ///
     /// Multiple models can be created to process different audio streams simultaneously
@@ -78,6 +156,12 @@ impl<'a> Model<'a> {
     /// # Returns
     ///
     /// Returns a `Result` containing the new `Model` instance or an `AicError` if creation fails.
+    /// Returns [`AicError::ModelFileNotFound`] if no file exists at `path`,
+    /// [`AicError::ModelFilePathInvalid`] if the path cannot be passed to the SDK and
+    /// [`AicError::ModelInvalid`] if the file is not a valid model.
+    /// Returns [`AicError::ModelVersionUnsupported`] if the version embedded in the model file
+    /// differs from [`get_compatible_model_version`](crate::get_compatible_model_version).
+    /// A successfully loaded model is always compatible with this SDK.
     ///
     /// # Example
     ///
@@ -87,8 +171,16 @@ impl<'a> Model<'a> {
     /// # Ok::<(), aic_sdk::AicError>(())
     /// ```
     pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Model<'static>, AicError> {
+        // The SDK reports a missing file as a generic filesystem error
+        if !path.as_ref().is_file() {
+            return Err(AicError::ModelFileNotFound(path.as_ref().to_path_buf()));
+        }
+
         let mut model_ptr: *mut AicModel = ptr::null_mut();
-        let c_path = CString::new(path.as_ref().to_string_lossy().as_bytes()).unwrap();
+        let c_path = CString::new(path.as_ref().to_string_lossy().as_bytes())
+            .map_err(|_| AicError::ModelFilePathInvalid)?;
+        #[cfg(feature = "log")]
+        log::trace!("Loading model from {}", path.as_ref().display());
 
         // SAFETY:
         // - `model_ptr` points to stack memory we own.
@@ -97,7 +189,13 @@ impl<'a> Model<'a> {
         //   buffer are local to this call and not shared with other threads.
         let error_code = unsafe { aic_model_create_from_file(&mut model_ptr, c_path.as_ptr()) };
 
-        handle_error(error_code)?;
+        handle_error(error_code).inspect_err(|_err| {
+            #[cfg(feature = "log")]
+            log::warn!(
+                "Failed to load model from {}: {_err}",
+                path.as_ref().display()
+            );
+        })?;
 
         // This should never happen if the C library is well-behaved, but let's be defensive
         assert!(
@@ -106,14 +204,15 @@ impl<'a> Model<'a> {
         );
 
         Ok(Model {
-            ptr: model_ptr,
+            handle: Arc::new(ModelHandle(model_ptr, None)),
             marker: PhantomData,
         })
     }
 
     /// Creates a new model instance from an in-memory buffer.
     ///
-    /// The buffer must be 64-byte aligned.
+    /// The buffer must be aligned to [`MODEL_ALIGNMENT`] bytes. Buffers allocated as `Vec<u8>`
+    /// are usually not aligned sufficiently.
     ///
     /// Consider using [`include_model!`](macro@crate::include_model) to embed a model file at compile time with
     /// the correct alignment.
@@ -125,6 +224,11 @@ impl<'a> Model<'a> {
     /// # Returns
     ///
     /// Returns a `Result` containing the new `Model` instance or an `AicError` if creation fails.
+    /// Returns [`AicError::ModelDataUnaligned`] if `buffer` is not aligned to
+    /// [`MODEL_ALIGNMENT`] bytes.
+    /// Returns [`AicError::ModelVersionUnsupported`] if the version embedded in the model file
+    /// differs from [`get_compatible_model_version`](crate::get_compatible_model_version).
+    /// A successfully loaded model is always compatible with this SDK.
     ///
     /// # Example
     ///
@@ -135,26 +239,110 @@ impl<'a> Model<'a> {
     /// # Ok::<(), aic_sdk::AicError>(())
     /// ```
     pub fn from_buffer(buffer: &'a [u8]) -> Result<Self, AicError> {
-        let mut model_ptr: *mut AicModel = ptr::null_mut();
+        Ok(Model {
+            handle: Arc::new(ModelHandle(create_from_buffer(buffer)?, None)),
+            marker: PhantomData,
+        })
+    }
 
-        // SAFETY:
-        // - `buffer` is a valid slice and immutable for `'a`.
-        // - The SDK only reads from `buffer` for the lifetime of the model.
-        // - This function is not thread-safe, but the output pointer is local to
-        //   this call and no model handle exists until it returns.
-        let error_code =
-            unsafe { aic_model_create_from_buffer(&mut model_ptr, buffer.as_ptr(), buffer.len()) };
+    /// Creates a new model instance that owns its in-memory model data.
+    ///
+    /// Unlike [`Model::from_buffer`], which borrows the buffer and ties the model's lifetime to
+    /// it, this takes ownership of `buffer` and stores it inside the model. The returned model
+    /// is `'static`, so it and its processors can be moved to other threads without keeping a
+    /// separate buffer alive. The data is freed once the last clone of the model is dropped.
+    ///
+    /// The buffer does not need to be aligned. If it is not aligned to [`MODEL_ALIGNMENT`]
+    /// bytes, it is copied into an aligned allocation once.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - Raw bytes of the model file.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the new `Model` instance or an `AicError` if creation fails.
+    /// Returns [`AicError::ModelVersionUnsupported`] if the version embedded in the model file
+    /// differs from [`get_compatible_model_version`](crate::get_compatible_model_version).
+    ///
+    /// # Warning
+    ///
+    /// Do not call from audio processing threads as this allocates memory.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::Model;
+    /// let bytes = std::fs::read("/path/to/model.aicmodel").unwrap();
+    /// let model = Model::from_buffer_owned(bytes)?;
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn from_buffer_owned(buffer: Vec<u8>) -> Result<Model<'static>, AicError> {
+        let buffer = ModelBuffer::new(buffer);
+        let model_ptr = create_from_buffer(buffer.as_slice())?;
 
-        handle_error(error_code)?;
+        // The heap data of `buffer` does not move when the buffer is moved into the handle.
+        Ok(Model {
+            handle: Arc::new(ModelHandle(model_ptr, Some(buffer))),
+            marker: PhantomData,
+        })
+    }
 
-        // This should never happen if the C library is well-behaved, but let's be defensive
-        assert!(
-            !model_ptr.is_null(),
-            "C library returned success but null pointer"
-        );
+    /// Creates a new model instance from a memory-mapped model file.
+    ///
+    /// Unlike [`Model::from_file`], the model data is not read into memory up front. Pages are
+    /// loaded by the operating system as the model is used and are shared between all
+    /// processes that map the same file, which reduces startup time and memory usage when
+    /// several processes load the same large model. The mapping is stored inside the model and
+    /// released once the last clone of the model and every processor created from it are
+    /// dropped, so the returned model is `'static`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Filesystem path to a model file.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the new `Model` instance or an `AicError` if creation fails.
+    /// Returns [`AicError::ModelFileNotFound`] if no file exists at `path` and
+    /// [`AicError::FileSystemError`] if the file cannot be opened or mapped.
+    /// Returns [`AicError::ModelVersionUnsupported`] if the version embedded in the model file
+    /// differs from [`get_compatible_model_version`](crate::get_compatible_model_version).
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated while it is mapped, neither by this process
+    /// nor by any other. Doing so changes the model data while the SDK reads it, which is
+    /// undefined behavior and may crash the process. Only map model files that are not written
+    /// to, e.g. read-only files of an installed application.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::Model;
+    /// // SAFETY: The model file is part of the installation and is never modified.
+    /// let model = unsafe { Model::from_mmap("/path/to/model.aicmodel")? };
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    #[cfg(feature = "mmap")]
+    pub unsafe fn from_mmap<P: AsRef<Path>>(path: P) -> Result<Model<'static>, AicError> {
+        let path = path.as_ref();
+        if !path.is_file() {
+            return Err(AicError::ModelFileNotFound(path.to_path_buf()));
+        }
+        #[cfg(feature = "log")]
+        log::trace!("Mapping model from {}", path.display());
 
+        let file = std::fs::File::open(path).map_err(|_| AicError::FileSystemError)?;
+        // SAFETY: The caller guarantees that the file is not modified while it is mapped.
+        let mmap = unsafe { memmap2::Mmap::map(&file) }.map_err(|_| AicError::FileSystemError)?;
+
+        let buffer = ModelBuffer::Mmap(mmap);
+        let model_ptr = create_from_buffer(buffer.as_slice())?;
+
+        // The mapped memory does not move when the mapping is moved into the handle.
         Ok(Model {
-            ptr: model_ptr,
+            handle: Arc::new(ModelHandle(model_ptr, Some(buffer))),
             marker: PhantomData,
         })
     }
@@ -291,6 +479,117 @@ impl<'a> Model<'a> {
         num_frames
     }
 
+    /// Returns common sample rates that processors for this model can be initialized with.
+    ///
+    /// The SDK accepts any sample rate from [`Model::MIN_SAMPLE_RATE`] to
+    /// [`Model::MAX_SAMPLE_RATE`] for every model, so this is not an exhaustive list. It
+    /// contains the standard rates within that range and the model's
+    /// [optimal sample rate](Model::optimal_sample_rate), in ascending order, e.g. to offer
+    /// them in a user interface.
+    ///
+    /// # Warning
+    /// Do not call from audio processing threads as this allocates memory.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::Model;
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// for sample_rate in model.supported_sample_rates() {
+    ///     println!("{sample_rate} Hz: {} frames", model.optimal_num_frames(sample_rate));
+    /// }
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn supported_sample_rates(&self) -> Vec<u32> {
+        const COMMON_SAMPLE_RATES: [u32; 12] = [
+            8000, 11025, 16000, 22050, 24000, 32000, 44100, 48000, 88200, 96000, 176400, 192000,
+        ];
+
+        let mut sample_rates = COMMON_SAMPLE_RATES.to_vec();
+        let optimal = self.optimal_sample_rate();
+        if let Err(index) = sample_rates.binary_search(&optimal) {
+            sample_rates.insert(index, optimal);
+        }
+        sample_rates
+    }
+
+    /// Returns whether a processor for this model can be initialized with `sample_rate` and
+    /// `num_frames`.
+    ///
+    /// The SDK does not provide a query for this, so the check is based on the documented
+    /// limits: the sample rate must be within [`Model::MIN_SAMPLE_RATE`] and
+    /// [`Model::MAX_SAMPLE_RATE`], and at least one frame must be processed per block.
+    /// [`Processor::initialize`](crate::Processor::initialize) remains the final authority.
+    ///
+    /// Frame counts other than [`Model::optimal_num_frames`] are supported but add latency.
+    ///
+    /// # Arguments
+    ///
+    /// * `sample_rate` - Sample rate in Hz.
+    /// * `num_frames` - Number of frames per channel in each processing block.
+    pub fn supports(&self, sample_rate: u32, num_frames: usize) -> bool {
+        (Self::MIN_SAMPLE_RATE..=Self::MAX_SAMPLE_RATE).contains(&sample_rate) && num_frames > 0
+    }
+
+    /// Returns a [`ProcessorConfig`] pre-filled with the model's optimal sample rate and frame
+    /// size.
+    ///
+    /// `num_channels` will be set to `1` and `allow_variable_frames` to `false`.
+    /// Adjust the number of channels and enable variable frames by using the builder pattern.
+    ///
+    /// This is equivalent to [`ProcessorConfig::optimal`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// let config = model.optimal_processor_config().with_num_channels(2);
+    /// let processor = Processor::new(&model, &license_key)?.with_config(&config)?;
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    #[must_use]
+    pub fn optimal_processor_config(&self) -> ProcessorConfig {
+        let sample_rate = self.optimal_sample_rate();
+        let num_frames = self.optimal_num_frames(sample_rate);
+        ProcessorConfig {
+            sample_rate,
+            num_channels: 1,
+            num_frames,
+            allow_variable_frames: false,
+        }
+    }
+
+    /// Collects the model's properties into a single [`ModelMetadata`] value.
+    ///
+    /// This is useful for logging which model is loaded or for verifying compatibility
+    /// before constructing a [`Processor`](crate::Processor).
+    ///
+    /// # Returns
+    ///
+    /// Returns the model's metadata, with the optimal frame count computed for the
+    /// model's optimal sample rate.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::Model;
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// let metadata = model.metadata();
+    /// println!("Loaded model {} at {} Hz", metadata.id, metadata.optimal_sample_rate);
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn metadata(&self) -> ModelMetadata {
+        let optimal_sample_rate = self.optimal_sample_rate();
+        ModelMetadata {
+            id: self.id().to_owned(),
+            optimal_sample_rate,
+            optimal_num_frames: self.optimal_num_frames(optimal_sample_rate),
+            compatible_model_version: crate::get_compatible_model_version(),
+        }
+    }
+
     /// Downloads a model file from the ai-coustics artifact CDN.
     ///
     /// This method fetches the model manifest, verifies that the requested model
@@ -317,48 +616,437 @@ impl<'a> Model<'a> {
     /// # Note
     ///
     /// This is a blocking operation that performs network I/O.
+    ///
+    /// Failed requests are retried with the defaults of [`DownloadOptions`]. Use
+    /// [`Model::download_with_options`] to customize this behavior.
     #[cfg(feature = "download-model")]
     pub fn download<P: AsRef<Path>>(
         model_id: &str,
         download_dir: P,
     ) -> Result<std::path::PathBuf, AicError> {
-        let compatible_version = crate::get_compatible_model_version();
-        aic_model_downloader::download(model_id, compatible_version, download_dir)
-            .map_err(|err| AicError::ModelDownload(err.to_string()))
+        Self::download_with_options(model_id, download_dir, &DownloadOptions::default())
+    }
+
+    /// Downloads a model file from the ai-coustics artifact CDN using custom [`DownloadOptions`].
+    ///
+    /// Behaves like [`Model::download`], but allows configuring how failed requests are retried
+    /// and which URLs the manifest and model files are downloaded from. Only server errors
+    /// (HTTP 5xx) and connection failures are retried, with an exponentially growing delay
+    /// between attempts. A missing model is reported immediately.
+    ///
+    /// See [`DownloadOptions`] for how URL overrides and the `AIC_MANIFEST_URL` and
+    /// `AIC_MODEL_BASE_URL` environment variables take precedence.
+    ///
+    /// # Arguments
+    ///
+    /// * `model_id` - The model identifier (e.g., `"quail-l-16khz"`).
+    /// * `download_dir` - Directory where the model file will be stored.
+    /// * `options` - Download options such as the number of retries or a mirror URL.
+    ///
+    /// # Returns
+    ///
+    /// Returns the full path to the model file on success, or an [`AicError`] if the
+    /// operation fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{DownloadOptions, Model};
+    /// # use std::time::Duration;
+    /// let options = DownloadOptions {
+    ///     retries: 5,
+    ///     base_delay: Duration::from_secs(1),
+    ///     ..Default::default()
+    /// };
+    /// let path = Model::download_with_options("quail-l-16khz", "models", &options)?;
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This is a blocking operation that performs network I/O.
+    #[cfg(feature = "download-model")]
+    pub fn download_with_options<P: AsRef<Path>>(
+        model_id: &str,
+        download_dir: P,
+        options: &DownloadOptions,
+    ) -> Result<std::path::PathBuf, AicError> {
+        let compatible_version = ModelVersion(crate::get_compatible_model_version());
+        aic_model_downloader::download_with_options(
+            model_id,
+            compatible_version,
+            download_dir,
+            options,
+        )
+        .map_err(AicError::from)
+    }
+
+    /// Downloads several model files from the ai-coustics artifact CDN.
+    ///
+    /// Behaves like calling [`Model::download`] for every model, but fetches the manifest
+    /// only once, e.g. to set up a small and a large model. All models are looked up before the
+    /// first file is downloaded, so an unknown model is reported without downloading any of
+    /// the others. Every file is verified against its checksum and moved into place on its
+    /// own.
+    ///
+    /// # Arguments
+    ///
+    /// * `model_ids` - The model identifiers (e.g., `["quail-s-16khz", "quail-l-16khz"]`).
+    /// * `download_dir` - Directory where the model files will be stored.
+    ///
+    /// # Returns
+    ///
+    /// Returns the full paths to the model files in the order of `model_ids`, or the first
+    /// [`AicError`] that occurred.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::Model;
+    /// let paths = Model::download_many(&["quail-s-16khz", "quail-l-16khz"], "models")?;
+    /// let small = Model::from_file(&paths[0])?;
+    /// let large = Model::from_file(&paths[1])?;
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This is a blocking operation that performs network I/O.
+    #[cfg(feature = "download-model")]
+    pub fn download_many<P: AsRef<Path>>(
+        model_ids: &[&str],
+        download_dir: P,
+    ) -> Result<Vec<std::path::PathBuf>, AicError> {
+        let compatible_version = ModelVersion(crate::get_compatible_model_version());
+        aic_model_downloader::download_many(model_ids, compatible_version, download_dir)
+            .map_err(AicError::from)
+    }
+
+    /// Downloads a model file from the ai-coustics artifact CDN, reporting progress.
+    ///
+    /// Behaves like [`Model::download`], but streams the model file to disk and calls
+    /// `progress` after every received chunk. The checksum is verified once the whole file
+    /// has been received.
+    ///
+    /// # Arguments
+    ///
+    /// * `model_id` - The model identifier (e.g., `"quail-l-16khz"`).
+    /// * `download_dir` - Directory where the model file will be stored.
+    /// * `progress` - Called with the number of bytes downloaded so far and the total size
+    ///   in bytes, if known. Not called if the model file already exists.
+    ///
+    /// # Returns
+    ///
+    /// Returns the full path to the model file on success, or an [`AicError`] if the
+    /// operation fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::Model;
+    /// let path = Model::download_with_progress("quail-l-16khz", "models", |downloaded, total| {
+    ///     match total {
+    ///         Some(total) => println!("{downloaded} / {total} bytes"),
+    ///         None => println!("{downloaded} bytes"),
+    ///     }
+    /// })?;
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This is a blocking operation that performs network I/O.
+    #[cfg(feature = "download-model")]
+    pub fn download_with_progress<P, F>(
+        model_id: &str,
+        download_dir: P,
+        progress: F,
+    ) -> Result<std::path::PathBuf, AicError>
+    where
+        P: AsRef<Path>,
+        F: FnMut(u64, Option<u64>),
+    {
+        let compatible_version = ModelVersion(crate::get_compatible_model_version());
+        aic_model_downloader::download_with_progress(
+            model_id,
+            compatible_version,
+            download_dir,
+            &DownloadOptions::default(),
+            progress,
+        )
+        .map_err(AicError::from)
+    }
+
+    /// Downloads a model file into a cache shared by all applications on this machine.
+    ///
+    /// Behaves like [`Model::download`], but stores the model file by its checksum in a
+    /// shared cache directory instead of a caller-provided one, so services that use the
+    /// same model store it only once. The cache directory is read from the
+    /// `AIC_MODEL_CACHE_DIR` environment variable and defaults to an `aic-models` directory in
+    /// the platform's cache directory, e.g. `~/.cache/aic-models` on Linux.
+    ///
+    /// Concurrent downloads of the same model, also from different processes, are safe: each
+    /// writes to its own temporary file, which is atomically renamed into place after the
+    /// checksum has been verified.
+    ///
+    /// # Arguments
+    ///
+    /// * `model_id` - The model identifier (e.g., `"quail-l-16khz"`).
+    ///
+    /// # Returns
+    ///
+    /// Returns the full path to the cached model file on success, or an [`AicError`] if the
+    /// operation fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::Model;
+    /// let path = Model::download_cached("quail-l-16khz")?;
+    /// let model = Model::from_file(&path)?;
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This is a blocking operation that performs network I/O.
+    #[cfg(feature = "download-model")]
+    pub fn download_cached(model_id: &str) -> Result<std::path::PathBuf, AicError> {
+        let compatible_version = ModelVersion(crate::get_compatible_model_version());
+        aic_model_downloader::download_cached(model_id, compatible_version).map_err(AicError::from)
+    }
+
+    /// Downloads a model from a build script so it can be embedded with [`embed_model!`].
+    ///
+    /// The model is stored in the shared cache of [`Model::download_cached`], so it is only
+    /// downloaded once per checksum, no matter how many crates or build directories embed it.
+    /// The cached path is passed to the compiler in an environment variable derived from the
+    /// model id, which [`embed_model!`] reads with the same model id.
+    ///
+    /// # Arguments
+    ///
+    /// * `model_id` - The model identifier (e.g., `"quail-xxs-48khz"`).
+    ///
+    /// # Returns
+    ///
+    /// Returns the full path to the cached model file on success, or an [`AicError`] if the
+    /// operation fails.
+    ///
+    /// # Example
+    ///
+    /// In `build.rs`, with `aic-sdk` and its `download-model` feature in `[build-dependencies]`:
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::Model;
+    /// Model::download_for_embedding("quail-xxs-48khz")?;
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This is a blocking operation that performs network I/O. It prints Cargo build script
+    /// instructions, so only call it from `build.rs`. These include `rerun-if-changed` for the
+    /// cached file, so Cargo no longer reruns the build script on every change to the package.
+    #[cfg(feature = "download-model")]
+    pub fn download_for_embedding(model_id: &str) -> Result<std::path::PathBuf, AicError> {
+        let path = Self::download_cached(model_id)?;
+        // Only rerun the build script, and with it the manifest request, if the cached file
+        // goes away
+        println!("cargo:rerun-if-changed={}", path.display());
+        println!("cargo:rerun-if-env-changed=AIC_MODEL_CACHE_DIR");
+        // Read by `embed_model!`, keep the variable name in sync
+        println!(
+            "cargo:rustc-env=AIC_EMBED_MODEL_{model_id}={}",
+            path.display()
+        );
+        Ok(path)
+    }
+
+    /// Lists the models available on the ai-coustics artifact CDN without downloading them.
+    ///
+    /// Each entry contains the model id and the model file versions it is available in.
+    /// Only models available in the version returned by
+    /// [`get_compatible_model_version`](crate::get_compatible_model_version) can be loaded
+    /// by this SDK, use [`ModelInfo::supports_version`] to filter for them.
+    ///
+    /// # Returns
+    ///
+    /// Returns the available models sorted by id, or an [`AicError`] if the manifest
+    /// could not be downloaded.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::Model;
+    /// let compatible_version = aic_sdk::get_compatible_model_version();
+    /// for model in Model::list_available()? {
+    ///     if model.supports_version(compatible_version) {
+    ///         println!("{}", model.id);
+    ///     }
+    /// }
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This is a blocking operation that performs network I/O.
+    #[cfg(feature = "download-model")]
+    pub fn list_available() -> Result<Vec<ModelInfo>, AicError> {
+        aic_model_downloader::list_models().map_err(AicError::from)
+    }
+
+    /// Downloads a model file from the ai-coustics artifact CDN without blocking the caller.
+    ///
+    /// Behaves like [`Model::download`], including checksum verification and retries, but
+    /// returns a future. The download runs on a dedicated background thread that is spawned
+    /// when the future is first polled, so the future does not depend on a specific async
+    /// runtime and can be awaited on any executor (Tokio, async-std, smol, ...).
+    ///
+    /// Use the blocking [`Model::download`] in contexts where async is inconvenient, such as
+    /// build scripts.
+    ///
+    /// # Arguments
+    ///
+    /// * `model_id` - The model identifier (e.g., `"quail-l-16khz"`).
+    /// * `download_dir` - Directory where the model file will be stored.
+    ///
+    /// # Returns
+    ///
+    /// Returns a future resolving to the full path to the model file on success, or an
+    /// [`AicError`] if the operation fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::Model;
+    /// # async fn example() -> Result<(), aic_sdk::AicError> {
+    /// let model_path = Model::download_async("quail-l-16khz", "models").await?;
+    /// let model = Model::from_file(&model_path)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async-download")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async-download")))]
+    pub fn download_async<P: AsRef<Path>>(
+        model_id: &str,
+        download_dir: P,
+    ) -> impl Future<Output = Result<std::path::PathBuf, AicError>> + Send + 'static {
+        let model_id = model_id.to_owned();
+        let download_dir = download_dir.as_ref().to_path_buf();
+
+        async move {
+            let (tx, rx) = futures_channel::oneshot::channel();
+            std::thread::Builder::new()
+                .name("aic-model-download".to_string())
+                .spawn(move || {
+                    let _ = tx.send(Model::download(&model_id, download_dir));
+                })
+                .map_err(|err| AicError::ModelDownload(ModelDownloadError::new(err, false)))?;
+            // The sender is only dropped without a result if the download thread panicked
+            rx.await.unwrap_or_else(|_| {
+                let err = std::io::Error::other("model download thread panicked");
+                Err(AicError::ModelDownload(ModelDownloadError::new(err, false)))
+            })
+        }
     }
 
     pub(crate) fn as_const_ptr(&self) -> *const AicModel {
-        self.ptr as *const AicModel
+        self.handle.0 as *const AicModel
+    }
+}
+
+/// Creates a C model structure that reads its weights from `buffer`.
+///
+/// The caller must keep `buffer` alive and unchanged until the returned model is destroyed.
+fn create_from_buffer(buffer: &[u8]) -> Result<*mut AicModel, AicError> {
+    let mut model_ptr: *mut AicModel = ptr::null_mut();
+    #[cfg(feature = "log")]
+    log::trace!("Loading model from a buffer of {} bytes", buffer.len());
+
+    // Checked here as well, so the requirement holds even if the SDK stops checking it.
+    if !(buffer.as_ptr() as usize).is_multiple_of(MODEL_ALIGNMENT) {
+        #[cfg(feature = "log")]
+        log::warn!("Model buffer is not aligned to {MODEL_ALIGNMENT} bytes");
+        return Err(AicError::ModelDataUnaligned);
+    }
+
+    // SAFETY:
+    // - `buffer` is a valid slice, and callers keep it alive and immutable for the lifetime
+    //   of the model.
+    // - The SDK only reads from `buffer` for the lifetime of the model.
+    // - This function is not thread-safe, but the output pointer is local to
+    //   this call and no model handle exists until it returns.
+    let error_code =
+        unsafe { aic_model_create_from_buffer(&mut model_ptr, buffer.as_ptr(), buffer.len()) };
+
+    handle_error(error_code).inspect_err(|_err| {
+        #[cfg(feature = "log")]
+        log::warn!("Failed to load model from buffer: {_err}");
+    })?;
+
+    // This should never happen if the C library is well-behaved, but let's be defensive
+    assert!(
+        !model_ptr.is_null(),
+        "C library returned success but null pointer"
+    );
+
+    Ok(model_ptr)
+}
+
+/// Structured information about a loaded [`Model`].
+///
+/// Returned by [`Model::metadata`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ModelMetadata {
+    /// The model identifier, as returned by [`Model::id`].
+    pub id: String,
+    /// The model's native sample rate in Hz, as returned by [`Model::optimal_sample_rate`].
+    pub optimal_sample_rate: u32,
+    /// The optimal number of frames at [`ModelMetadata::optimal_sample_rate`].
+    pub optimal_num_frames: usize,
+    /// The model version supported by this SDK, as returned by
+    /// [`get_compatible_model_version`](crate::get_compatible_model_version).
+    pub compatible_model_version: u32,
+}
+
+// Only reports whether the handle is set, the SDK model is never accessed.
+impl fmt::Debug for Model<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Model")
+            .field("non_null", &!self.handle.0.is_null())
+            .finish_non_exhaustive()
     }
 }
 
-impl<'a> Drop for Model<'a> {
+impl Drop for ModelHandle {
     fn drop(&mut self) {
-        if !self.ptr.is_null() {
+        if !self.0.is_null() {
             // SAFETY:
-            // - `self.ptr` was allocated by the SDK and is still owned by this wrapper.
-            // - This function is not thread-safe with concurrent model use, but
-            //   `drop` has exclusive access to `self`.
-            unsafe { aic_model_destroy(self.ptr) };
+            // - `self.0` was allocated by the SDK and is still owned by this handle.
+            // - This function is not thread-safe with concurrent model use, but the handle is
+            //   only dropped once the last `Model` clone referencing it is gone.
+            unsafe { aic_model_destroy(self.0) };
         }
     }
 }
 
 // SAFETY:
-// - Model wraps a raw pointer to an AicModel which is immutable after creation and it
+// - ModelHandle wraps a raw pointer to an AicModel which is immutable after creation and it
 //   does not provide access to it through its public API.
 // - Methods only pass the pointer to SDK calls documented as thread-safe for const access.
-unsafe impl<'a> Send for Model<'a> {}
+unsafe impl Send for ModelHandle {}
 // SAFETY:
-// - Model wraps a raw pointer to an AicModel which is immutable after creation and it
+// - ModelHandle wraps a raw pointer to an AicModel which is immutable after creation and it
 //   does not provide access to it through its public API.
 // - Methods only pass the pointer to SDK calls documented as thread-safe for const access.
-unsafe impl<'a> Sync for Model<'a> {}
+unsafe impl Sync for ModelHandle {}
 
 /// Embeds the bytes of model file, ensuring proper alignment.
 ///
 /// This macro uses Rust's standard library's [`include_bytes!`](std::include_bytes) macro
-/// to include the model file at compile time.
+/// to include the model file at compile time. The data is aligned to [`MODEL_ALIGNMENT`]
+/// bytes, which is checked at compile time.
 ///
 /// # Example
 ///
@@ -375,6 +1063,11 @@ macro_rules! include_model {
         #[repr(C, align(64))]
         struct __Aligned<T: ?Sized>(T);
 
+        const _: () = assert!(
+            ::core::mem::align_of::<__Aligned<[u8; 0]>>() >= $crate::MODEL_ALIGNMENT,
+            "include_model! must align data to aic_sdk::MODEL_ALIGNMENT"
+        );
+
         const __DATA: &'static __Aligned<[u8; include_bytes!($path).len()]> =
             &__Aligned(*include_bytes!($path));
 
@@ -382,9 +1075,154 @@ macro_rules! include_model {
     }};
 }
 
+/// Embeds a model that was downloaded by the build script, ensuring proper alignment.
+///
+/// Takes the model id passed to [`Model::download_for_embedding`] in `build.rs` and embeds the
+/// downloaded file with [`include_model!`], so neither the path nor an environment variable
+/// has to be handled by hand. Compilation fails if the build script did not download the
+/// model.
+///
+/// # Example
+///
+/// `build.rs`, with `aic-sdk` and its `download-model` feature in `[build-dependencies]`:
+///
+/// ```rust,ignore
+/// fn main() {
+///     aic_sdk::Model::download_for_embedding("quail-xxs-48khz").unwrap();
+/// }
+/// ```
+///
+/// `src/main.rs`:
+///
+/// ```rust,ignore
+/// # use aic_sdk::{embed_model, Model};
+///
+/// static MODEL: &'static [u8] = embed_model!("quail-xxs-48khz");
+/// let model = Model::from_buffer(MODEL)?;
+/// # Ok::<(), aic_sdk::AicError>(())
+/// ```
+#[macro_export]
+macro_rules! embed_model {
+    ($model_id:literal) => {
+        $crate::include_model!(::core::env!(
+            ::core::concat!("AIC_EMBED_MODEL_", $model_id),
+            "model not downloaded, call `aic_sdk::Model::download_for_embedding` with this model id from build.rs"
+        ))
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::get_rook_s_48khz;
+    use std::{fs, path::PathBuf};
+
+    fn load_test_model() -> Model<'static> {
+        Model::from_file(get_rook_s_48khz().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn model_id_is_not_empty() {
+        let model = load_test_model();
+        assert!(!model.id().is_empty());
+        assert_eq!(model.id(), model.id(), "id should be stable across calls");
+    }
+
+    #[test]
+    fn optimal_sample_rate_is_not_zero() {
+        let model = load_test_model();
+        assert!(model.optimal_sample_rate() > 0);
+    }
+
+    #[test]
+    fn optimal_num_frames_scales_with_sample_rate() {
+        let model = load_test_model();
+
+        let frames_16khz = model.optimal_num_frames(16000);
+        let frames_48khz = model.optimal_num_frames(48000);
+
+        assert!(frames_16khz > 0);
+        assert!(frames_48khz > 0);
+        assert!(
+            frames_48khz > frames_16khz,
+            "higher sample rates should require more frames for the same window duration \
+             (16 kHz: {frames_16khz}, 48 kHz: {frames_48khz})"
+        );
+    }
+
+    #[test]
+    fn optimal_sample_rate_is_supported() {
+        let model = load_test_model();
+        let optimal = model.optimal_sample_rate();
+
+        let sample_rates = model.supported_sample_rates();
+        assert!(sample_rates.contains(&optimal));
+        assert!(sample_rates.is_sorted());
+        assert!(sample_rates.iter().all(|&rate| model.supports(rate, 1)));
+        assert!(model.supports(optimal, model.optimal_num_frames(optimal)));
+    }
+
+    #[test]
+    fn configs_outside_the_limits_are_not_supported() {
+        let model = load_test_model();
+
+        assert!(!model.supports(Model::MIN_SAMPLE_RATE - 1, 480));
+        assert!(!model.supports(Model::MAX_SAMPLE_RATE + 1, 480));
+        assert!(!model.supports(48000, 0));
+    }
+
+    #[test]
+    fn metadata_matches_individual_getters() {
+        let model = load_test_model();
+        let metadata = model.metadata();
+
+        assert_eq!(metadata.id, model.id());
+        assert_eq!(metadata.optimal_sample_rate, model.optimal_sample_rate());
+        assert_eq!(
+            metadata.optimal_num_frames,
+            model.optimal_num_frames(model.optimal_sample_rate())
+        );
+        assert_eq!(
+            metadata.compatible_model_version,
+            crate::get_compatible_model_version()
+        );
+    }
+
+    #[test]
+    fn optimal_processor_config_matches_processor_config_optimal() {
+        let model = load_test_model();
+        let config = model.optimal_processor_config();
+
+        assert_eq!(config, ProcessorConfig::optimal(&model));
+        assert_eq!(config.sample_rate, model.optimal_sample_rate());
+        assert_eq!(
+            config.num_frames,
+            model.optimal_num_frames(model.optimal_sample_rate())
+        );
+        assert_eq!(config.num_channels, 1);
+        assert!(!config.allow_variable_frames);
+    }
+
+    #[test]
+    fn download_errors_map_to_model_download() {
+        let error = AicError::from(aic_model_downloader::Error::ModelNotFound(
+            "does-not-exist".to_string(),
+        ));
+
+        assert_eq!(error.to_string(), "Model download error");
+        assert_eq!(
+            std::error::Error::source(&error).unwrap().to_string(),
+            "Model `does-not-exist` not found in manifest"
+        );
+        assert!(matches!(
+            &error,
+            AicError::ModelDownload(err) if matches!(
+                err.downcast_ref(),
+                Some(aic_model_downloader::Error::ModelNotFound(id)) if id == "does-not-exist"
+            )
+        ));
+        assert!(!error.is_transient());
+    }
 
     #[test]
     fn include_model_aligns_to_64_bytes() {
@@ -396,6 +1234,128 @@ mod tests {
             ptr.is_multiple_of(64),
             "include_model should align data to 64 bytes"
         );
+        assert!(ptr.is_multiple_of(MODEL_ALIGNMENT));
+    }
+
+    #[test]
+    fn from_buffer_rejects_unaligned_buffers() {
+        let data = include_model!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"));
+
+        let error = Model::from_buffer(&data[1..]).unwrap_err();
+        assert_eq!(error, AicError::ModelDataUnaligned);
+    }
+
+    #[test]
+    fn model_buffer_is_aligned_and_keeps_data() {
+        let data: Vec<u8> = (0..200u8).collect();
+        let aligned = include_model!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"));
+
+        for buffer in [data[1..].to_vec(), aligned.to_vec()] {
+            let owned = ModelBuffer::new(buffer.clone());
+            assert!((owned.as_slice().as_ptr() as usize).is_multiple_of(MODEL_ALIGNMENT));
+            assert_eq!(owned.as_slice(), buffer.as_slice());
+        }
+    }
+
+    #[test]
+    fn owned_buffer_models_outlive_the_caller() {
+        let license_key = std::env::var("AIC_SDK_LICENSE")
+            .expect("AIC_SDK_LICENSE environment variable must be set for tests");
+        let bytes = fs::read(get_rook_s_48khz().unwrap()).unwrap();
+        let model = Model::from_buffer_owned(bytes).unwrap();
+        assert_eq!(model.id(), load_test_model().id());
+
+        let handle = std::thread::spawn(move || {
+            let config = ProcessorConfig::optimal(&model);
+            let mut processor = crate::Processor::new(&model, &license_key)
+                .unwrap()
+                .with_config(&config)
+                .unwrap();
+            drop(model);
+
+            let mut audio = vec![0.0f32; config.num_frames];
+            processor.process_interleaved(&mut audio).unwrap();
+        });
+        handle.join().unwrap();
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn mapped_models_can_process_audio() {
+        let license_key = std::env::var("AIC_SDK_LICENSE")
+            .expect("AIC_SDK_LICENSE environment variable must be set for tests");
+        // SAFETY: The test model is not modified while the tests run.
+        let model = unsafe { Model::from_mmap(get_rook_s_48khz().unwrap()) }.unwrap();
+        assert_eq!(model.id(), load_test_model().id());
+
+        let config = ProcessorConfig::optimal(&model);
+        let mut processor = crate::Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+        drop(model);
+
+        let mut audio = vec![0.0f32; config.num_frames];
+        processor.process_interleaved(&mut audio).unwrap();
+    }
+
+    #[test]
+    fn owned_buffer_rejects_invalid_data() {
+        assert!(Model::from_buffer_owned(vec![0u8; 256]).is_err());
+    }
+
+    #[test]
+    fn garbage_buffers_are_reported_as_invalid_models() {
+        let garbage: Vec<u8> = (0..4096u32).map(|i| (i * 31 % 251) as u8).collect();
+        let buffer = ModelBuffer::new(garbage);
+
+        assert_eq!(
+            Model::from_buffer(buffer.as_slice()).unwrap_err(),
+            AicError::ModelInvalid
+        );
+    }
+
+    #[test]
+    fn missing_model_files_are_reported_before_loading() {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("target/missing.aicmodel");
+
+        assert_eq!(
+            Model::from_file(&path).unwrap_err(),
+            AicError::ModelFileNotFound(path)
+        );
+    }
+
+    #[test]
+    fn cloned_models_can_be_used_in_separate_processors() {
+        let license_key = std::env::var("AIC_SDK_LICENSE")
+            .expect("AIC_SDK_LICENSE environment variable must be set for tests");
+        let model = load_test_model();
+        let clone = model.clone();
+        assert_eq!(clone.id(), model.id());
+        assert_eq!(clone.as_const_ptr(), model.as_const_ptr());
+
+        let config = ProcessorConfig::optimal(&model);
+        let mut first = crate::Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+
+        // The clone keeps the model alive after the original is dropped
+        drop(model);
+
+        let thread_config = config.clone();
+        let handle = std::thread::spawn(move || {
+            let mut second = crate::Processor::new(&clone, &license_key)
+                .unwrap()
+                .with_config(&thread_config)
+                .unwrap();
+            let mut audio = vec![0.0f32; thread_config.num_frames];
+            second.process_interleaved(&mut audio).unwrap();
+        });
+
+        let mut audio = vec![0.0f32; config.num_frames];
+        first.process_interleaved(&mut audio).unwrap();
+        handle.join().unwrap();
     }
 
     #[test]
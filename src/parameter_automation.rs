@@ -0,0 +1,264 @@
+use crate::{
+    error::AicError,
+    processor::{ProcessorContext, ProcessorParameter},
+};
+
+use std::sync::{
+    Arc,
+    atomic::{AtomicU64, AtomicUsize, Ordering},
+};
+
+/// Creates a sender/automation pair for passing parameter changes to the audio thread.
+///
+/// The [`ParameterSender`] is designed to be used by a UI or control thread, the
+/// [`ParameterAutomation`] by the audio thread, which applies all pending changes once per
+/// block with [`ParameterAutomation::apply_pending`]. This way parameter changes always take
+/// effect at block boundaries.
+///
+/// The queue is a lock-free single-producer single-consumer ring buffer. It is allocated once
+/// here, so neither side allocates or blocks afterwards.
+///
+/// # Arguments
+///
+/// * `capacity` - Maximum number of changes that can be pending at once. Must not be zero.
+///
+/// # Panics
+///
+/// Panics if `capacity` is zero.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use aic_sdk::{Model, Processor, ProcessorConfig, ProcessorParameter};
+/// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+/// # let model = Model::from_file("/path/to/model.aicmodel")?;
+/// # let config = ProcessorConfig::optimal(&model);
+/// let mut processor = Processor::new(&model, &license_key)?.with_config(&config)?;
+/// let (mut sender, mut automation) = aic_sdk::parameter_automation(64);
+///
+/// // UI thread
+/// sender.push(ProcessorParameter::EnhancementLevel, 0.5);
+///
+/// // Audio thread, once per block
+/// let context = processor.processor_context();
+/// let mut audio = vec![0.0f32; config.num_frames];
+/// automation.apply_pending(&context)?;
+/// processor.process_interleaved(&mut audio)?;
+/// # Ok::<(), aic_sdk::AicError>(())
+/// ```
+pub fn parameter_automation(capacity: usize) -> (ParameterSender, ParameterAutomation) {
+    assert!(
+        capacity > 0,
+        "Parameter automation capacity must not be zero"
+    );
+
+    let queue = Arc::new(Queue {
+        slots: (0..capacity).map(|_| AtomicU64::new(0)).collect(),
+        head: AtomicUsize::new(0),
+        tail: AtomicUsize::new(0),
+    });
+
+    (
+        ParameterSender {
+            queue: Arc::clone(&queue),
+        },
+        ParameterAutomation { queue },
+    )
+}
+
+/// Shared ring buffer of packed parameter changes.
+///
+/// `head` is only written by the consumer, `tail` only by the producer. Both count changes
+/// since creation and are mapped to slots modulo the capacity.
+struct Queue {
+    slots: Box<[AtomicU64]>,
+    head: AtomicUsize,
+    tail: AtomicUsize,
+}
+
+/// Packs a change into a single word, so slots can be plain atomics.
+fn pack(parameter: ProcessorParameter, value: f32) -> u64 {
+    let index = ProcessorParameter::ALL
+        .iter()
+        .position(|&p| p == parameter)
+        .expect("all parameters are listed in ProcessorParameter::ALL");
+    ((index as u64) << 32) | u64::from(value.to_bits())
+}
+
+fn unpack(packed: u64) -> (ProcessorParameter, f32) {
+    let parameter = ProcessorParameter::ALL[(packed >> 32) as usize];
+    (parameter, f32::from_bits(packed as u32))
+}
+
+/// Sending half of [`parameter_automation`], used by the UI or control thread.
+pub struct ParameterSender {
+    queue: Arc<Queue>,
+}
+
+impl ParameterSender {
+    /// Queues a parameter change.
+    ///
+    /// # Arguments
+    ///
+    /// * `parameter` - Parameter to modify
+    /// * `value` - New parameter value. See parameter documentation for ranges
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the change was queued, or `false` if the queue is full. Values are
+    /// validated when they are applied, see [`ParameterAutomation::apply_pending`].
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Never blocks or allocates.
+    pub fn push(&mut self, parameter: ProcessorParameter, value: f32) -> bool {
+        let queue = &*self.queue;
+        let tail = queue.tail.load(Ordering::Relaxed);
+        let head = queue.head.load(Ordering::Acquire);
+
+        if tail.wrapping_sub(head) == queue.slots.len() {
+            return false;
+        }
+
+        queue.slots[tail % queue.slots.len()].store(pack(parameter, value), Ordering::Relaxed);
+        queue.tail.store(tail.wrapping_add(1), Ordering::Release);
+        true
+    }
+}
+
+/// Receiving half of [`parameter_automation`], used by the audio thread.
+pub struct ParameterAutomation {
+    queue: Arc<Queue>,
+}
+
+impl ParameterAutomation {
+    /// Applies all pending parameter changes in the order they were pushed.
+    ///
+    /// Call this once per block, before processing it.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - Context of the processor the changes are applied to.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of changes applied, or the [`AicError`] of the first change that
+    /// was rejected, e.g. [`AicError::ParameterOutOfRange`]. The rejected change is dropped,
+    /// changes after it stay pending until the next call.
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Can be called from audio processing threads.
+    pub fn apply_pending(&mut self, context: &ProcessorContext) -> Result<usize, AicError> {
+        let queue = &*self.queue;
+        let mut head = queue.head.load(Ordering::Relaxed);
+        let tail = queue.tail.load(Ordering::Acquire);
+        let mut applied = 0;
+
+        while head != tail {
+            let packed = queue.slots[head % queue.slots.len()].load(Ordering::Relaxed);
+            head = head.wrapping_add(1);
+            queue.head.store(head, Ordering::Release);
+
+            let (parameter, value) = unpack(packed);
+            context.set_parameter(parameter, value)?;
+            applied += 1;
+        }
+
+        Ok(applied)
+    }
+
+    /// Returns the number of changes waiting to be applied.
+    pub fn pending(&self) -> usize {
+        let head = self.queue.head.load(Ordering::Relaxed);
+        let tail = self.queue.tail.load(Ordering::Acquire);
+        tail.wrapping_sub(head)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{processor::Processor, test_support::load_test_model};
+
+    #[test]
+    fn changes_round_trip_through_packing() {
+        for parameter in ProcessorParameter::ALL {
+            for value in [0.0, 0.25, 1.0, -1.5] {
+                assert_eq!(unpack(pack(parameter, value)), (parameter, value));
+            }
+        }
+    }
+
+    #[test]
+    fn push_fails_when_full() {
+        let (mut sender, automation) = parameter_automation(2);
+
+        assert!(sender.push(ProcessorParameter::Bypass, 1.0));
+        assert!(sender.push(ProcessorParameter::Bypass, 0.0));
+        assert!(!sender.push(ProcessorParameter::Bypass, 1.0));
+        assert_eq!(automation.pending(), 2);
+    }
+
+    #[test]
+    fn rejected_changes_do_not_block_the_queue() {
+        let (model, license_key) = load_test_model().unwrap();
+        let processor = Processor::new(&model, &license_key).unwrap();
+        let context = processor.processor_context();
+        let (mut sender, mut automation) = parameter_automation(4);
+
+        sender.push(ProcessorParameter::EnhancementLevel, 2.0);
+        sender.push(ProcessorParameter::EnhancementLevel, 0.5);
+
+        assert_eq!(
+            automation.apply_pending(&context),
+            Err(AicError::ParameterOutOfRange)
+        );
+        assert_eq!(automation.apply_pending(&context), Ok(1));
+        assert_eq!(
+            context.parameter(ProcessorParameter::EnhancementLevel),
+            Ok(0.5)
+        );
+    }
+
+    #[test]
+    fn changes_from_another_thread_are_applied_in_order() {
+        let (model, license_key) = load_test_model().unwrap();
+        let processor = Processor::new(&model, &license_key).unwrap();
+        let context = processor.processor_context();
+        let (mut sender, mut automation) = parameter_automation(8);
+
+        const NUM_CHANGES: usize = 1000;
+        let values: Vec<f32> = (0..NUM_CHANGES)
+            .map(|i| i as f32 / NUM_CHANGES as f32)
+            .collect();
+
+        let ui_values = values.clone();
+        let ui_thread = std::thread::spawn(move || {
+            for value in ui_values {
+                // Spin until the audio thread has made room
+                while !sender.push(ProcessorParameter::EnhancementLevel, value) {
+                    std::thread::yield_now();
+                }
+            }
+        });
+
+        let mut applied = 0;
+        while applied < NUM_CHANGES {
+            let before = applied;
+            applied += automation.apply_pending(&context).unwrap();
+
+            // Each block sees the most recent change pushed before it was drained
+            if applied > before {
+                assert_eq!(
+                    context.parameter(ProcessorParameter::EnhancementLevel),
+                    Ok(values[applied - 1])
+                );
+            }
+            std::thread::yield_now();
+        }
+
+        ui_thread.join().unwrap();
+        assert_eq!(automation.pending(), 0);
+    }
+}
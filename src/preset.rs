@@ -0,0 +1,459 @@
+use crate::{
+    error::AicError,
+    processor::{Processor, ProcessorConfig, ProcessorParameter},
+    vad::VadParameter,
+};
+
+use std::{
+    collections::BTreeMap,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use thiserror::Error;
+
+/// A complete processor setup: the configuration plus processor and VAD parameter values.
+///
+/// Presets make it easy to store user settings. Use [`Preset::capture`] to take a snapshot of
+/// a live processor and [`Preset::apply`] to restore it on the same or a fresh processor.
+/// With the `serde` feature enabled, presets can be serialized to any format supported by
+/// serde, e.g. JSON or TOML. Parameters are keyed by their snake case names, so stored presets
+/// stay valid when new parameters are added.
+///
+/// Presets implement [`Eq`] and [`Hash`], so they can be used as cache keys, e.g. together
+/// with [`Model::id`](crate::Model::id). Parameter values are compared by their bit pattern:
+/// `0.0` and `-0.0` are different values, and a `NaN` is equal to itself.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use aic_sdk::{Model, Preset, Processor, ProcessorConfig, ProcessorParameter};
+/// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+/// # let model = Model::from_file("/path/to/model.aicmodel")?;
+/// let preset = Preset::new(ProcessorConfig::optimal(&model).with_num_channels(2))
+///     .with_parameter(ProcessorParameter::EnhancementLevel, 0.8);
+///
+/// let mut processor = Processor::new(&model, &license_key)?;
+/// preset.apply(&mut processor)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Preset {
+    /// The audio processing configuration.
+    pub config: ProcessorConfig,
+    /// Processor parameter values. Parameters that are not listed keep their current value.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub parameters: BTreeMap<ProcessorParameter, f32>,
+    /// VAD parameter values. Parameters that are not listed keep their current value.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub vad_parameters: BTreeMap<VadParameter, f32>,
+}
+
+impl Preset {
+    /// Creates a preset with the given configuration and no parameter values.
+    pub fn new(config: ProcessorConfig) -> Self {
+        Self {
+            config,
+            parameters: BTreeMap::new(),
+            vad_parameters: BTreeMap::new(),
+        }
+    }
+
+    /// Takes a snapshot of the configuration and all parameter values of a processor.
+    ///
+    /// # Arguments
+    ///
+    /// * `processor` - The processor to capture. It must be initialized.
+    ///
+    /// # Returns
+    ///
+    /// Returns the captured preset, or a [`PresetError`] naming the field that could not be
+    /// read. Fails with [`PresetField::Config`] and [`AicError::ProcessorNotInitialized`] if
+    /// the processor has not been initialized, and with [`PresetField::Config`] if the SDK
+    /// fails to create a context to read the parameters.
+    ///
+    /// # Warning
+    /// Do not call from audio processing threads as this allocates memory.
+    pub fn capture(processor: &Processor) -> Result<Self, PresetError> {
+        let config = processor.config().cloned().ok_or(PresetError::new(
+            PresetField::Config,
+            AicError::ProcessorNotInitialized,
+        ))?;
+
+        let processor_context = processor
+            .try_processor_context()
+            .map_err(|error| PresetError::new(PresetField::Config, error))?;
+        let vad_context = processor
+            .try_vad_context()
+            .map_err(|error| PresetError::new(PresetField::Config, error))?;
+        let mut preset = Self::new(config);
+
+        for parameter in ProcessorParameter::ALL {
+            let value = processor_context
+                .parameter(parameter)
+                .map_err(|error| PresetError::new(PresetField::Parameter(parameter), error))?;
+            preset.parameters.insert(parameter, value);
+        }
+
+        for parameter in VadParameter::ALL {
+            let value = vad_context
+                .parameter(parameter)
+                .map_err(|error| PresetError::new(PresetField::VadParameter(parameter), error))?;
+            preset.vad_parameters.insert(parameter, value);
+        }
+
+        Ok(preset)
+    }
+
+    /// Applies the preset to a processor.
+    ///
+    /// The processor is (re)initialized with the preset's configuration first, then the
+    /// processor and VAD parameters are set. Parameters that are not part of the preset keep
+    /// their current value.
+    ///
+    /// # Arguments
+    ///
+    /// * `processor` - The processor to configure. It does not have to be initialized.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or a [`PresetError`] naming the first field that was
+    /// rejected, e.g. [`PresetField::Parameter`] with [`AicError::ParameterOutOfRange`].
+    /// Fields before the failing one have already been applied. If the configuration is
+    /// rejected, the processor has to be initialized again before it can be used. Failing to
+    /// create a context to set the parameters is reported as [`PresetField::Config`].
+    ///
+    /// # Warning
+    /// Do not call from audio processing threads as this allocates memory.
+    pub fn apply(&self, processor: &mut Processor) -> Result<(), PresetError> {
+        processor
+            .reinitialize(&self.config)
+            .map_err(|error| PresetError::new(PresetField::Config, error))?;
+
+        let processor_context = processor
+            .try_processor_context()
+            .map_err(|error| PresetError::new(PresetField::Config, error))?;
+        for (&parameter, &value) in &self.parameters {
+            processor_context
+                .set_parameter(parameter, value)
+                .map_err(|error| PresetError::new(PresetField::Parameter(parameter), error))?;
+        }
+
+        let vad_context = processor
+            .try_vad_context()
+            .map_err(|error| PresetError::new(PresetField::Config, error))?;
+        for (&parameter, &value) in &self.vad_parameters {
+            vad_context
+                .set_parameter(parameter, value)
+                .map_err(|error| PresetError::new(PresetField::VadParameter(parameter), error))?;
+        }
+
+        Ok(())
+    }
+
+    /// Sets the value of a processor parameter.
+    #[must_use]
+    pub fn with_parameter(mut self, parameter: ProcessorParameter, value: f32) -> Self {
+        self.parameters.insert(parameter, value);
+        self
+    }
+
+    /// Sets the value of a VAD parameter.
+    #[must_use]
+    pub fn with_vad_parameter(mut self, parameter: VadParameter, value: f32) -> Self {
+        self.vad_parameters.insert(parameter, value);
+        self
+    }
+}
+
+// `f32` is neither `Eq` nor `Hash`, so values are compared by their bits to keep `Eq` and
+// `Hash` consistent.
+impl PartialEq for Preset {
+    fn eq(&self, other: &Self) -> bool {
+        self.config == other.config
+            && values_eq(&self.parameters, &other.parameters)
+            && values_eq(&self.vad_parameters, &other.vad_parameters)
+    }
+}
+
+impl Eq for Preset {}
+
+impl Hash for Preset {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.config.hash(state);
+        hash_values(&self.parameters, state);
+        hash_values(&self.vad_parameters, state);
+    }
+}
+
+fn values_eq<K: Eq>(a: &BTreeMap<K, f32>, b: &BTreeMap<K, f32>) -> bool {
+    a.len() == b.len()
+        && a.iter()
+            .zip(b)
+            .all(|((ka, va), (kb, vb))| ka == kb && va.to_bits() == vb.to_bits())
+}
+
+fn hash_values<K: Hash, H: Hasher>(values: &BTreeMap<K, f32>, state: &mut H) {
+    values.len().hash(state);
+    for (key, value) in values {
+        key.hash(state);
+        value.to_bits().hash(state);
+    }
+}
+
+/// A field of a [`Preset`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PresetField {
+    /// The processor configuration.
+    Config,
+    /// A processor parameter.
+    Parameter(ProcessorParameter),
+    /// A VAD parameter.
+    VadParameter(VadParameter),
+}
+
+impl fmt::Display for PresetField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PresetField::Config => write!(f, "processor configuration"),
+            PresetField::Parameter(parameter) => write!(f, "processor parameter {parameter:?}"),
+            PresetField::VadParameter(parameter) => write!(f, "VAD parameter {parameter:?}"),
+        }
+    }
+}
+
+/// Error returned by [`Preset::capture`] and [`Preset::apply`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("Failed to access {field}: {error}")]
+pub struct PresetError {
+    /// The field that could not be read or applied.
+    pub field: PresetField,
+    /// The error reported by the SDK.
+    pub error: AicError,
+}
+
+impl PresetError {
+    fn new(field: PresetField, error: AicError) -> Self {
+        Self { field, error }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::load_test_model;
+
+    #[test]
+    fn capture_and_apply_restore_the_setup() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+        let source = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+        source
+            .processor_context()
+            .set_parameter(ProcessorParameter::EnhancementLevel, 0.5)
+            .unwrap();
+        source
+            .vad_context()
+            .set_parameter(VadParameter::MinimumSpeechDuration, 0.5)
+            .unwrap();
+
+        let preset = Preset::capture(&source).unwrap();
+        assert_eq!(preset.config, config);
+        assert_eq!(preset.parameters.len(), ProcessorParameter::ALL.len());
+        assert_eq!(preset.vad_parameters.len(), VadParameter::ALL.len());
+
+        let mut target = Processor::new(&model, &license_key).unwrap();
+        preset.apply(&mut target).unwrap();
+
+        assert_eq!(Preset::capture(&target).unwrap(), preset);
+    }
+
+    #[test]
+    fn capture_requires_an_initialized_processor() {
+        let (model, license_key) = load_test_model().unwrap();
+        let processor = Processor::new(&model, &license_key).unwrap();
+
+        assert_eq!(
+            Preset::capture(&processor),
+            Err(PresetError::new(
+                PresetField::Config,
+                AicError::ProcessorNotInitialized
+            ))
+        );
+    }
+
+    #[test]
+    fn apply_reports_the_failing_field() {
+        let (model, license_key) = load_test_model().unwrap();
+        let mut processor = Processor::new(&model, &license_key).unwrap();
+
+        let preset = Preset::new(ProcessorConfig::optimal(&model))
+            .with_parameter(ProcessorParameter::EnhancementLevel, 2.0);
+        let error = preset.apply(&mut processor).unwrap_err();
+        assert_eq!(
+            error.field,
+            PresetField::Parameter(ProcessorParameter::EnhancementLevel)
+        );
+        assert_eq!(error.error, AicError::ParameterOutOfRange);
+
+        let preset = Preset::new(ProcessorConfig::optimal(&model).with_num_channels(0));
+        let error = preset.apply(&mut processor).unwrap_err();
+        assert_eq!(error.field, PresetField::Config);
+    }
+
+    #[cfg(feature = "serde")]
+    fn preset() -> Preset {
+        Preset::new(ProcessorConfig {
+            sample_rate: 48000,
+            num_channels: 2,
+            num_frames: 480,
+            allow_variable_frames: false,
+        })
+        // Exactly representable as `f32`, so the values compare equal after a round trip
+        .with_parameter(ProcessorParameter::EnhancementLevel, 0.5)
+        .with_parameter(ProcessorParameter::Bypass, 0.0)
+        .with_vad_parameter(VadParameter::SpeechHoldDuration, 0.5)
+    }
+
+    fn hash_of(preset: &Preset) -> u64 {
+        let mut hasher = std::hash::DefaultHasher::new();
+        preset.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn equal_presets_have_equal_hashes() {
+        let a = Preset::new(ProcessorConfig::default())
+            .with_parameter(ProcessorParameter::EnhancementLevel, 0.5)
+            .with_vad_parameter(VadParameter::Sensitivity, 6.0);
+        let b = Preset::new(ProcessorConfig::default())
+            .with_vad_parameter(VadParameter::Sensitivity, 6.0)
+            .with_parameter(ProcessorParameter::EnhancementLevel, 0.5);
+
+        assert_eq!(a, b);
+        assert_eq!(hash_of(&a), hash_of(&b));
+    }
+
+    #[test]
+    fn presets_differing_in_any_field_are_unequal() {
+        let preset = Preset::new(ProcessorConfig::default())
+            .with_parameter(ProcessorParameter::EnhancementLevel, 0.5);
+
+        assert_ne!(
+            preset,
+            Preset::new(ProcessorConfig::default().with_num_channels(2))
+                .with_parameter(ProcessorParameter::EnhancementLevel, 0.5)
+        );
+        assert_ne!(
+            preset,
+            preset
+                .clone()
+                .with_parameter(ProcessorParameter::EnhancementLevel, 0.6)
+        );
+        assert_ne!(
+            preset,
+            preset
+                .clone()
+                .with_vad_parameter(VadParameter::Sensitivity, 6.0)
+        );
+        assert_ne!(preset, Preset::new(ProcessorConfig::default()));
+    }
+
+    #[test]
+    fn values_are_compared_by_bits() {
+        let with_value = |value| {
+            Preset::new(ProcessorConfig::default())
+                .with_parameter(ProcessorParameter::Bypass, value)
+        };
+
+        assert_ne!(with_value(0.0), with_value(-0.0));
+        assert_eq!(with_value(f32::NAN), with_value(f32::NAN));
+        assert_eq!(
+            hash_of(&with_value(f32::NAN)),
+            hash_of(&with_value(f32::NAN))
+        );
+    }
+
+    #[test]
+    fn presets_can_be_cache_keys_with_model_ids() {
+        let (model, _) = load_test_model().unwrap();
+        let preset = Preset::new(ProcessorConfig::optimal(&model));
+
+        let mut cache = std::collections::HashMap::new();
+        cache.insert((model.id().to_owned(), preset.clone()), 1);
+
+        assert_eq!(cache.get(&(model.id().to_owned(), preset)), Some(&1));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn preset_round_trips_through_json() {
+        let preset = preset();
+
+        let json = serde_json::to_string(&preset).unwrap();
+        let restored: Preset = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored, preset);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn parameters_are_serialized_by_name() {
+        let json = serde_json::to_value(preset()).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "config": {
+                    "sample_rate": 48000,
+                    "num_channels": 2,
+                    "num_frames": 480,
+                    "allow_variable_frames": false,
+                },
+                "parameters": {
+                    "bypass": 0.0,
+                    "enhancement_level": 0.5,
+                },
+                "vad_parameters": {
+                    "speech_hold_duration": 0.5,
+                },
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn vad_parameters_are_serialized_by_name() {
+        let json = serde_json::to_string(&VadParameter::SpeechHoldDuration).unwrap();
+        assert_eq!(json, "\"speech_hold_duration\"");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_names_match_parameter_names() {
+        for parameter in ProcessorParameter::ALL {
+            let json = serde_json::to_value(parameter).unwrap();
+            assert_eq!(json, parameter.as_str());
+        }
+        for parameter in VadParameter::ALL {
+            let json = serde_json::to_value(parameter).unwrap();
+            assert_eq!(json, parameter.as_str());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn parameters_are_optional() {
+        let json = r#"{"config":{"sample_rate":16000,"num_channels":1,"num_frames":160,"allow_variable_frames":true}}"#;
+
+        let preset: Preset = serde_json::from_str(json).unwrap();
+
+        assert!(preset.parameters.is_empty());
+        assert!(preset.vad_parameters.is_empty());
+        assert!(preset.config.allow_variable_frames);
+    }
+}
@@ -1,14 +1,25 @@
-use crate::{error::*, model::Model};
+use crate::{error::*, license::License, model::Model, processor_builder::ProcessorBuilder};
 
 use aic_sdk_sys::{AicProcessorParameter::*, *};
 
-use std::{ffi::CString, marker::PhantomData, ptr};
+use std::{
+    collections::VecDeque,
+    ffi::CString,
+    fmt, ptr,
+    str::FromStr,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
 
 /// Audio processing configuration passed to [`Processor::initialize`].
 ///
 /// Use [`ProcessorConfig::optimal`] as a starting point, then adjust fields
 /// to match your stream layout.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProcessorConfig {
     /// Sample rate in Hz (8000 - 192000).
     pub sample_rate: u32,
@@ -52,15 +63,11 @@ impl ProcessorConfig {
     /// };
     /// # Ok::<(), aic_sdk::AicError>(())
     /// ```
+    ///
+    /// This is equivalent to [`Model::optimal_processor_config`].
+    #[must_use]
     pub fn optimal(model: &Model) -> Self {
-        let sample_rate = model.optimal_sample_rate();
-        let num_frames = model.optimal_num_frames(sample_rate);
-        ProcessorConfig {
-            sample_rate,
-            num_channels: 1,
-            num_frames,
-            allow_variable_frames: false,
-        }
+        model.optimal_processor_config()
     }
 
     /// Sets the number of audio channels for processing.
@@ -68,6 +75,7 @@ impl ProcessorConfig {
     /// # Arguments
     ///
     /// * `num_channels` - Number of audio channels (1 for mono, 2 for stereo, etc.)
+    #[must_use]
     pub fn with_num_channels(mut self, num_channels: u16) -> Self {
         self.num_channels = num_channels;
         self
@@ -80,14 +88,98 @@ impl ProcessorConfig {
     /// # Arguments
     ///
     /// * `allow_variable_frames` - `true` to enable variable frame sizes, `false` for fixed size
+    #[must_use]
     pub fn with_allow_variable_frames(mut self, allow_variable_frames: bool) -> Self {
         self.allow_variable_frames = allow_variable_frames;
         self
     }
+
+    /// Converts a number of frames at the configured sample rate to a duration.
+    ///
+    /// The result is rounded to the nearest nanosecond, e.g. 441 frames at 44.1 kHz are
+    /// exactly 10 ms, while 1 frame at 44.1 kHz is 22,676 ns.
+    ///
+    /// # Arguments
+    ///
+    /// * `frames` - Number of frames, e.g. `num_frames` or an output delay.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aic_sdk::ProcessorConfig;
+    /// # use std::time::Duration;
+    /// let config = ProcessorConfig::default();
+    /// assert_eq!(config.frames_to_duration(config.num_frames), Duration::from_millis(10));
+    /// ```
+    pub fn frames_to_duration(&self, frames: usize) -> Duration {
+        if self.sample_rate == 0 {
+            return Duration::ZERO;
+        }
+
+        let sample_rate = u128::from(self.sample_rate);
+        let nanos = (frames as u128 * 1_000_000_000 + sample_rate / 2) / sample_rate;
+        Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX))
+    }
+
+    /// Converts a duration to the nearest number of frames at the configured sample rate.
+    ///
+    /// Durations that do not correspond to a whole number of frames are rounded to the
+    /// nearest frame, with halves rounded up. For example 10 ms at 22.05 kHz are 220.5 frames,
+    /// which is rounded to 221.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The duration to convert.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use aic_sdk::ProcessorConfig;
+    /// # use std::time::Duration;
+    /// let config = ProcessorConfig {
+    ///     sample_rate: 16000,
+    ///     ..Default::default()
+    /// };
+    /// let config = ProcessorConfig {
+    ///     num_frames: config.duration_to_frames(Duration::from_millis(20)),
+    ///     ..config
+    /// };
+    /// assert_eq!(config.num_frames, 320);
+    /// ```
+    pub fn duration_to_frames(&self, duration: Duration) -> usize {
+        let frames =
+            (duration.as_nanos() * u128::from(self.sample_rate) + 500_000_000) / 1_000_000_000;
+        usize::try_from(frames).unwrap_or(usize::MAX)
+    }
+}
+
+/// A generic configuration for tests and quick experiments: 48 kHz mono audio in blocks of
+/// 480 frames (10 ms) without variable frames.
+///
+/// This does not depend on a model and is not necessarily optimal for any of them. Processors
+/// add latency when the sample rate or frame count differ from the model's optimal values, so
+/// prefer [`ProcessorConfig::optimal`] in production code.
+impl Default for ProcessorConfig {
+    fn default() -> Self {
+        Self {
+            sample_rate: 48000,
+            num_channels: 1,
+            num_frames: 480,
+            allow_variable_frames: false,
+        }
+    }
 }
 
 /// Configurable parameters for audio enhancement
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// With the `serde` feature, parameters are serialized by their snake case names
+/// (e.g. `"enhancement_level"`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum ProcessorParameter {
     /// Controls whether audio processing is bypassed while preserving algorithmic delay.
     ///
@@ -114,6 +206,43 @@ pub enum ProcessorParameter {
     EnhancementLevel,
 }
 
+impl ProcessorParameter {
+    /// All parameters, in declaration order.
+    pub const ALL: [ProcessorParameter; 2] = [
+        ProcessorParameter::Bypass,
+        ProcessorParameter::EnhancementLevel,
+    ];
+
+    /// Returns the stable snake case name of the parameter, e.g. `"enhancement_level"`.
+    ///
+    /// The names match the serde representation and never change between releases.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProcessorParameter::Bypass => "bypass",
+            ProcessorParameter::EnhancementLevel => "enhancement_level",
+        }
+    }
+}
+
+impl fmt::Display for ProcessorParameter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ProcessorParameter {
+    type Err = ParseParameterError;
+
+    /// Parses a parameter from its name as returned by [`ProcessorParameter::as_str`].
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "bypass" => Ok(ProcessorParameter::Bypass),
+            "enhancement_level" => Ok(ProcessorParameter::EnhancementLevel),
+            _ => Err(ParseParameterError::new(name)),
+        }
+    }
+}
+
 impl From<ProcessorParameter> for AicProcessorParameter::Type {
     fn from(parameter: ProcessorParameter) -> Self {
         match parameter {
@@ -123,6 +252,97 @@ impl From<ProcessorParameter> for AicProcessorParameter::Type {
     }
 }
 
+/// How a [`Processor`] handles multichannel audio, as returned by [`Processor::channel_mode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChannelMode {
+    /// All channels are mixed to mono before enhancement, so channels are not enhanced
+    /// independently and stereo images are not preserved. To enhance channels independently,
+    /// use one [`Processor`] per channel.
+    Downmixed,
+}
+
+/// Input and output levels of a block, as returned by
+/// [`Processor::process_interleaved_metered`].
+///
+/// All vectors are indexed by channel. Levels are linear amplitudes, e.g. a full-scale sine
+/// has a peak of `1.0` and an RMS of about `0.707`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LevelMeter {
+    /// Largest absolute input sample per channel.
+    pub input_peak: Vec<f32>,
+    /// Largest absolute output sample per channel.
+    pub output_peak: Vec<f32>,
+    /// Root mean square of the input per channel.
+    pub input_rms: Vec<f32>,
+    /// Root mean square of the output per channel.
+    pub output_rms: Vec<f32>,
+}
+
+/// Computes the peak and RMS of each channel of interleaved audio.
+fn measure_levels(audio: &[f32], num_channels: usize) -> (Vec<f32>, Vec<f32>) {
+    let mut peak = vec![0.0f32; num_channels];
+    let mut sum_of_squares = vec![0.0f64; num_channels];
+
+    for frame in audio.chunks_exact(num_channels) {
+        for (channel, &sample) in frame.iter().enumerate() {
+            peak[channel] = peak[channel].max(sample.abs());
+            sum_of_squares[channel] += f64::from(sample) * f64::from(sample);
+        }
+    }
+
+    let num_frames = (audio.len() / num_channels).max(1) as f64;
+    let rms = sum_of_squares
+        .into_iter()
+        .map(|sum| (sum / num_frames).sqrt() as f32)
+        .collect();
+
+    (peak, rms)
+}
+
+/// Delays the dry signal of [`Processor::process_interleaved_mix`] by the output delay.
+struct DryMix {
+    /// Interleaved samples that have been read but not yet mixed, always `delay` samples long
+    delay_line: VecDeque<f32>,
+    /// Delayed dry samples of the current block
+    block: Vec<f32>,
+}
+
+impl DryMix {
+    fn new(delay: usize, max_block_len: usize) -> Self {
+        let mut delay_line = VecDeque::with_capacity(delay + max_block_len);
+        delay_line.resize(delay, 0.0);
+
+        Self {
+            delay_line,
+            block: vec![0.0; max_block_len],
+        }
+    }
+
+    /// Pushes `input` into the delay line and stores the delayed samples of the same length
+    /// at the start of `block`.
+    fn delay(&mut self, input: &[f32]) {
+        self.delay_line.extend(input);
+        for (delayed, sample) in self
+            .block
+            .iter_mut()
+            .zip(self.delay_line.drain(..input.len()))
+        {
+            *delayed = sample;
+        }
+    }
+
+    /// Reverts the last call to [`DryMix::delay`] with a block of `len` samples, e.g. because
+    /// processing the block failed.
+    fn undo(&mut self, len: usize) {
+        let delay = self.delay_line.len();
+        for &sample in self.block[..len].iter().rev() {
+            self.delay_line.push_front(sample);
+        }
+        self.delay_line.truncate(delay);
+    }
+}
+
 /// OpenTelemetry configuration for a [`Processor`].
 ///
 /// Pass to [`Processor::with_otel_config`] to control telemetry on a per-processor
@@ -172,15 +392,33 @@ impl OtelConfig {
     }
 }
 
+/// Pending ramps of [`ProcessorContext::set_parameter_smoothed`], one per entry of
+/// [`ProcessorParameter::ALL`]. Each packs the remaining blocks into the upper and the target
+/// value into the lower 32 bits, `0` means no ramp is pending.
+type ParameterRamps = [AtomicU64; ProcessorParameter::ALL.len()];
+
+/// Handle for changing the parameters of a [`Processor`] from any thread.
+///
+/// Created with [`Processor::processor_context`]. All contexts of a processor refer to the
+/// same processor instance.
+///
+/// `ProcessorContext` is `Send` and `Sync`, and all of its methods take `&self`, so a single
+/// context can be shared between a control thread and the audio thread, e.g. in an `Arc`.
+/// Parameter changes take effect with the next processed block.
 pub struct ProcessorContext {
     /// Raw pointer to the C processor context structure
     inner: *mut AicProcessorContext,
+    /// Pending ramps, shared with the processor and all of its other contexts
+    ramps: Arc<ParameterRamps>,
 }
 
 impl ProcessorContext {
     /// Creates a new Processor context.
-    pub(crate) fn new(ctx_ptr: *mut AicProcessorContext) -> Self {
-        Self { inner: ctx_ptr }
+    pub(crate) fn new(ctx_ptr: *mut AicProcessorContext, ramps: Arc<ParameterRamps>) -> Self {
+        Self {
+            inner: ctx_ptr,
+            ramps,
+        }
     }
 
     fn as_const_ptr(&self) -> *const AicProcessorContext {
@@ -201,6 +439,10 @@ impl ProcessorContext {
     ///
     /// Returns `Ok(())` on success or an [`AicError`] if the parameter cannot be set.
     ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Can be called from audio processing threads.
+    ///
     /// # Example
     ///
     /// ```rust,no_run
@@ -234,6 +476,10 @@ impl ProcessorContext {
     ///
     /// Returns `Ok(value)` containing the current parameter value, or an [`AicError`] if the query fails.
     ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Can be called from audio processing threads.
+    ///
     /// # Example
     ///
     /// ```rust,no_run
@@ -259,6 +505,145 @@ impl ProcessorContext {
         Ok(value)
     }
 
+    /// Ramps a processor parameter linearly to `target` over `ramp_blocks` blocks.
+    ///
+    /// Changing a parameter abruptly can cause an audible step. Instead of setting the value
+    /// right away, this stores a pending ramp that [`ProcessorContext::apply_ramps`] advances
+    /// by one step per call. **The host must call [`ProcessorContext::apply_ramps`] once per
+    /// processed block**, before processing it; the parameter reaches `target` with the
+    /// `ramp_blocks`-th call. Ramps are stored in the processor, so a ramp started through
+    /// one context is applied by [`ProcessorContext::apply_ramps`] on any context of the same
+    /// processor.
+    ///
+    /// Starting a new ramp for a parameter replaces its pending ramp, starting from the
+    /// parameter's current value.
+    ///
+    /// # Arguments
+    ///
+    /// * `parameter` - Parameter to ramp
+    /// * `target` - Value the parameter ends at. See parameter documentation for ranges
+    /// * `ramp_blocks` - Number of blocks the ramp takes. `0` sets the value immediately and
+    ///   cancels a pending ramp.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, [`AicError::ParameterOutOfRange`] if `target` is outside
+    /// 0.0 to 1.0, or any other [`AicError`] if the parameter cannot be set.
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Can be called from audio processing threads.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor, ProcessorConfig, ProcessorParameter};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// # let config = ProcessorConfig::optimal(&model);
+    /// let mut processor = Processor::new(&model, &license_key)?.with_config(&config)?;
+    /// let context = processor.processor_context();
+    ///
+    /// // Fade the enhancement in over 10 blocks
+    /// context.set_parameter_smoothed(ProcessorParameter::EnhancementLevel, 1.0, 10)?;
+    ///
+    /// let mut audio = vec![0.0f32; config.num_frames];
+    /// for _ in 0..10 {
+    ///     context.apply_ramps()?;
+    ///     processor.process_interleaved(&mut audio)?;
+    /// }
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn set_parameter_smoothed(
+        &self,
+        parameter: ProcessorParameter,
+        target: f32,
+        ramp_blocks: u32,
+    ) -> Result<(), AicError> {
+        // Both processor parameters range from 0.0 to 1.0
+        if !(0.0..=1.0).contains(&target) {
+            return Err(AicError::ParameterOutOfRange);
+        }
+
+        let ramp = self.ramp(parameter);
+        if ramp_blocks == 0 {
+            ramp.store(0, Ordering::Release);
+            return self.set_parameter(parameter, target);
+        }
+
+        ramp.store(
+            (u64::from(ramp_blocks) << 32) | u64::from(target.to_bits()),
+            Ordering::Release,
+        );
+        Ok(())
+    }
+
+    /// Advances all ramps started with [`ProcessorContext::set_parameter_smoothed`] by one
+    /// block.
+    ///
+    /// Call this once per block, before processing it. Each call moves every ramping
+    /// parameter an equal share of the remaining distance towards its target, so it reaches
+    /// the target exactly with the last block of the ramp.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of parameters that are still ramping afterwards, or an [`AicError`]
+    /// if a parameter cannot be read or set. A ramp whose step failed is not advanced, so the
+    /// next call retries it and the ramp still ends on its target.
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Can be called from audio processing threads.
+    pub fn apply_ramps(&self) -> Result<usize, AicError> {
+        let mut ramping = 0;
+
+        for parameter in ProcessorParameter::ALL {
+            let ramp = self.ramp(parameter);
+            let packed = ramp.load(Ordering::Acquire);
+            if packed == 0 {
+                continue;
+            }
+
+            let remaining = (packed >> 32) as u32;
+            let target = f32::from_bits(packed as u32);
+            let next = if remaining == 1 {
+                0
+            } else {
+                (u64::from(remaining - 1) << 32) | u64::from(target.to_bits())
+            };
+
+            let current = self.parameter(parameter)?;
+            let value = if remaining == 1 {
+                target
+            } else {
+                current + (target - current) / remaining as f32
+            };
+            // The step is only consumed once it was applied, so a failed call leaves the
+            // ramp to be retried with the next block
+            self.set_parameter(parameter, value)?;
+
+            // A ramp started in the meantime replaces this one, it starts from the value just
+            // set with the next block
+            if ramp
+                .compare_exchange(packed, next, Ordering::AcqRel, Ordering::Acquire)
+                .is_err()
+                || next != 0
+            {
+                ramping += 1;
+            }
+        }
+
+        Ok(ramping)
+    }
+
+    fn ramp(&self, parameter: ProcessorParameter) -> &AtomicU64 {
+        let index = ProcessorParameter::ALL
+            .iter()
+            .position(|&p| p == parameter)
+            .expect("all parameters are listed in ProcessorParameter::ALL");
+        &self.ramps[index]
+    }
+
     /// Returns the total output delay in samples for the current audio configuration.
     ///
     /// This function provides the complete end-to-end latency introduced by the processor,
@@ -281,8 +666,8 @@ impl ProcessorContext {
     ///   including any additional buffering introduced by non-optimal frame sizes
     ///
     /// **Important:** The delay value is always expressed in samples at the sample rate
-    /// you configured during `initialize`. To convert to time units:
-    /// `delay_ms = (delay_samples * 1000) / sample_rate`
+    /// you configured during `initialize`. To convert to time units, use
+    /// [`ProcessorConfig::frames_to_duration`] or [`Processor::output_delay_duration`].
     ///
     /// **Note:** Using frame sizes different from the optimal value returned by
     /// `optimal_num_frames` will increase the delay beyond the model's base latency.
@@ -291,6 +676,10 @@ impl ProcessorContext {
     ///
     /// Returns the delay in samples.
     ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Can be called from audio processing threads.
+    ///
     /// # Example
     ///
     /// ```rust,no_run
@@ -330,6 +719,18 @@ impl ProcessorContext {
     ///
     /// The processor stays initialized to the configured settings.
     ///
+    /// # Determinism
+    ///
+    /// Processing has no random state: after a reset, the processor is in the same state as
+    /// right after initialization, and processing the same input in the same blocks with the
+    /// same parameters yields the same output and VAD results. Parameter values are not
+    /// reset, so set them to the same values as well. This makes it possible to compare the
+    /// output against stored reference files in tests.
+    ///
+    /// Results are only reproducible with the same model file and SDK version on the same
+    /// platform. Different CPUs may use different vectorized code paths, so compare against
+    /// references with a small tolerance, e.g. `1e-6`.
+    ///
     /// # Returns
     ///
     /// Returns `Ok(())` on success or an [`AicError`] if the reset fails.
@@ -403,6 +804,111 @@ impl ProcessorContext {
         };
         handle_error(error_code)
     }
+
+    /// Enables or disables [`ProcessorParameter::Bypass`].
+    ///
+    /// Shorthand for setting the parameter to `1.0` or `0.0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - `true` to pass audio through unmodified, `false` to enhance it.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success or an [`AicError`] if the parameter cannot be set.
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Can be called from audio processing threads.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// # let processor = Processor::new(&model, &license_key)?;
+    /// # let processor_context = processor.processor_context();
+    /// processor_context.set_bypass(true)?;
+    /// assert!(processor_context.bypass()?);
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn set_bypass(&self, enabled: bool) -> Result<(), AicError> {
+        let value = if enabled { 1.0 } else { 0.0 };
+        self.set_parameter(ProcessorParameter::Bypass, value)
+    }
+
+    /// Returns whether [`ProcessorParameter::Bypass`] is enabled.
+    ///
+    /// Values of `0.5` and above count as enabled.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(enabled)`, or an [`AicError`] if the parameter cannot be read.
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Can be called from audio processing threads.
+    pub fn bypass(&self) -> Result<bool, AicError> {
+        Ok(self.parameter(ProcessorParameter::Bypass)? >= 0.5)
+    }
+
+    /// Enables [`ProcessorParameter::Bypass`] until the returned guard is dropped.
+    ///
+    /// Use this for temporary passthrough, e.g. while seeking or muting. Bypass is
+    /// latency-compensated, so toggling it does not cause clicks or timing shifts. When the
+    /// guard is dropped, the bypass value from before this call is restored.
+    ///
+    /// # Returns
+    ///
+    /// Returns the guard, or an [`AicError`] if the bypass parameter could not be read or set.
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Can be called from audio processing threads.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// # let processor = Processor::new(&model, &license_key)?;
+    /// let processor_context = processor.processor_context();
+    /// {
+    ///     let _bypass = processor_context.bypass_guard()?;
+    ///     // Audio processed here passes through unmodified
+    /// }
+    /// // Enhancement is active again
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn bypass_guard(&self) -> Result<BypassGuard<'_>, AicError> {
+        let previous = self.parameter(ProcessorParameter::Bypass)?;
+        self.set_bypass(true)?;
+
+        Ok(BypassGuard {
+            context: self,
+            previous,
+        })
+    }
+}
+
+/// Restores the previous bypass value when dropped.
+///
+/// Created by [`ProcessorContext::bypass_guard`].
+#[must_use = "bypass is disabled again when the guard is dropped"]
+pub struct BypassGuard<'a> {
+    context: &'a ProcessorContext,
+    previous: f32,
+}
+
+impl Drop for BypassGuard<'_> {
+    fn drop(&mut self) {
+        // Restoring a value that was read from the processor cannot be out of range
+        let _ = self
+            .context
+            .set_parameter(ProcessorParameter::Bypass, self.previous);
+    }
 }
 
 impl Drop for ProcessorContext {
@@ -417,6 +923,15 @@ impl Drop for ProcessorContext {
     }
 }
 
+// Only reports whether the handle is set, the SDK context is never accessed.
+impl fmt::Debug for ProcessorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProcessorContext")
+            .field("non_null", &!self.inner.is_null())
+            .finish_non_exhaustive()
+    }
+}
+
 // Safety: The underlying C library should be thread-safe for individual ProcessorContext instances
 unsafe impl Send for ProcessorContext {}
 unsafe impl Sync for ProcessorContext {}
@@ -427,6 +942,17 @@ unsafe impl Sync for ProcessorContext {}
 /// It handles memory management automatically and converts C-style error codes
 /// to Rust `Result` types.
 ///
+/// # Sharing and Multi-threading
+///
+/// `Processor` is `Send` and `Sync`. It can be moved to the audio thread after it has been
+/// created elsewhere. All methods that process audio or change the configuration take
+/// `&mut self`, so a processor handles exactly one stream at a time. To process several
+/// streams concurrently, create one processor per stream from clones of the same [`Model`].
+///
+/// Parameters can be changed from any thread while audio is processed through a
+/// [`ProcessorContext`] or [`VadContext`](crate::VadContext), which only need `&self` to be
+/// created. See `examples/multithread.rs` for a complete example.
+///
 /// # Example
 ///
 /// ```rust,no_run
@@ -449,13 +975,50 @@ unsafe impl Sync for ProcessorContext {}
 pub struct Processor<'a> {
     /// Raw pointer to the C processor structure
     inner: *mut AicProcessor,
-    /// Configured number of channels
-    num_channels: Option<u16>,
-    /// Marker to tie the lifetime of the processor to the lifetime of the model's weights
-    marker: PhantomData<&'a [u8]>,
+    /// Configuration the processor was initialized with
+    config: Option<ProcessorConfig>,
+    /// Dry signal state of [`Processor::process_interleaved_mix`], allocated on first use
+    dry_mix: Option<DryMix>,
+    /// Planar scratch buffers of [`Processor::process_planar_selective`] for skipped channels,
+    /// allocated on first use
+    skipped_channels: Vec<f32>,
+    /// Per-channel gains applied before the SDK mixes the channels to mono, `None` for equal
+    /// weights
+    downmix_weights: Option<Vec<f32>>,
+    /// Whether audio was processed since the delayed tail was last drained with
+    /// [`Processor::drain`]
+    tail_pending: bool,
+    /// Whether non-finite input samples are replaced before processing
+    sanitize_input: bool,
+    /// Number of input samples replaced since sanitizing was last enabled
+    sanitized_samples: u64,
+    /// Telemetry configuration the processor was created with, reused by `swap_model`
+    otel_config: Option<OtelConfig>,
+    /// Pending parameter ramps, shared by all contexts created with
+    /// [`Processor::processor_context`]
+    ramps: Arc<ParameterRamps>,
+    /// Model the processor was created with, to query its optimal block size in
+    /// [`Processor::internal_block_size`]. The block size depends on the sample rate passed
+    /// to [`Processor::initialize`], which only receives a [`ProcessorConfig`], so the
+    /// processor has to be able to ask the model.
+    ///
+    /// This clone also ties the lifetime of the processor to the lifetime of the model's
+    /// weights. Invariant: the SDK keeps the model alive through reference counting, so the
+    /// caller's [`Model`] may be dropped before the processor, but the SDK never copies the
+    /// data of a model created with [`Model::from_buffer`]. That buffer has to stay valid and
+    /// unmodified until the processor is destroyed, which the borrow in `Model<'a>` enforces.
+    /// [`Processor::swap_model`] can only replace the model with one borrowing data that lives
+    /// at least as long.
+    model: Model<'a>,
 }
 
 impl<'a> Processor<'a> {
+    /// Maximum number of audio channels a processor can be initialized with.
+    ///
+    /// [`Processor::initialize`] returns [`AicError::AudioConfigUnsupported`] for configurations
+    /// with more channels.
+    pub const MAX_CHANNELS: u16 = 16;
+
     /// Creates a new audio enhancement processor instance.
     ///
     /// Multiple processors can be created to process different audio streams simultaneously
@@ -464,8 +1027,9 @@ impl<'a> Processor<'a> {
     /// # Arguments
     ///
     /// * `model` - The loaded model instance
-    /// * `license_key` - license key for the ai-coustics SDK
-    ///   (generate your key at [developers.ai-coustics.com](https://developers.ai-coustics.com/))
+    /// * `license_key` - license key for the ai-coustics SDK, as a [`License`](crate::License)
+    ///   or string (generate your key at
+    ///   [developers.ai-coustics.com](https://developers.ai-coustics.com/))
     ///
     /// # Returns
     ///
@@ -480,8 +1044,8 @@ impl<'a> Processor<'a> {
     /// let processor = Processor::new(&model, &license_key)?;
     /// # Ok::<(), aic_sdk::AicError>(())
     /// ```
-    pub fn new(model: &Model<'a>, license_key: &str) -> Result<Self, AicError> {
-        Self::create(model, license_key, None)
+    pub fn new(model: &Model<'a>, license_key: impl Into<License>) -> Result<Self, AicError> {
+        Self::create(model, &license_key.into(), None)
     }
 
     /// Creates a new audio enhancement processor instance with explicit
@@ -503,19 +1067,43 @@ impl<'a> Processor<'a> {
     /// ```
     pub fn with_otel_config(
         model: &Model<'a>,
-        license_key: &str,
+        license_key: impl Into<License>,
         otel_config: &OtelConfig,
     ) -> Result<Self, AicError> {
-        Self::create(model, license_key, Some(otel_config))
+        Self::create(model, &license_key.into(), Some(otel_config))
+    }
+
+    /// Returns a [`ProcessorBuilder`] to create a processor with its configuration, parameters
+    /// and VAD parameters in one call.
+    ///
+    /// The builder validates the whole setup up front and returns the initialized processor
+    /// together with its [`ProcessorContext`] and [`VadContext`](crate::VadContext). See
+    /// [`ProcessorBuilder`] for an example.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The loaded model instance
+    /// * `license_key` - license key for the ai-coustics SDK, as a [`License`](crate::License)
+    ///   or string
+    pub fn builder<'m>(
+        model: &'m Model<'a>,
+        license_key: impl Into<License>,
+    ) -> ProcessorBuilder<'m, 'a> {
+        ProcessorBuilder::new(model, license_key.into())
     }
 
     fn create(
         model: &Model<'a>,
-        license_key: &str,
+        license_key: &License,
         otel_config: Option<&OtelConfig>,
     ) -> Result<Self, AicError> {
         // Set the wrapper ID as soon as the user attempts to instantiate a processor
-        crate::set_wrapper_id();
+        crate::apply_wrapper_id();
+        #[cfg(feature = "log")]
+        log::trace!(
+            "Creating processor for model {} with telemetry config {otel_config:?}",
+            model.id()
+        );
 
         // Session ID must outlive the FFI call so its pointer stays valid.
         let c_session_id = otel_config
@@ -534,8 +1122,7 @@ impl<'a> Processor<'a> {
             .map_or(ptr::null(), |o| o as *const AicOtelConfig);
 
         let mut processor_ptr: *mut AicProcessor = ptr::null_mut();
-        let c_license_key =
-            CString::new(license_key).map_err(|_| AicError::LicenseFormatInvalid)?;
+        let c_license_key = license_key.to_c_string()?;
 
         // SAFETY:
         // - `processor_ptr` points to stack storage for output.
@@ -554,7 +1141,10 @@ impl<'a> Processor<'a> {
             )
         };
 
-        handle_error(error_code)?;
+        handle_error(error_code).inspect_err(|_err| {
+            #[cfg(feature = "log")]
+            log::warn!("Failed to create processor: {_err}");
+        })?;
 
         // This should never happen if the C library is well-behaved, but let's be defensive
         assert!(
@@ -564,8 +1154,16 @@ impl<'a> Processor<'a> {
 
         Ok(Self {
             inner: processor_ptr,
-            num_channels: None,
-            marker: PhantomData,
+            config: None,
+            dry_mix: None,
+            skipped_channels: Vec::new(),
+            downmix_weights: None,
+            tail_pending: false,
+            sanitize_input: false,
+            sanitized_samples: 0,
+            otel_config: otel_config.cloned(),
+            ramps: Arc::default(),
+            model: model.clone(),
         })
     }
 
@@ -603,21 +1201,392 @@ impl<'a> Processor<'a> {
         Ok(self)
     }
 
-    /// Creates a [ProcessorContext] instance.
-    /// This can be used to control all parameters and other settings of the processor.
+    /// Sets a processor parameter and returns `self`.
+    ///
+    /// This is a convenience method that sets the parameter through a [`ProcessorContext`]
+    /// before the processor is handed out, so the parameter is already in effect for the first
+    /// processed block. Call it after [`Processor::with_config`].
+    ///
+    /// # Arguments
+    ///
+    /// * `parameter` - Parameter to set
+    /// * `value` - New parameter value. See [`ProcessorParameter`] for the valid ranges.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Self)` with the configured processor, or [`AicError::ParameterOutOfRange`]
+    /// if the value is outside the parameter's range.
     ///
     /// # Example
     ///
     /// ```rust,no_run
-    /// # use aic_sdk::{Model, Processor};
-    /// let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
-    /// let model = Model::from_file("/path/to/model.aicmodel")?;
-    /// let processor = Processor::new(&model, &license_key)?;
-    /// let processor_context = processor.processor_context();
+    /// # use aic_sdk::{Model, Processor, ProcessorConfig, ProcessorParameter};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// let config = ProcessorConfig::optimal(&model);
+    /// let processor = Processor::new(&model, &license_key)?
+    ///     .with_config(&config)?
+    ///     .with_parameter(ProcessorParameter::EnhancementLevel, 0.8)?;
     /// # Ok::<(), aic_sdk::AicError>(())
     /// ```
-    pub fn processor_context(&self) -> ProcessorContext {
-        let mut processor_context: *mut AicProcessorContext = ptr::null_mut();
+    pub fn with_parameter(
+        self,
+        parameter: ProcessorParameter,
+        value: f32,
+    ) -> Result<Self, AicError> {
+        self.try_processor_context()?
+            .set_parameter(parameter, value)?;
+        Ok(self)
+    }
+
+    /// Sets [`ProcessorParameter::EnhancementLevel`] without creating a [`ProcessorContext`]
+    /// first.
+    ///
+    /// Use a [`ProcessorContext`] instead to change the level from another thread or
+    /// repeatedly, e.g. from a UI slider.
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - Enhancement level from 0.0 to 1.0.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, [`AicError::ParameterOutOfRange`] if `level` is outside
+    /// 0.0 to 1.0, or any other [`AicError`] if the parameter cannot be set.
+    ///
+    /// # Warning
+    /// Do not call from audio processing threads as this allocates memory.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// let processor = Processor::new(&model, &license_key)?;
+    /// processor.set_enhancement_level(0.8)?;
+    /// assert_eq!(processor.enhancement_level()?, 0.8);
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn set_enhancement_level(&self, level: f32) -> Result<(), AicError> {
+        if !(0.0..=1.0).contains(&level) {
+            return Err(AicError::ParameterOutOfRange);
+        }
+        self.try_processor_context()?
+            .set_parameter(ProcessorParameter::EnhancementLevel, level)
+    }
+
+    /// Returns the current [`ProcessorParameter::EnhancementLevel`] without creating a
+    /// [`ProcessorContext`] first.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(level)`, or an [`AicError`] if the parameter cannot be read.
+    ///
+    /// # Warning
+    /// Do not call from audio processing threads as this allocates memory.
+    pub fn enhancement_level(&self) -> Result<f32, AicError> {
+        self.try_processor_context()?
+            .parameter(ProcessorParameter::EnhancementLevel)
+    }
+
+    /// Returns the configuration the processor was last initialized with, or `None` if it
+    /// has not been initialized yet.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor, ProcessorConfig};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// let config = ProcessorConfig::optimal(&model);
+    /// let processor = Processor::new(&model, &license_key)?.with_config(&config)?;
+    /// assert_eq!(processor.config(), Some(&config));
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn config(&self) -> Option<&ProcessorConfig> {
+        self.config.as_ref()
+    }
+
+    /// Returns the number of channels the processor was initialized with, or `None` if it has
+    /// not been initialized yet.
+    ///
+    /// Use this to size audio buffers from the processor itself.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor, ProcessorConfig};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+    /// let processor = Processor::new(&model, &license_key)?.with_config(&config)?;
+    /// assert_eq!(processor.num_channels(), Some(2));
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn num_channels(&self) -> Option<u16> {
+        self.config.as_ref().map(|config| config.num_channels)
+    }
+
+    /// Returns the number of frames the model processes at a time at the configured sample
+    /// rate, or `None` if the processor has not been initialized yet.
+    ///
+    /// This is the model's [optimal frame count](Model::optimal_num_frames). Blocks of any
+    /// other size, including the varying block sizes allowed by
+    /// [`ProcessorConfig::allow_variable_frames`], are buffered internally until a full block
+    /// is available, which adds to the [output delay](ProcessorContext::output_delay). To keep
+    /// the added latency low, choose [`ProcessorConfig::num_frames`] and the block sizes passed
+    /// to the processor as close to this value as possible.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor, ProcessorConfig};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// let config = ProcessorConfig::optimal(&model).with_allow_variable_frames(true);
+    /// let processor = Processor::new(&model, &license_key)?.with_config(&config)?;
+    /// println!("Process blocks of {:?} frames", processor.internal_block_size());
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn internal_block_size(&self) -> Option<usize> {
+        self.config
+            .as_ref()
+            .map(|config| self.model.optimal_num_frames(config.sample_rate))
+    }
+
+    /// Returns how the processor handles multichannel audio.
+    ///
+    /// All models currently supported by the SDK enhance a single channel, so multichannel
+    /// input is always [`ChannelMode::Downmixed`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{ChannelMode, Model, Processor};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// let processor = Processor::new(&model, &license_key)?;
+    /// if processor.channel_mode() == ChannelMode::Downmixed {
+    ///     println!("Stereo input is enhanced as mono");
+    /// }
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn channel_mode(&self) -> ChannelMode {
+        ChannelMode::Downmixed
+    }
+
+    /// Returns `true` if the processor has been initialized and is ready to process audio.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor, ProcessorConfig};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// let mut processor = Processor::new(&model, &license_key)?;
+    /// assert!(!processor.is_initialized());
+    ///
+    /// processor.initialize(&ProcessorConfig::optimal(&model))?;
+    /// assert!(processor.is_initialized());
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn is_initialized(&self) -> bool {
+        self.config.is_some()
+    }
+
+    /// Returns the output delay of the processor as a duration.
+    ///
+    /// This is [`ProcessorContext::output_delay`] converted with
+    /// [`ProcessorConfig::frames_to_duration`] at the configured sample rate.
+    ///
+    /// # Returns
+    ///
+    /// Returns the delay, or [`AicError::ProcessorNotInitialized`] if the processor has not
+    /// been initialized.
+    ///
+    /// # Warning
+    /// Do not call from audio processing threads as this allocates memory.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor, ProcessorConfig};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// let config = ProcessorConfig::optimal(&model);
+    /// let processor = Processor::new(&model, &license_key)?.with_config(&config)?;
+    /// println!("Latency: {:?}", processor.output_delay_duration()?);
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn output_delay_duration(&self) -> Result<Duration, AicError> {
+        let config = self.config().ok_or(AicError::ProcessorNotInitialized)?;
+        let delay = self.try_processor_context()?.output_delay();
+        Ok(config.frames_to_duration(delay))
+    }
+
+    /// Enables or disables replacing non-finite input samples before processing.
+    ///
+    /// When enabled, every `NaN` or infinite input sample is replaced with `0.0` before it
+    /// reaches the model, where it could otherwise cause loud artifacts or corrupt internal
+    /// state. This is intended for debugging flaky capture devices or upstream bugs, use
+    /// [`Processor::sanitized_samples`] to find out whether it happened.
+    ///
+    /// Sanitizing is disabled by default. When enabled, every input sample is checked once
+    /// per processing call, which costs an extra pass over the buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - `true` to sanitize the input of all `process_*` methods.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor, ProcessorConfig};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// let config = ProcessorConfig::optimal(&model);
+    /// let mut processor = Processor::new(&model, &license_key)?.with_config(&config)?;
+    /// processor.set_sanitize_input(true);
+    ///
+    /// let mut audio = vec![f32::NAN; config.num_frames];
+    /// processor.process_interleaved(&mut audio)?;
+    /// assert_eq!(processor.sanitized_samples(), config.num_frames as u64);
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn set_sanitize_input(&mut self, enabled: bool) {
+        self.sanitize_input = enabled;
+        self.sanitized_samples = 0;
+    }
+
+    /// Sets per-channel gains that are applied to the input before it is mixed to mono.
+    ///
+    /// All channels are mixed to mono for enhancement (see [`ChannelMode::Downmixed`]) with
+    /// equal weights by default. With custom weights, e.g. `[1.0, 0.0]` to enhance only the
+    /// left channel of a stereo signal, each input channel is multiplied by its weight before
+    /// the mix. The enhanced mono result is then distributed to all output channels as before,
+    /// so every output channel carries the same enhanced signal regardless of its weight.
+    ///
+    /// The weights are applied to the input itself, so they also scale the original signal in
+    /// the output where it is mixed back in, e.g. with an
+    /// [`EnhancementLevel`](ProcessorParameter::EnhancementLevel) below `1.0` or while
+    /// [bypassed](ProcessorParameter::Bypass). A channel with a weight of `0.0` is silent in
+    /// that part of the output.
+    ///
+    /// The weights are kept when the processor is re-initialized with the same number of
+    /// channels and reset to equal weights otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `weights` - One finite gain per channel. Negative gains invert the channel.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, [`AicError::ProcessorNotInitialized`] if the processor is
+    /// not initialized, [`AicError::AudioConfigMismatch`] if `weights` does not have one entry
+    /// per channel, or [`AicError::ParameterOutOfRange`] if a weight is not finite. All
+    /// weights are validated before any are stored, so a rejected call keeps the previous
+    /// weights. `weights` is only read.
+    ///
+    /// # Warning
+    /// Do not call from audio processing threads as this allocates memory.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor, ProcessorConfig};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+    /// let mut processor = Processor::new(&model, &license_key)?.with_config(&config)?;
+    ///
+    /// // The voice is on the left channel, the right one only picks up noise
+    /// processor.set_downmix(&[1.0, 0.0])?;
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn set_downmix(&mut self, weights: &[f32]) -> Result<(), AicError> {
+        let num_channels = self.configured_num_channels()?;
+        if weights.len() != num_channels as usize {
+            return Err(AicError::AudioConfigMismatch);
+        }
+        if !weights.iter().all(|weight| weight.is_finite()) {
+            return Err(AicError::ParameterOutOfRange);
+        }
+
+        self.downmix_weights = if weights.iter().all(|&weight| weight == 1.0) {
+            None
+        } else {
+            Some(weights.to_vec())
+        };
+        Ok(())
+    }
+
+    /// Returns the per-channel gains applied before the input is mixed to mono, or `None` for
+    /// equal weights.
+    ///
+    /// See [`Processor::set_downmix`].
+    pub fn downmix(&self) -> Option<&[f32]> {
+        self.downmix_weights.as_deref()
+    }
+
+    /// Returns whether non-finite input samples are replaced before processing.
+    ///
+    /// See [`Processor::set_sanitize_input`].
+    pub fn sanitizes_input(&self) -> bool {
+        self.sanitize_input
+    }
+
+    /// Returns the number of non-finite input samples that were replaced with `0.0` since
+    /// sanitizing was last enabled with [`Processor::set_sanitize_input`].
+    pub fn sanitized_samples(&self) -> u64 {
+        self.sanitized_samples
+    }
+
+    /// Creates a [ProcessorContext] instance.
+    /// This can be used to control all parameters and other settings of the processor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the SDK fails to create the context. Use
+    /// [`Processor::try_processor_context`] where panicking is not acceptable, e.g. in plugin
+    /// callbacks.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor};
+    /// let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// let processor = Processor::new(&model, &license_key)?;
+    /// let processor_context = processor.processor_context();
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    #[must_use]
+    pub fn processor_context(&self) -> ProcessorContext {
+        self.try_processor_context()
+            .expect("Failed to create processor context")
+    }
+
+    /// Creates a [ProcessorContext] instance, returning an error instead of panicking.
+    ///
+    /// See [`Processor::processor_context`].
+    ///
+    /// # Returns
+    ///
+    /// Returns the context, or an [`AicError`] if the SDK fails to create it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// let processor = Processor::new(&model, &license_key)?;
+    /// let processor_context = processor.try_processor_context()?;
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn try_processor_context(&self) -> Result<ProcessorContext, AicError> {
+        let mut processor_context: *mut AicProcessorContext = ptr::null_mut();
 
         // SAFETY:
         // - `processor_context` is valid output storage.
@@ -626,8 +1595,7 @@ impl<'a> Processor<'a> {
         let error_code =
             unsafe { aic_processor_context_create(&mut processor_context, self.as_const_ptr()) };
 
-        // This should never fail
-        assert!(handle_error(error_code).is_ok());
+        handle_error(error_code)?;
 
         // This should never happen if the C library is well-behaved, but let's be defensive
         assert!(
@@ -635,12 +1603,20 @@ impl<'a> Processor<'a> {
             "C library returned success but null pointer"
         );
 
-        ProcessorContext::new(processor_context)
+        Ok(ProcessorContext::new(
+            processor_context,
+            Arc::clone(&self.ramps),
+        ))
     }
 
     /// Creates a [Voice Activity Detector Context](crate::vad::VadContext) instance.
     /// All handles created from a given processor reference the same VAD instance.
     ///
+    /// # Panics
+    ///
+    /// Panics if the SDK fails to create the context. Use [`Processor::try_vad_context`]
+    /// where panicking is not acceptable, e.g. in plugin callbacks.
+    ///
     /// # Example
     ///
     /// ```rust,no_run
@@ -651,7 +1627,21 @@ impl<'a> Processor<'a> {
     /// let vad = processor.vad_context();
     /// # Ok::<(), aic_sdk::AicError>(())
     /// ```
+    #[must_use]
     pub fn vad_context(&self) -> crate::VadContext {
+        self.try_vad_context()
+            .expect("Failed to create VAD context")
+    }
+
+    /// Creates a [Voice Activity Detector Context](crate::vad::VadContext) instance, returning
+    /// an error instead of panicking.
+    ///
+    /// See [`Processor::vad_context`].
+    ///
+    /// # Returns
+    ///
+    /// Returns the context, or an [`AicError`] if the SDK fails to create it.
+    pub fn try_vad_context(&self) -> Result<crate::VadContext, AicError> {
         let mut vad_ptr: *mut AicVadContext = ptr::null_mut();
 
         // SAFETY:
@@ -661,8 +1651,7 @@ impl<'a> Processor<'a> {
         //   processor is in use, so we only borrow `&self`.
         let error_code = unsafe { aic_vad_context_create(&mut vad_ptr, self.as_const_ptr()) };
 
-        // This should never fail
-        assert!(handle_error(error_code).is_ok());
+        handle_error(error_code)?;
 
         // This should never happen if the C library is well-behaved, but let's be defensive
         assert!(
@@ -670,7 +1659,7 @@ impl<'a> Processor<'a> {
             "C library returned success but null pointer"
         );
 
-        crate::vad::VadContext::new(vad_ptr)
+        Ok(crate::vad::VadContext::new(vad_ptr))
     }
 
     /// Configures the processor for specific audio settings.
@@ -679,6 +1668,10 @@ impl<'a> Processor<'a> {
     /// For the lowest delay use the sample rate and frame size returned by
     /// [`Model::optimal_sample_rate`] and [`Model::optimal_num_frames`].
     ///
+    /// Initializing resets all internal state, so it can only be called once. A stray second
+    /// call mid-stream is rejected instead of silently dropping the state. To change the
+    /// configuration of an initialized processor, use [`Processor::reinitialize`].
+    ///
     /// # Arguments
     ///
     /// * `config` - Audio processing configuration
@@ -686,13 +1679,16 @@ impl<'a> Processor<'a> {
     /// # Returns
     ///
     /// Returns `Ok(())` on success or an [`AicError`] if initialization fails.
+    /// Returns [`AicError::AudioConfigUnsupported`] if `config.num_channels` is zero or exceeds
+    /// [`Processor::MAX_CHANNELS`], and [`AicError::AlreadyInitialized`] if the processor was
+    /// already initialized.
     ///
     /// # Warning
     /// Do not call from audio processing threads as this allocates memory.
     ///
     /// # Note
-    /// All channels are mixed to mono for processing. To process channels
-    /// independently, create separate [`Processor`] instances.
+    /// All channels are mixed to mono for processing (see [`ChannelMode::Downmixed`]). To process
+    /// channels independently, create separate [`Processor`] instances.
     ///
     /// # Example
     ///
@@ -706,6 +1702,15 @@ impl<'a> Processor<'a> {
     /// # Ok::<(), aic_sdk::AicError>(())
     /// ```
     pub fn initialize(&mut self, config: &ProcessorConfig) -> Result<(), AicError> {
+        #[cfg(feature = "log")]
+        log::trace!("Initializing processor with {config:?}");
+        if self.config.is_some() {
+            return Err(AicError::AlreadyInitialized);
+        }
+        if config.num_channels == 0 || config.num_channels > Self::MAX_CHANNELS {
+            return Err(AicError::AudioConfigUnsupported);
+        }
+
         // SAFETY:
         // - `self.inner` is a valid pointer to a live processor.
         // - This function is not thread-safe, so we borrow `&mut self`.
@@ -719,49 +1724,46 @@ impl<'a> Processor<'a> {
             )
         };
 
-        handle_error(error_code)?;
-        self.num_channels = Some(config.num_channels);
+        handle_error(error_code).inspect_err(|_err| {
+            #[cfg(feature = "log")]
+            log::warn!("Failed to initialize processor with {config:?}: {_err}");
+        })?;
+        self.config = Some(config.clone());
+        // The output delay and block size may have changed
+        self.dry_mix = None;
+        self.tail_pending = false;
+        // Downmix weights are only kept if they still match the number of channels
+        if self
+            .downmix_weights
+            .as_ref()
+            .is_some_and(|weights| weights.len() != config.num_channels as usize)
+        {
+            self.downmix_weights = None;
+        }
         Ok(())
     }
 
-    /// Processes audio with separate buffers for each channel (planar layout).
-    ///
-    /// Enhances speech in the provided audio buffers in-place.
+    /// Applies a new configuration to an already initialized processor.
     ///
-    /// **Memory Layout:**
-    /// - Separate buffer for each channel
-    /// - Each buffer contains `num_frames` floats
-    /// - Maximum of 16 channels supported
-    /// - Example for 2 channels, 4 frames:
-    ///   ```text
-    ///   audio[0] -> [ch0_f0, ch0_f1, ch0_f2, ch0_f3]
-    ///   audio[1] -> [ch1_f0, ch1_f1, ch1_f2, ch1_f3]
-    ///   ```
+    /// Use this when the audio format of a stream changes, e.g. when a mono stream becomes
+    /// stereo. Unlike creating a new [`Processor`], the license check is not repeated.
+    /// Internal buffers are reset as with [`ProcessorContext::reset`], while the values of all
+    /// [`ProcessorParameter`]s are kept.
     ///
-    /// The function accepts any type of collection of `f32` values that implements `as_mut`, e.g.:
-    /// - `[vec![0.0; 128]; 2]`
-    /// - `[[0.0; 128]; 2]`
-    /// - `[&mut ch1, &mut ch2]`
+    /// If the processor has not been initialized yet, this is equivalent to
+    /// [`Processor::initialize`].
     ///
     /// # Arguments
     ///
-    /// * `audio` - Array of mutable channel buffer slices to be enhanced in-place.
-    ///             Each channel buffer must be exactly of size `num_frames`,
-    ///             or if `allow_variable_frames` was enabled, less than the initialization value.
-    ///
-    /// # Notes
-    ///
-    /// - All channels are mixed to mono for processing. To process channels
-    ///   independently, create separate processor instances.
-    /// - Maximum supported number of channels is 16. Exceeding this will return an error.
+    /// * `config` - The new audio processing configuration
     ///
     /// # Returns
     ///
-    /// Returns `Ok(())` on success or an [`AicError`] if processing fails.
-    ///
-    /// # Real-time safety
+    /// Returns `Ok(())` on success or an [`AicError`] if initialization fails. On failure the
+    /// processor has to be initialized again before it can be used.
     ///
-    /// Real-time safe. Can be called from audio processing threads.
+    /// # Warning
+    /// Do not call from audio processing threads as this allocates memory.
     ///
     /// # Example
     ///
@@ -769,7 +1771,331 @@ impl<'a> Processor<'a> {
     /// # use aic_sdk::{Model, Processor, ProcessorConfig};
     /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
     /// # let model = Model::from_file("/path/to/model.aicmodel")?;
-    /// # let mut processor = Processor::new(&model, &license_key)?;
+    /// let config = ProcessorConfig::optimal(&model);
+    /// let mut processor = Processor::new(&model, &license_key)?.with_config(&config)?;
+    ///
+    /// // A second speaker joins, switch to stereo
+    /// processor.reinitialize(&config.with_num_channels(2))?;
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn reinitialize(&mut self, config: &ProcessorConfig) -> Result<(), AicError> {
+        if self.config.is_none() {
+            return self.initialize(config);
+        }
+
+        let context = self.try_processor_context()?;
+        let bypass = context.parameter(ProcessorParameter::Bypass)?;
+        let enhancement_level = context.parameter(ProcessorParameter::EnhancementLevel)?;
+
+        // Mark the processor as uninitialized until the new configuration has been applied,
+        // so a failed initialization cannot be followed by processing with the old layout.
+        self.config = None;
+        self.initialize(config)?;
+
+        context.set_parameter(ProcessorParameter::Bypass, bypass)?;
+        context.set_parameter(ProcessorParameter::EnhancementLevel, enhancement_level)?;
+        Ok(())
+    }
+
+    /// Replaces the model of the processor, e.g. to switch to a lighter model under CPU
+    /// pressure.
+    ///
+    /// The SDK binds a processor to its model for life, so a new processor is created for
+    /// `model` with the same telemetry configuration. It is initialized with the current
+    /// configuration, and all processor and VAD parameter values, the
+    /// [downmix weights](Processor::set_downmix) and the
+    /// [input sanitizing](Processor::set_sanitize_input) setting are carried over. Only if
+    /// all of this succeeds, the new processor replaces the current one. Otherwise the
+    /// current processor is left unchanged and can still be used.
+    ///
+    /// Creating a processor validates the license again, which is why `license_key` is
+    /// required.
+    ///
+    /// # Audio glitches
+    ///
+    /// The new processor starts with empty internal buffers and may have a different
+    /// [output delay](ProcessorContext::output_delay), so the output jumps at the switch.
+    /// Crossfade or mute around the switch if this is audible. Existing [`ProcessorContext`]s
+    /// and [`VadContext`](crate::VadContext)s still refer to the replaced processor and have
+    /// to be created again.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The model to switch to.
+    /// * `license_key` - License key for the ai-coustics SDK.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or an [`AicError`] if the processor could not be
+    /// created. Returns [`AicError::AudioConfigUnsupported`] if the new model does not support
+    /// the current configuration, e.g. its sample rate.
+    ///
+    /// # Warning
+    /// Do not call from audio processing threads as this allocates memory and is not
+    /// real-time safe.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor, ProcessorConfig};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// let light_model = Model::from_file("/path/to/light_model.aicmodel")?;
+    ///
+    /// let config = ProcessorConfig::optimal(&model);
+    /// let mut processor = Processor::new(&model, &license_key)?.with_config(&config)?;
+    ///
+    /// // Under CPU pressure
+    /// processor.swap_model(&light_model, &license_key)?;
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn swap_model(
+        &mut self,
+        model: &Model<'a>,
+        license_key: impl Into<License>,
+    ) -> Result<(), AicError> {
+        let mut processor = Self::create(model, &license_key.into(), self.otel_config.as_ref())?;
+
+        if let Some(config) = &self.config {
+            processor.initialize(config)?;
+        }
+
+        let (old_context, new_context) = (
+            self.try_processor_context()?,
+            processor.try_processor_context()?,
+        );
+        for parameter in ProcessorParameter::ALL {
+            new_context.set_parameter(parameter, old_context.parameter(parameter)?)?;
+        }
+
+        let (old_vad, new_vad) = (self.try_vad_context()?, processor.try_vad_context()?);
+        for parameter in crate::VadParameter::ALL {
+            new_vad.set_parameter(parameter, old_vad.parameter(parameter)?)?;
+        }
+
+        processor.downmix_weights = self.downmix_weights.take();
+        processor.sanitize_input = self.sanitize_input;
+        processor.sanitized_samples = self.sanitized_samples;
+        *self = processor;
+        Ok(())
+    }
+
+    /// Resets the processor and primes it with silence, so audio processed afterwards starts
+    /// from a deterministic, fully primed state.
+    ///
+    /// Right after initialization, the processor's internal buffers are empty and the first
+    /// blocks of real audio are enhanced without any history. This is inconvenient for
+    /// benchmarks and A/B tests. Warming up clears all state with [`ProcessorContext::reset`],
+    /// including the VAD state, then processes silent blocks covering at least the
+    /// [output delay](ProcessorContext::output_delay).
+    ///
+    /// The SDK has no dedicated warm-up call, so this is exactly equivalent to resetting and
+    /// processing the same amount of silence yourself. Parameter values are kept, and the VAD
+    /// reports no speech afterwards.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success or an [`AicError`] if the processor is not initialized or
+    /// processing fails.
+    ///
+    /// # Warning
+    /// Do not call from audio processing threads as this allocates memory.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor, ProcessorConfig};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// let config = ProcessorConfig::optimal(&model);
+    /// let mut processor = Processor::new(&model, &license_key)?.with_config(&config)?;
+    /// processor.warmup()?;
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn warmup(&mut self) -> Result<(), AicError> {
+        let num_channels = self.configured_num_channels()? as usize;
+        let num_frames = self.config.as_ref().map_or(0, |config| config.num_frames);
+
+        let context = self.try_processor_context()?;
+        context.reset()?;
+        let delay = context.output_delay();
+
+        // The dry signal of `process_interleaved_mix` has to restart from silence as well
+        self.dry_mix = None;
+
+        let mut silence = vec![0.0f32; num_channels * num_frames];
+        for _ in 0..delay.div_ceil(num_frames.max(1)) {
+            silence.fill(0.0);
+            self.process_interleaved(&mut silence)?;
+        }
+        // Only silence is left in the processor
+        self.tail_pending = false;
+
+        Ok(())
+    }
+
+    /// Returns an iterator over the delayed tail of the enhanced stream.
+    ///
+    /// The processor delays its output by [`ProcessorContext::output_delay`] frames, so the
+    /// end of a stream is still inside the processor after the last block was processed. The
+    /// iterator pushes silence through the processor and yields the enhanced tail as
+    /// interleaved blocks of [`ProcessorConfig::num_frames`] frames, with a shorter final
+    /// block, until exactly `output_delay` frames have been yielded.
+    ///
+    /// The tail is drained once: calling `drain` again without processing more audio, or on a
+    /// processor that is not initialized, yields nothing. Dropping the iterator early
+    /// discards the rest of the tail. If the [`ProcessorContext`] to query the delay cannot
+    /// be created, the iterator yields only that error.
+    ///
+    /// # Warning
+    /// Do not call from audio processing threads as this allocates memory.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor, ProcessorConfig};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// let config = ProcessorConfig::optimal(&model);
+    /// let mut processor = Processor::new(&model, &license_key)?.with_config(&config)?;
+    ///
+    /// let mut audio = vec![0.0f32; config.num_frames];
+    /// processor.process_interleaved(&mut audio)?;
+    ///
+    /// // Collect the rest of the stream
+    /// let mut tail = Vec::new();
+    /// for block in processor.drain() {
+    ///     tail.extend(block?);
+    /// }
+    /// assert_eq!(tail.len(), processor.processor_context().output_delay());
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn drain(&mut self) -> Drain<'_, 'a> {
+        let (remaining, error) = if self.tail_pending && self.config.is_some() {
+            match self.try_processor_context() {
+                Ok(context) => (context.output_delay(), None),
+                Err(err) => (0, Some(err)),
+            }
+        } else {
+            (0, None)
+        };
+        self.tail_pending = false;
+
+        Drain {
+            processor: self,
+            remaining,
+            error,
+        }
+    }
+
+    /// Processes interleaved audio like [`Processor::process_interleaved`] and measures the
+    /// input and output levels of each channel.
+    ///
+    /// This is meant for level meters in user interfaces. The levels of the block are
+    /// measured right before and after it is enhanced in-place.
+    ///
+    /// # Arguments
+    ///
+    /// * `audio` - Interleaved audio buffer, see [`Processor::process_interleaved`].
+    ///
+    /// # Returns
+    ///
+    /// Returns the [`LevelMeter`] of the block, or an [`AicError`] if processing fails.
+    ///
+    /// # Warning
+    /// Do not call from audio processing threads as this allocates memory.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor, ProcessorConfig};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+    /// let mut processor = Processor::new(&model, &license_key)?.with_config(&config)?;
+    ///
+    /// let mut audio = vec![0.0f32; config.num_channels as usize * config.num_frames];
+    /// let levels = processor.process_interleaved_metered(&mut audio)?;
+    /// println!("Left output peak: {}", levels.output_peak[0]);
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn process_interleaved_metered(
+        &mut self,
+        audio: &mut [f32],
+    ) -> Result<LevelMeter, AicError> {
+        let num_channels = self.configured_num_channels()? as usize;
+        self.validate_layout(AudioLayout::Contiguous(audio.len()))?;
+
+        let (input_peak, input_rms) = measure_levels(audio, num_channels);
+        self.process_interleaved(audio)?;
+        let (output_peak, output_rms) = measure_levels(audio, num_channels);
+
+        Ok(LevelMeter {
+            input_peak,
+            output_peak,
+            input_rms,
+            output_rms,
+        })
+    }
+
+    /// Processes audio with separate buffers for each channel (planar layout).
+    ///
+    /// Enhances speech in the provided audio buffers in-place.
+    ///
+    /// **Memory Layout:**
+    /// - Separate buffer for each channel
+    /// - Each buffer contains `num_frames` floats
+    /// - Maximum of 16 channels supported
+    /// - Example for 2 channels, 4 frames:
+    ///   ```text
+    ///   audio[0] -> [ch0_f0, ch0_f1, ch0_f2, ch0_f3]
+    ///   audio[1] -> [ch1_f0, ch1_f1, ch1_f2, ch1_f3]
+    ///   ```
+    ///
+    /// The function accepts any type of collection of `f32` values that implements `as_mut`, e.g.:
+    /// - `[vec![0.0; 128]; 2]`
+    /// - `[[0.0; 128]; 2]`
+    /// - `[&mut ch1, &mut ch2]`
+    ///
+    /// A `&mut Vec<Vec<f32>>` or `&mut Vec<&mut [f32]>` can be passed directly, there is no
+    /// need to collect the channels into a vector of slices first.
+    ///
+    /// # Arguments
+    ///
+    /// * `audio` - Array of mutable channel buffer slices to be enhanced in-place.
+    ///             Each channel buffer must be exactly of size `num_frames`,
+    ///             or if `allow_variable_frames` was enabled, at most `num_frames`.
+    ///             Larger buffers are rejected with [`AicError::AudioConfigMismatch`].
+    ///
+    /// # Notes
+    ///
+    /// - All channels are mixed to mono for processing. To process channels
+    ///   independently, create separate processor instances.
+    /// - Maximum supported number of channels is [`Processor::MAX_CHANNELS`]. Exceeding this
+    ///   will return an error.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success or an [`AicError`] if processing fails.
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Can be called from audio processing threads.
+    ///
+    /// This is the path intended for the hot audio thread. The channel pointer array is built
+    /// on the stack and never allocates, and validating the buffers compares one length per
+    /// channel. There is deliberately no variant that caches the pointers or the length
+    /// checks between calls: detecting that the shape is unchanged takes the same per-channel
+    /// comparisons, and the pointers must be read again on every call because the caller may
+    /// pass different buffers each time.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor, ProcessorConfig};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// # let mut processor = Processor::new(&model, &license_key)?;
     /// let config = ProcessorConfig::optimal(&model).with_num_channels(2);
     /// processor.initialize(&config)?;
     /// let mut audio = vec![vec![0.0f32; config.num_frames]; config.num_channels as usize];
@@ -778,44 +2104,138 @@ impl<'a> Processor<'a> {
     /// ```
     #[allow(clippy::doc_overindented_list_items)]
     pub fn process_planar<V: AsMut<[f32]>>(&mut self, audio: &mut [V]) -> Result<(), AicError> {
-        const MAX_CHANNELS: u16 = 16;
+        let num_channels = self.configured_num_channels()?;
+        let mut channel_lens = audio.iter_mut().map(|channel| channel.as_mut().len());
+        let num_frames = self.validate_layout(AudioLayout::Planar(&mut channel_lens))?;
+
+        // `validate_layout` guarantees at most `MAX_CHANNELS` buffers
+        let mut audio_ptrs = [std::ptr::null_mut::<f32>(); Self::MAX_CHANNELS as usize];
+        for (i, channel) in audio.iter_mut().enumerate() {
+            self.sanitize(channel.as_mut());
+            self.apply_downmix_weight(i, channel.as_mut());
+            audio_ptrs[i] = channel.as_mut().as_mut_ptr();
+        }
 
-        let Some(num_channels) = self.num_channels else {
-            return Err(AicError::ProcessorNotInitialized);
+        // SAFETY:
+        // - `self.inner` is a valid pointer to a live processor.
+        // - `audio_ptrs` holds `num_channels` valid, writable pointers with `num_frames` samples each.
+        // - This function is not thread-safe, so we borrow `&mut self`.
+        let error_code = unsafe {
+            aic_processor_process_planar(self.inner, audio_ptrs.as_ptr(), num_channels, num_frames)
         };
 
-        if audio.len() != num_channels as usize {
-            return Err(AicError::AudioConfigMismatch);
+        handle_error(error_code)?;
+        self.tail_pending = true;
+        Ok(())
+    }
+
+    /// Processes planar audio where some channels are skipped.
+    ///
+    /// Channels that are `Some` are enhanced in-place as with [`Processor::process_planar`].
+    /// Channels that are `None` are left untouched, e.g. to bypass some channels of a mixing
+    /// console.
+    ///
+    /// # Skipped channels and the mono mix
+    ///
+    /// All channels are mixed to mono for processing (see [`ChannelMode::Downmixed`]).
+    /// Skipped channels are passed to the processor as silence, so they do not contribute to
+    /// the mix, and the enhanced channels are based on the mix of the other channels only.
+    /// Since the mix is still formed over all `num_channels` channels, the level of the
+    /// enhanced channels can be lower than when processing the same channels on their own.
+    /// To enhance a subset of channels independently, initialize a separate [`Processor`]
+    /// with fewer channels instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `audio` - One slot per channel. Must contain exactly `num_channels` slots, and all
+    ///   `Some` channels must hold the same number of frames, as for
+    ///   [`Processor::process_planar`].
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success or an [`AicError`] if processing fails.
+    /// Returns [`AicError::AudioConfigMismatch`] if the number of slots differs from the
+    /// number of channels or the channels have different lengths. If all channels are
+    /// skipped, nothing is processed.
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe after the first call that skips a channel. The buffers for skipped
+    /// channels are allocated on that call.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor, ProcessorConfig};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// let config = ProcessorConfig::optimal(&model).with_num_channels(3);
+    /// let mut processor = Processor::new(&model, &license_key)?.with_config(&config)?;
+    ///
+    /// let mut voice = vec![0.0f32; config.num_frames];
+    /// let mut music = vec![0.0f32; config.num_frames];
+    /// let mut guest = vec![0.0f32; config.num_frames];
+    /// // Enhance both voices, but keep the music untouched
+    /// processor.process_planar_selective(&mut [Some(&mut voice), None, Some(&mut guest)])?;
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn process_planar_selective(
+        &mut self,
+        audio: &mut [Option<&mut [f32]>],
+    ) -> Result<(), AicError> {
+        let num_channels = self.configured_num_channels()?;
+
+        // Skipped channels take the length of the processed ones. If all channels are skipped,
+        // only the number of slots is validated.
+        let processed_len = audio.iter().flatten().map(|channel| channel.len()).next();
+        let skipped_len = processed_len.unwrap_or(1);
+        let mut channel_lens = audio
+            .iter()
+            .map(|slot| slot.as_ref().map_or(skipped_len, |channel| channel.len()));
+        let num_frames = self.validate_layout(AudioLayout::Planar(&mut channel_lens))?;
+        if processed_len.is_none() {
+            return Ok(());
         }
 
-        if num_channels > MAX_CHANNELS {
-            return Err(AicError::AudioConfigUnsupported);
+        for (i, slot) in audio.iter_mut().enumerate() {
+            if let Some(channel) = slot {
+                self.sanitize(channel);
+                self.apply_downmix_weight(i, channel);
+            }
         }
 
-        let num_frames = if audio.is_empty() {
-            0
-        } else {
-            audio[0].as_mut().len()
-        };
+        if self.skipped_channels.len() < num_channels as usize * num_frames {
+            self.skipped_channels
+                .resize(num_channels as usize * num_frames, 0.0);
+        }
 
-        let mut audio_ptrs = [std::ptr::null_mut::<f32>(); MAX_CHANNELS as usize];
-        for (i, channel) in audio.iter_mut().enumerate() {
-            // Check that all channels have the same number of frames
-            if channel.as_mut().len() != num_frames {
-                return Err(AicError::AudioConfigMismatch);
-            }
-            audio_ptrs[i] = channel.as_mut().as_mut_ptr();
+        let mut audio_ptrs = [std::ptr::null_mut::<f32>(); Self::MAX_CHANNELS as usize];
+        for ((ptr, slot), silence) in audio_ptrs
+            .iter_mut()
+            .zip(audio.iter_mut())
+            .zip(self.skipped_channels.chunks_exact_mut(num_frames))
+        {
+            *ptr = match slot {
+                Some(channel) => channel.as_mut_ptr(),
+                None => {
+                    silence.fill(0.0);
+                    silence.as_mut_ptr()
+                }
+            };
         }
 
         // SAFETY:
         // - `self.inner` is a valid pointer to a live processor.
-        // - `audio_ptrs` holds `num_channels` valid, writable pointers with `num_frames` samples each.
+        // - `audio_ptrs` holds `num_channels` valid, writable and non-overlapping pointers with
+        //   `num_frames` samples each.
         // - This function is not thread-safe, so we borrow `&mut self`.
         let error_code = unsafe {
             aic_processor_process_planar(self.inner, audio_ptrs.as_ptr(), num_channels, num_frames)
         };
 
-        handle_error(error_code)
+        handle_error(error_code)?;
+        self.tail_pending = true;
+        Ok(())
     }
 
     /// Processes audio with interleaved channel data.
@@ -834,7 +2254,8 @@ impl<'a> Processor<'a> {
     ///
     /// * `audio` - Interleaved audio buffer to be enhanced in-place.
     ///             Must be exactly of size `num_channels` * `num_frames`,
-    ///             or if `allow_variable_frames` was enabled, less than the initialization value per channel.
+    ///             or if `allow_variable_frames` was enabled, at most `num_frames` per channel.
+    ///             Larger buffers are rejected with [`AicError::AudioConfigMismatch`].
     ///
     /// # Note
     ///
@@ -864,16 +2285,17 @@ impl<'a> Processor<'a> {
     /// ```
     #[allow(clippy::doc_overindented_list_items)]
     pub fn process_interleaved(&mut self, audio: &mut [f32]) -> Result<(), AicError> {
-        let Some(num_channels) = self.num_channels else {
-            return Err(AicError::ProcessorNotInitialized);
-        };
-
-        if !audio.len().is_multiple_of(num_channels as usize) {
-            return Err(AicError::AudioConfigMismatch);
+        let num_channels = self.configured_num_channels()?;
+        let num_frames = self.validate_layout(AudioLayout::Contiguous(audio.len()))?;
+        self.sanitize(audio);
+        if let Some(weights) = &self.downmix_weights {
+            for frame in audio.chunks_exact_mut(weights.len()) {
+                for (sample, weight) in frame.iter_mut().zip(weights) {
+                    *sample *= weight;
+                }
+            }
         }
 
-        let num_frames = audio.len() / num_channels as usize;
-
         // SAFETY:
         // - `self.inner` is a valid pointer to a live processor.
         // - `audio` points to a contiguous f32 slice of length `num_channels * num_frames`.
@@ -887,38 +2309,40 @@ impl<'a> Processor<'a> {
             )
         };
 
-        handle_error(error_code)
+        handle_error(error_code)?;
+        self.tail_pending = true;
+        Ok(())
     }
 
-    /// Processes audio with sequential channel data.
+    /// Processes interleaved audio and blends the enhanced (wet) signal with the original (dry)
+    /// signal at a linear ratio.
     ///
-    /// Enhances speech in the provided audio buffer in-place.
+    /// Unlike [`ProcessorParameter::EnhancementLevel`], the mix is a plain linear crossfade:
+    /// `output = (1 - dry_wet) * dry + dry_wet * wet`.
     ///
-    /// **Memory Layout:**
-    /// - Single contiguous buffer with all samples for each channel stored sequentially
-    /// - Buffer size: `num_channels` * `num_frames` floats
-    /// - Example for 2 channels, 4 frames:
-    ///   ```text
-    ///   audio -> [ch0_f0, ch0_f1, ch0_f2, ch0_f3, ch1_f0, ch1_f1, ch1_f2, ch1_f3]
-    ///   ```
+    /// The enhanced signal lags the input by the processor's
+    /// [output delay](ProcessorContext::output_delay). To avoid comb filtering and phase
+    /// cancellation, the dry signal must be delayed by the same amount before mixing. This
+    /// method does so internally with a delay line of `output_delay` frames, so mixing with
+    /// the undelayed input yourself is not equivalent.
     ///
     /// # Arguments
     ///
-    /// * `audio` - Sequential audio buffer to be enhanced in-place.
-    ///             Must be exactly of size `num_channels` * `num_frames`,
-    ///             or if `allow_variable_frames` was enabled, less than the initialization value per channel.
-    /// # Note
-    ///
-    /// All channels are mixed to mono for processing. To process channels
-    /// independently, create separate processor instances.
+    /// * `audio` - Interleaved audio buffer to be processed in-place, as for
+    ///   [`Processor::process_interleaved`].
+    /// * `dry_wet` - Share of the enhanced signal, from 0.0 (original audio only, delayed by
+    ///   the output delay) to 1.0 (enhanced audio only).
     ///
     /// # Returns
     ///
     /// Returns `Ok(())` on success or an [`AicError`] if processing fails.
+    /// Returns [`AicError::ParameterOutOfRange`] if `dry_wet` is not within 0.0 to 1.0.
     ///
     /// # Real-time safety
     ///
-    /// Real-time safe. Can be called from audio processing threads.
+    /// Real-time safe after the first call. The dry signal buffers are allocated on the first
+    /// call after the processor was (re)initialized. The delay line is not cleared by
+    /// [`ProcessorContext::reset`].
     ///
     /// # Example
     ///
@@ -926,24 +2350,106 @@ impl<'a> Processor<'a> {
     /// # use aic_sdk::{Model, Processor, ProcessorConfig};
     /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
     /// # let model = Model::from_file("/path/to/model.aicmodel")?;
-    /// # let mut processor = Processor::new(&model, &license_key)?;
     /// let config = ProcessorConfig::optimal(&model).with_num_channels(2);
-    /// processor.initialize(&config)?;
+    /// let mut processor = Processor::new(&model, &license_key)?.with_config(&config)?;
     /// let mut audio = vec![0.0f32; config.num_channels as usize * config.num_frames];
-    /// processor.process_sequential(&mut audio)?;
+    /// // Blend 70% enhanced with 30% original audio
+    /// processor.process_interleaved_mix(&mut audio, 0.7)?;
     /// # Ok::<(), aic_sdk::AicError>(())
     /// ```
-    #[allow(clippy::doc_overindented_list_items)]
-    pub fn process_sequential(&mut self, audio: &mut [f32]) -> Result<(), AicError> {
-        let Some(num_channels) = self.num_channels else {
-            return Err(AicError::ProcessorNotInitialized);
-        };
+    pub fn process_interleaved_mix(
+        &mut self,
+        audio: &mut [f32],
+        dry_wet: f32,
+    ) -> Result<(), AicError> {
+        if !(0.0..=1.0).contains(&dry_wet) {
+            return Err(AicError::ParameterOutOfRange);
+        }
 
-        if !audio.len().is_multiple_of(num_channels as usize) {
-            return Err(AicError::AudioConfigMismatch);
+        let num_channels = self.configured_num_channels()? as usize;
+        let max_len = num_channels * self.config.as_ref().map_or(0, |config| config.num_frames);
+        self.validate_layout(AudioLayout::Contiguous(audio.len()))?;
+
+        // Sanitized before it is copied into the dry signal
+        self.sanitize(audio);
+
+        // Taken out of `self` while processing, so the dry block can be borrowed alongside it
+        let mut dry_mix = match self.dry_mix.take() {
+            Some(dry_mix) => dry_mix,
+            None => {
+                let delay = self.try_processor_context()?.output_delay();
+                DryMix::new(num_channels * delay, max_len)
+            }
+        };
+        dry_mix.delay(audio);
+        let result = self.process_interleaved(audio);
+
+        match result {
+            Ok(()) => {
+                for (sample, &dry) in audio.iter_mut().zip(&dry_mix.block) {
+                    *sample = (1.0 - dry_wet) * dry + dry_wet * *sample;
+                }
+            }
+            // The block was not enhanced, so it must not advance the dry signal either
+            Err(_) => dry_mix.undo(audio.len()),
         }
 
-        let num_frames = audio.len() / num_channels as usize;
+        self.dry_mix = Some(dry_mix);
+        result
+    }
+
+    /// Processes audio with sequential channel data.
+    ///
+    /// Enhances speech in the provided audio buffer in-place.
+    ///
+    /// **Memory Layout:**
+    /// - Single contiguous buffer with all samples for each channel stored sequentially
+    /// - Buffer size: `num_channels` * `num_frames` floats
+    /// - Example for 2 channels, 4 frames:
+    ///   ```text
+    ///   audio -> [ch0_f0, ch0_f1, ch0_f2, ch0_f3, ch1_f0, ch1_f1, ch1_f2, ch1_f3]
+    ///   ```
+    ///
+    /// # Arguments
+    ///
+    /// * `audio` - Sequential audio buffer to be enhanced in-place.
+    ///             Must be exactly of size `num_channels` * `num_frames`,
+    ///             or if `allow_variable_frames` was enabled, at most `num_frames` per channel.
+    ///             Larger buffers are rejected with [`AicError::AudioConfigMismatch`].
+    /// # Note
+    ///
+    /// All channels are mixed to mono for processing. To process channels
+    /// independently, create separate processor instances.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success or an [`AicError`] if processing fails.
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Can be called from audio processing threads.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor, ProcessorConfig};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// # let mut processor = Processor::new(&model, &license_key)?;
+    /// let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+    /// processor.initialize(&config)?;
+    /// let mut audio = vec![0.0f32; config.num_channels as usize * config.num_frames];
+    /// processor.process_sequential(&mut audio)?;
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    #[allow(clippy::doc_overindented_list_items)]
+    pub fn process_sequential(&mut self, audio: &mut [f32]) -> Result<(), AicError> {
+        let num_channels = self.configured_num_channels()?;
+        let num_frames = self.validate_layout(AudioLayout::Contiguous(audio.len()))?;
+        self.sanitize(audio);
+        for (i, channel) in audio.chunks_exact_mut(num_frames).enumerate() {
+            self.apply_downmix_weight(i, channel);
+        }
 
         // SAFETY:
         // - `self.inner` is a valid pointer to a live, initialized processor.
@@ -958,167 +2464,1847 @@ impl<'a> Processor<'a> {
             )
         };
 
-        handle_error(error_code)
+        handle_error(error_code)?;
+        self.tail_pending = true;
+        Ok(())
+    }
+
+    /// Processes interleaved input audio and writes the enhanced audio to separate
+    /// per-channel output buffers (planar layout).
+    ///
+    /// This is useful for audio hosts that provide an interleaved input buffer but expect
+    /// the result in a separate planar output buffer. The input is deinterleaved into
+    /// `output` with [`layout::interleaved_to_planar`](crate::layout::interleaved_to_planar),
+    /// which is then enhanced in-place with [`Processor::process_planar`].
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Interleaved input audio. Must hold `num_channels` * `num_frames` samples.
+    /// * `output` - One output buffer per channel, each holding `num_frames` samples.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success or an [`AicError`] if processing fails.
+    /// Returns [`AicError::AudioConfigMismatch`] if the input and output buffers do not
+    /// describe the same number of channels and frames.
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Can be called from audio processing threads.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor, ProcessorConfig};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// # let mut processor = Processor::new(&model, &license_key)?;
+    /// let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+    /// processor.initialize(&config)?;
+    /// let input = vec![0.0f32; config.num_channels as usize * config.num_frames];
+    /// let mut output = vec![vec![0.0f32; config.num_frames]; config.num_channels as usize];
+    /// processor.process_deinterleaved_from_interleaved(&input, &mut output)?;
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn process_deinterleaved_from_interleaved<V: AsMut<[f32]>>(
+        &mut self,
+        input: &[f32],
+        output: &mut [V],
+    ) -> Result<(), AicError> {
+        crate::layout::interleaved_to_planar(input, output)?;
+        self.process_planar(output)
+    }
+
+    /// Processes planar input audio and writes the enhanced audio to an interleaved
+    /// output buffer.
+    ///
+    /// This is the counterpart of [`Processor::process_deinterleaved_from_interleaved`].
+    /// The input is interleaved into `output` with
+    /// [`layout::planar_to_interleaved`](crate::layout::planar_to_interleaved), which is then
+    /// enhanced in-place with [`Processor::process_interleaved`].
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - One input buffer per channel, each holding `num_frames` samples.
+    /// * `output` - Interleaved output audio. Must hold `num_channels` * `num_frames` samples.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success or an [`AicError`] if processing fails.
+    /// Returns [`AicError::AudioConfigMismatch`] if the input and output buffers do not
+    /// describe the same number of channels and frames.
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Can be called from audio processing threads.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor, ProcessorConfig};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// # let mut processor = Processor::new(&model, &license_key)?;
+    /// let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+    /// processor.initialize(&config)?;
+    /// let input = vec![vec![0.0f32; config.num_frames]; config.num_channels as usize];
+    /// let mut output = vec![0.0f32; config.num_channels as usize * config.num_frames];
+    /// processor.process_interleaved_from_planar(&input, &mut output)?;
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn process_interleaved_from_planar<V: AsRef<[f32]>>(
+        &mut self,
+        input: &[V],
+        output: &mut [f32],
+    ) -> Result<(), AicError> {
+        crate::layout::planar_to_interleaved(input, output)?;
+        self.process_interleaved(output)
+    }
+
+    /// Processes interleaved input audio and writes the enhanced audio to a separate output
+    /// buffer that may be longer than the input.
+    ///
+    /// This generalizes [`Processor::process_interleaved`]: the input is copied to the start
+    /// of `output` and enhanced there, and the number of frames written is returned. The
+    /// processor itself never changes the number of frames, so exactly as many frames are
+    /// written as `input` contains. The lengths of `input` and `output` can therefore only
+    /// differ in two ways:
+    ///
+    /// - `output` is larger than `input`, e.g. a buffer sized for `num_frames` frames while
+    ///   `input` holds fewer frames with `allow_variable_frames` enabled. Samples after the
+    ///   written frames are left untouched.
+    /// - The sample rate differs between input and output. This requires resampling, see
+    ///   [`ResamplingProcessor::process_to`](crate::ResamplingProcessor::process_to).
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Interleaved audio, as for [`Processor::process_interleaved`].
+    /// * `output` - Interleaved output audio. Must hold at least as many samples as `input`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of frames written to `output`, or an [`AicError`] if processing
+    /// fails. Returns [`AicError::AudioConfigMismatch`] if `output` is shorter than `input`,
+    /// in which case `output` is left untouched.
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Can be called from audio processing threads.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor, ProcessorConfig};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// let config = ProcessorConfig::optimal(&model)
+    ///     .with_num_channels(2)
+    ///     .with_allow_variable_frames(true);
+    /// let mut processor = Processor::new(&model, &license_key)?.with_config(&config)?;
+    ///
+    /// let input = vec![0.0f32; 2 * 100];
+    /// let mut output = vec![0.0f32; 2 * config.num_frames];
+    /// let num_frames = processor.process_to(&input, &mut output)?;
+    /// assert_eq!(num_frames, 100);
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn process_to(&mut self, input: &[f32], output: &mut [f32]) -> Result<usize, AicError> {
+        let num_frames = self.validate_layout(AudioLayout::Contiguous(input.len()))?;
+        if output.len() < input.len() {
+            return Err(AicError::AudioConfigMismatch);
+        }
+
+        let output = &mut output[..input.len()];
+        output.copy_from_slice(input);
+        self.process_interleaved(output)?;
+        Ok(num_frames)
+    }
+
+    /// Processes interleaved audio stored as one array per frame.
+    ///
+    /// `&mut [[f32; CH]]` has the same memory layout as an interleaved buffer with `CH`
+    /// channels, so the frames are enhanced in-place with [`Processor::process_interleaved`]
+    /// without copying.
+    ///
+    /// # Arguments
+    ///
+    /// * `frames` - Audio frames to be enhanced in-place, one array of `CH` samples per frame.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success or an [`AicError`] if processing fails.
+    /// Returns [`AicError::AudioConfigMismatch`] if `CH` differs from the number of channels
+    /// the processor was initialized with.
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Can be called from audio processing threads.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor, ProcessorConfig};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// # let mut processor = Processor::new(&model, &license_key)?;
+    /// let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+    /// processor.initialize(&config)?;
+    /// let mut frames = vec![[0.0f32; 2]; config.num_frames];
+    /// processor.process_frames(&mut frames)?;
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn process_frames<const CH: usize>(
+        &mut self,
+        frames: &mut [[f32; CH]],
+    ) -> Result<(), AicError> {
+        let num_channels = self.configured_num_channels()?;
+
+        if CH != num_channels as usize {
+            return Err(AicError::AudioConfigMismatch);
+        }
+
+        self.process_interleaved(frames.as_flattened_mut())
+    }
+
+    /// Processes a long interleaved buffer block by block and calls `f` after each block.
+    ///
+    /// The buffer is split into blocks of `num_frames` frames, which are enhanced in-place with
+    /// [`Processor::process_interleaved`]. After each block, `f` receives the enhanced block
+    /// and a [`VadContext`](crate::VadContext) holding the prediction for that block.
+    ///
+    /// If the buffer does not end on a block boundary, the final shorter block is only
+    /// processed if the processor was initialized with `allow_variable_frames`. Otherwise it is
+    /// left untouched and not passed to `f`.
+    ///
+    /// # Arguments
+    ///
+    /// * `audio` - Interleaved audio of any number of frames, enhanced in-place.
+    /// * `f` - Called with each enhanced block and the VAD context.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of frames that were processed, or an [`AicError`] if processing a
+    /// block fails. Returns [`AicError::AudioConfigMismatch`] if `audio` does not contain
+    /// whole frames.
+    ///
+    /// # Warning
+    /// Do not call from audio processing threads as this allocates memory.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor, ProcessorConfig};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// let config = ProcessorConfig::optimal(&model);
+    /// let mut processor = Processor::new(&model, &license_key)?.with_config(&config)?;
+    ///
+    /// let mut audio = vec![0.0f32; 10 * config.sample_rate as usize];
+    /// let mut speech = Vec::new();
+    /// processor.for_each_block(&mut audio, |_block, vad| {
+    ///     speech.push(vad.is_speech_detected());
+    /// })?;
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn for_each_block(
+        &mut self,
+        audio: &mut [f32],
+        mut f: impl FnMut(&[f32], &crate::VadContext),
+    ) -> Result<usize, AicError> {
+        let num_channels = self.configured_num_channels()? as usize;
+        let (num_frames, allow_variable_frames) =
+            self.config.as_ref().map_or((0, false), |config| {
+                (config.num_frames, config.allow_variable_frames)
+            });
+
+        if !audio.len().is_multiple_of(num_channels) || num_frames == 0 {
+            return Err(AicError::AudioConfigMismatch);
+        }
+
+        let vad = self.try_vad_context()?;
+        let mut processed_frames = 0;
+        for block in audio.chunks_mut(num_channels * num_frames) {
+            let block_frames = block.len() / num_channels;
+            if block_frames < num_frames && !allow_variable_frames {
+                break;
+            }
+
+            self.process_interleaved(block)?;
+            f(block, &vad);
+            processed_frames += block_frames;
+        }
+
+        Ok(processed_frames)
+    }
+
+    /// Returns the number of channels the processor was initialized with.
+    ///
+    /// `initialize` rejects zero channels, but the check is repeated here so the `process_*`
+    /// methods can never divide by zero.
+    fn configured_num_channels(&self) -> Result<u16, AicError> {
+        match self.num_channels() {
+            None => Err(AicError::ProcessorNotInitialized),
+            Some(0) => Err(AicError::AudioConfigUnsupported),
+            Some(num_channels) => Ok(num_channels),
+        }
+    }
+
+    /// Replaces non-finite samples with `0.0` if sanitizing is enabled.
+    fn sanitize(&mut self, audio: &mut [f32]) {
+        if !self.sanitize_input {
+            return;
+        }
+
+        for sample in audio.iter_mut().filter(|sample| !sample.is_finite()) {
+            *sample = 0.0;
+            self.sanitized_samples += 1;
+        }
+    }
+
+    /// Scales one channel by its weight set with [`Processor::set_downmix`].
+    fn apply_downmix_weight(&self, channel: usize, audio: &mut [f32]) {
+        if let Some(weights) = &self.downmix_weights {
+            for sample in audio {
+                *sample *= weights[channel];
+            }
+        }
+    }
+
+    /// Validates the layout of audio passed to a `process_*` method against the configuration
+    /// and returns the number of frames per channel, see [`validate_audio_layout`].
+    fn validate_layout(&self, layout: AudioLayout<'_>) -> Result<usize, AicError> {
+        let config = self
+            .config
+            .as_ref()
+            .ok_or(AicError::ProcessorNotInitialized)?;
+        validate_audio_layout(config.num_channels, config.num_frames, layout)
+    }
+
+    fn as_const_ptr(&self) -> *const AicProcessor {
+        self.inner as *const AicProcessor
+    }
+}
+
+/// Lengths of the buffers passed to a `process_*` method.
+enum AudioLayout<'a> {
+    /// A single interleaved or sequential buffer holding all channels, with its length
+    Contiguous(usize),
+    /// One buffer per channel, with the length of each buffer
+    Planar(&'a mut dyn ExactSizeIterator<Item = usize>),
+}
+
+/// Validates the layout of audio passed to a `process_*` method and returns the number of
+/// frames per channel.
+///
+/// All `process_*` methods share these rules:
+/// - `num_channels` must be between 1 and [`Processor::MAX_CHANNELS`], otherwise
+///   [`AicError::AudioConfigUnsupported`] is returned before the buffers are inspected.
+/// - A contiguous buffer must hold whole frames. Planar audio must consist of exactly
+///   `num_channels` buffers of equal length.
+/// - A block must hold at least one and at most `max_num_frames` frames. Smaller blocks are
+///   left to the SDK, which accepts them if variable frames are allowed.
+///
+/// All other violations return [`AicError::AudioConfigMismatch`].
+fn validate_audio_layout(
+    num_channels: u16,
+    max_num_frames: usize,
+    layout: AudioLayout<'_>,
+) -> Result<usize, AicError> {
+    if num_channels == 0 || num_channels > Processor::MAX_CHANNELS {
+        return Err(AicError::AudioConfigUnsupported);
+    }
+
+    let num_frames = match layout {
+        AudioLayout::Contiguous(len) => {
+            if !len.is_multiple_of(num_channels as usize) {
+                return Err(AicError::AudioConfigMismatch);
+            }
+            len / num_channels as usize
+        }
+        AudioLayout::Planar(channel_lens) => {
+            if channel_lens.len() != num_channels as usize {
+                return Err(AicError::AudioConfigMismatch);
+            }
+            let num_frames = channel_lens.next().unwrap_or(0);
+            for len in channel_lens {
+                if len != num_frames {
+                    return Err(AicError::AudioConfigMismatch);
+                }
+            }
+            num_frames
+        }
+    };
+
+    if num_frames == 0 || num_frames > max_num_frames {
+        return Err(AicError::AudioConfigMismatch);
+    }
+    Ok(num_frames)
+}
+
+// Only reports state kept on the Rust side, the SDK processor is never accessed.
+impl fmt::Debug for Processor<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Processor")
+            .field("non_null", &!self.inner.is_null())
+            .field("initialized", &self.is_initialized())
+            .field("num_channels", &self.num_channels())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> Drop for Processor<'a> {
+    fn drop(&mut self) {
+        if !self.inner.is_null() {
+            // SAFETY:
+            // - `self.inner` was allocated by the SDK and is still owned by this wrapper.
+            // - This function is not thread-safe with concurrent processor use, but
+            //   `drop` has exclusive access to `self`.
+            unsafe { aic_processor_destroy(self.inner) };
+        }
+    }
+}
+
+// SAFETY: Everything in Processor is Send, with the exception of the inner raw pointer.
+// The Processor only uses the raw pointer according to the safety contracts of the
+// unsafe APIs that require the pointer, and the Processor does not expose access to the
+// raw pointer in any of its methods. Therefore, it safe to implement Send for Processor.
+unsafe impl<'a> Send for Processor<'a> {}
+
+// SAFETY: Processor does not expose any interior mutability, and all unsafe APIs that make use of
+// the inner raw pointer are only used in methods that take &mut self, which upholds the thread safety
+// contracts required by the unsafe APIs. Therefore, it is safe to implement Sync for Processor.
+unsafe impl<'a> Sync for Processor<'a> {}
+
+/// Iterator over the delayed tail of a [`Processor`], returned by [`Processor::drain`].
+///
+/// Yields interleaved blocks of enhanced audio. Stops after the first error.
+#[derive(Debug)]
+pub struct Drain<'p, 'a> {
+    processor: &'p mut Processor<'a>,
+    /// Frames of the tail that have not been yielded yet
+    remaining: usize,
+    /// Error creating the context to query the output delay, yielded as the only item
+    error: Option<AicError>,
+}
+
+impl Iterator for Drain<'_, '_> {
+    type Item = Result<Vec<f32>, AicError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(err) = self.error.take() {
+            return Some(Err(err));
+        }
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let config = self.processor.config.as_ref()?;
+        let num_channels = config.num_channels as usize;
+        let num_frames = config.num_frames.min(self.remaining);
+
+        // Full blocks are processed even for the final block, since the processor may not
+        // accept variable frame counts
+        let mut block = vec![0.0f32; num_channels * config.num_frames];
+        let result = self.processor.process_interleaved(&mut block);
+        self.processor.tail_pending = false;
+        if let Err(err) = result {
+            self.remaining = 0;
+            return Some(Err(err));
+        }
+
+        self.remaining -= num_frames;
+        block.truncate(num_channels * num_frames);
+        Some(Ok(block))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let num_frames = self
+            .processor
+            .config
+            .as_ref()
+            .map_or(1, |config| config.num_frames.max(1));
+        let blocks = self.remaining.div_ceil(num_frames) + usize::from(self.error.is_some());
+        (0, Some(blocks))
+    }
+}
+
+impl std::iter::FusedIterator for Drain<'_, '_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        model::ModelBuffer,
+        test_support::{get_rook_s_48khz, load_test_model},
+    };
+    use std::fs;
+
+    #[test]
+    fn model_creation_and_basic_operations() {
+        dbg!(crate::get_sdk_version());
+        dbg!(crate::get_compatible_model_version());
+
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+
+        let mut processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+
+        let num_channels = config.num_channels as usize;
+        let mut audio = vec![vec![0.0f32; config.num_frames]; num_channels];
+
+        processor.process_planar(&mut audio).unwrap();
+    }
+
+    #[test]
+    fn process_interleaved_fixed_frames() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+
+        let mut processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+
+        let num_channels = config.num_channels as usize;
+        let mut audio = vec![0.0f32; num_channels * config.num_frames];
+        processor.process_interleaved(&mut audio).unwrap();
+    }
+
+    #[test]
+    fn process_planar_fixed_frames() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+
+        let mut processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+
+        let mut left = vec![0.0f32; config.num_frames];
+        let mut right = vec![0.0f32; config.num_frames];
+        let mut audio = [left.as_mut_slice(), right.as_mut_slice()];
+        processor.process_planar(&mut audio).unwrap();
+    }
+
+    #[test]
+    fn process_planar_selective_leaves_skipped_channels_untouched() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model).with_num_channels(3);
+
+        let mut processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+
+        let signal: Vec<f32> = (0..config.num_frames)
+            .map(|i| (i as f32 * 0.01).sin() * 0.5)
+            .collect();
+        let mut first = signal.clone();
+        let mut third = signal.clone();
+
+        for _ in 0..10 {
+            first.copy_from_slice(&signal);
+            third.copy_from_slice(&signal);
+            processor
+                .process_planar_selective(&mut [Some(&mut first), None, Some(&mut third)])
+                .unwrap();
+        }
+
+        assert_ne!(first, signal);
+        assert_eq!(first, third);
+
+        // Skipping all channels processes nothing
+        processor
+            .process_planar_selective(&mut [None, None, None])
+            .unwrap();
+    }
+
+    #[test]
+    fn process_planar_selective_validates_slots() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+
+        let mut processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+
+        let mut first = vec![0.0f32; config.num_frames];
+        let mut second = vec![0.0f32; config.num_frames - 1];
+        assert_eq!(
+            processor.process_planar_selective(&mut [Some(&mut first)]),
+            Err(AicError::AudioConfigMismatch)
+        );
+        assert_eq!(
+            processor.process_planar_selective(&mut [Some(&mut first), None, None]),
+            Err(AicError::AudioConfigMismatch)
+        );
+        assert_eq!(
+            processor.process_planar_selective(&mut [Some(&mut first), Some(&mut second)]),
+            Err(AicError::AudioConfigMismatch)
+        );
+    }
+
+    #[test]
+    fn downmix_weights_scale_channels_before_the_mono_mix() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+        let weights = [0.75, 0.25];
+
+        let mut weighted = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+        weighted.set_downmix(&weights).unwrap();
+        assert_eq!(weighted.downmix(), Some(&weights[..]));
+
+        let mut reference = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+
+        // Different signals on both channels, so each weight matters
+        let input: Vec<f32> = (0..config.num_frames)
+            .flat_map(|i| [(i as f32 * 0.01).sin() * 0.5, (i as f32 * 0.07).cos() * 0.3])
+            .collect();
+        let scaled: Vec<f32> = input
+            .chunks_exact(2)
+            .flat_map(|frame| [frame[0] * weights[0], frame[1] * weights[1]])
+            .collect();
+
+        for _ in 0..10 {
+            let mut audio = input.clone();
+            weighted.process_interleaved(&mut audio).unwrap();
+
+            let mut expected = scaled.clone();
+            reference.process_interleaved(&mut expected).unwrap();
+
+            assert_eq!(audio, expected);
+        }
+
+        // Equal weights are the default
+        weighted.set_downmix(&[1.0, 1.0]).unwrap();
+        assert_eq!(weighted.downmix(), None);
+    }
+
+    #[test]
+    fn process_planar_accepts_common_buffer_shapes() {
+        const NUM_FRAMES: usize = 128;
+
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig {
+            num_frames: NUM_FRAMES,
+            ..ProcessorConfig::optimal(&model).with_num_channels(2)
+        };
+        let mut processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+
+        let mut vecs = vec![vec![0.0f32; NUM_FRAMES]; 2];
+        processor.process_planar(&mut vecs).unwrap();
+
+        let mut arrays = [[0.0f32; NUM_FRAMES]; 2];
+        processor.process_planar(&mut arrays).unwrap();
+
+        let (mut left, mut right) = ([0.0f32; NUM_FRAMES], [0.0f32; NUM_FRAMES]);
+        let mut slices: Vec<&mut [f32]> = vec![&mut left, &mut right];
+        processor.process_planar(&mut slices).unwrap();
+    }
+
+    #[test]
+    fn drain_yields_the_output_delay_once() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model);
+
+        let mut processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+        let delay = processor.processor_context().output_delay();
+
+        // Nothing was processed yet
+        assert_eq!(processor.drain().count(), 0);
+
+        let mut audio = vec![0.1f32; config.num_frames];
+        for _ in 0..3 {
+            processor.process_interleaved(&mut audio).unwrap();
+        }
+
+        let blocks: Vec<Vec<f32>> = processor.drain().map(Result::unwrap).collect();
+        assert!(blocks.iter().all(|block| block.len() <= config.num_frames));
+        assert_eq!(blocks.iter().map(Vec::len).sum::<usize>(), delay);
+
+        // The tail is only drained once
+        assert_eq!(processor.drain().count(), 0);
+    }
+
+    #[test]
+    fn downmix_weights_are_validated() {
+        let (model, license_key) = load_test_model().unwrap();
+        let mut processor = Processor::new(&model, &license_key).unwrap();
+        assert_eq!(
+            processor.set_downmix(&[1.0, 0.0]),
+            Err(AicError::ProcessorNotInitialized)
+        );
+
+        let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+        processor.initialize(&config).unwrap();
+        processor.set_downmix(&[0.5, 0.25]).unwrap();
+        assert_eq!(
+            processor.set_downmix(&[1.0]),
+            Err(AicError::AudioConfigMismatch)
+        );
+        assert_eq!(
+            processor.set_downmix(&[2.0, f32::NAN]),
+            Err(AicError::ParameterOutOfRange)
+        );
+
+        // Rejected weights keep the current weights
+        assert_eq!(processor.downmix(), Some(&[0.5, 0.25][..]));
+
+        // Weights only survive re-initialization with the same number of channels
+        processor.set_downmix(&[1.0, -1.0]).unwrap();
+        processor.reinitialize(&config).unwrap();
+        assert_eq!(processor.downmix(), Some(&[1.0, -1.0][..]));
+        processor
+            .reinitialize(&config.with_num_channels(3))
+            .unwrap();
+        assert_eq!(processor.downmix(), None);
+    }
+
+    #[test]
+    fn process_sequential_fixed_frames() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+
+        let mut processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+
+        let num_channels = config.num_channels as usize;
+        let mut audio = vec![0.0f32; num_channels * config.num_frames];
+        processor.process_sequential(&mut audio).unwrap();
+    }
+
+    #[test]
+    fn process_deinterleaved_from_interleaved_matches_process_planar() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+        let num_channels = config.num_channels as usize;
+
+        let input: Vec<f32> = (0..num_channels * config.num_frames)
+            .map(|i| (i as f32 * 0.01).sin() * 0.5)
+            .collect();
+
+        let mut expected = vec![vec![0.0f32; config.num_frames]; num_channels];
+        crate::layout::interleaved_to_planar(&input, &mut expected).unwrap();
+        Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap()
+            .process_planar(&mut expected)
+            .unwrap();
+
+        let mut output = vec![vec![0.0f32; config.num_frames]; num_channels];
+        Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap()
+            .process_deinterleaved_from_interleaved(&input, &mut output)
+            .unwrap();
+
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn process_deinterleaved_from_interleaved_rejects_mismatched_output() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+
+        let mut processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+
+        let input = vec![0.0f32; 2 * config.num_frames];
+        let mut output = vec![vec![0.0f32; config.num_frames - 1]; 2];
+        assert_eq!(
+            processor.process_deinterleaved_from_interleaved(&input, &mut output),
+            Err(AicError::AudioConfigMismatch)
+        );
+    }
+
+    #[test]
+    fn process_to_writes_as_many_frames_as_given() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model)
+            .with_num_channels(2)
+            .with_allow_variable_frames(true);
+
+        let input: Vec<f32> = (0..2 * config.num_frames)
+            .map(|i| (i as f32 * 0.01).sin() * 0.5)
+            .collect();
+        let mut expected = input.clone();
+        Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap()
+            .process_interleaved(&mut expected)
+            .unwrap();
+
+        let mut processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+        let mut output = vec![7.0f32; 2 * config.num_frames + 10];
+        assert_eq!(
+            processor.process_to(&input, &mut output),
+            Ok(config.num_frames)
+        );
+        assert_eq!(output[..input.len()], expected);
+        assert!(output[input.len()..].iter().all(|&sample| sample == 7.0));
+
+        // Shorter input with variable frames
+        assert_eq!(processor.process_to(&input[..2 * 20], &mut output), Ok(20));
+    }
+
+    #[test]
+    fn process_to_rejects_short_output() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+
+        let mut processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+
+        let input = vec![0.5f32; 2 * config.num_frames];
+        let mut output = vec![0.0f32; 2 * config.num_frames - 2];
+        assert_eq!(
+            processor.process_to(&input, &mut output),
+            Err(AicError::AudioConfigMismatch)
+        );
+        assert!(output.iter().all(|&sample| sample == 0.0));
+    }
+
+    fn process_frames_matches_process_interleaved<const CH: usize>() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model).with_num_channels(CH as u16);
+
+        let input: Vec<f32> = (0..CH * config.num_frames)
+            .map(|i| (i as f32 * 0.01).sin() * 0.5)
+            .collect();
+
+        let mut expected = input.clone();
+        Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap()
+            .process_interleaved(&mut expected)
+            .unwrap();
+
+        let mut frames: Vec<[f32; CH]> = input
+            .chunks_exact(CH)
+            .map(|frame| frame.try_into().unwrap())
+            .collect();
+        Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap()
+            .process_frames(&mut frames)
+            .unwrap();
+
+        assert_eq!(frames.as_flattened(), expected);
+    }
+
+    #[test]
+    fn process_frames_mono() {
+        process_frames_matches_process_interleaved::<1>();
+    }
+
+    #[test]
+    fn process_frames_stereo() {
+        process_frames_matches_process_interleaved::<2>();
+    }
+
+    #[test]
+    fn process_frames_rejects_mismatched_channel_count() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+
+        let mut processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+
+        let mut frames = vec![[0.0f32; 1]; 2 * config.num_frames];
+        assert_eq!(
+            processor.process_frames(&mut frames),
+            Err(AicError::AudioConfigMismatch)
+        );
+    }
+
+    #[test]
+    fn reinitialize_switches_from_mono_to_stereo() {
+        let (model, license_key) = load_test_model().unwrap();
+        let mono = ProcessorConfig::optimal(&model);
+        let stereo = mono.clone().with_num_channels(2);
+
+        let mut processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&mono)
+            .unwrap();
+        let context = processor.processor_context();
+        context
+            .set_parameter(ProcessorParameter::EnhancementLevel, 0.3)
+            .unwrap();
+
+        let mut audio = vec![0.0f32; mono.num_frames];
+        processor.process_interleaved(&mut audio).unwrap();
+
+        processor.reinitialize(&stereo).unwrap();
+
+        let mut audio = vec![0.0f32; 2 * stereo.num_frames];
+        processor.process_interleaved(&mut audio).unwrap();
+
+        // Mono buffers no longer match the configuration
+        let mut audio = vec![0.0f32; mono.num_frames];
+        assert_eq!(
+            processor.process_interleaved(&mut audio),
+            Err(AicError::AudioConfigMismatch)
+        );
+
+        let enhancement_level = context
+            .parameter(ProcessorParameter::EnhancementLevel)
+            .unwrap();
+        assert!((enhancement_level - 0.3).abs() < 1e-6);
+    }
+
+    #[test]
+    fn initialize_rejects_an_initialized_processor() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model);
+
+        let mut processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+        assert_eq!(
+            processor.initialize(&config.clone().with_num_channels(2)),
+            Err(AicError::AlreadyInitialized)
+        );
+
+        // The processor keeps its configuration
+        assert_eq!(processor.config(), Some(&config));
+        let mut audio = vec![0.0f32; config.num_frames];
+        processor.process_interleaved(&mut audio).unwrap();
+    }
+
+    #[test]
+    fn reinitialize_is_an_explicit_opt_in() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model);
+
+        let mut processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+        let stereo = config.with_num_channels(2);
+        processor.reinitialize(&stereo).unwrap();
+        assert_eq!(processor.config(), Some(&stereo));
+
+        let mut audio = vec![0.0f32; 2 * stereo.num_frames];
+        processor.process_interleaved(&mut audio).unwrap();
+    }
+
+    #[test]
+    fn initialize_rejects_zero_channels() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model).with_num_channels(0);
+
+        let mut processor = Processor::new(&model, &license_key).unwrap();
+        assert_eq!(
+            processor.initialize(&config),
+            Err(AicError::AudioConfigUnsupported)
+        );
+
+        // The processor stays uninitialized instead of dividing by zero
+        let mut audio = vec![0.0f32; config.num_frames];
+        assert_eq!(
+            processor.process_interleaved(&mut audio),
+            Err(AicError::ProcessorNotInitialized)
+        );
+        assert_eq!(
+            processor.process_sequential(&mut audio),
+            Err(AicError::ProcessorNotInitialized)
+        );
+        let mut planar: [Vec<f32>; 0] = [];
+        assert_eq!(
+            processor.process_planar(&mut planar),
+            Err(AicError::ProcessorNotInitialized)
+        );
+    }
+
+    #[test]
+    fn process_interleaved_mix_rejects_invalid_ratios() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model);
+        let mut processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+        let mut audio = vec![0.0f32; config.num_frames];
+
+        for dry_wet in [-0.1, 1.1, f32::NAN] {
+            assert_eq!(
+                processor.process_interleaved_mix(&mut audio, dry_wet),
+                Err(AicError::ParameterOutOfRange)
+            );
+        }
+    }
+
+    #[test]
+    fn process_interleaved_mix_dry_is_delay_compensated() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+        let mut processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+        let delay = processor.processor_context().output_delay();
+
+        let num_blocks = delay / config.num_frames + 2;
+        let block_len = 2 * config.num_frames;
+        let input: Vec<f32> = (0..num_blocks * block_len)
+            .map(|i| (i as f32 * 0.01).sin() * 0.5)
+            .collect();
+
+        let mut output = input.clone();
+        for block in output.chunks_exact_mut(block_len) {
+            processor.process_interleaved_mix(block, 0.0).unwrap();
+        }
+
+        // Fully dry output is the input delayed by the output delay
+        assert!(output[..2 * delay].iter().all(|&sample| sample == 0.0));
+        assert_eq!(output[2 * delay..], input[..input.len() - 2 * delay]);
+    }
+
+    #[test]
+    fn process_interleaved_mix_failed_block_keeps_dry_aligned() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+        let mut processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+        let delay = processor.processor_context().output_delay();
+
+        let num_blocks = delay / config.num_frames + 2;
+        let block_len = 2 * config.num_frames;
+        let input: Vec<f32> = (0..num_blocks * block_len)
+            .map(|i| (i as f32 * 0.01).sin() * 0.5)
+            .collect();
+
+        let mut output = input.clone();
+        for (i, block) in output.chunks_exact_mut(block_len).enumerate() {
+            if i == 1 {
+                // Without variable frames, the SDK rejects blocks shorter than `num_frames`
+                let mut short_block = vec![0.5f32; block_len - 2];
+                assert_eq!(
+                    processor.process_interleaved_mix(&mut short_block, 0.0),
+                    Err(AicError::AudioConfigMismatch)
+                );
+            }
+            processor.process_interleaved_mix(block, 0.0).unwrap();
+        }
+
+        // The failed block is not part of the dry signal
+        assert!(output[..2 * delay].iter().all(|&sample| sample == 0.0));
+        assert_eq!(output[2 * delay..], input[..input.len() - 2 * delay]);
+    }
+
+    #[test]
+    fn process_interleaved_mix_fully_wet_matches_processing() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model);
+        let input: Vec<f32> = (0..config.num_frames)
+            .map(|i| (i as f32 * 0.01).sin() * 0.5)
+            .collect();
+
+        let mut mixed = input.clone();
+        Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap()
+            .process_interleaved_mix(&mut mixed, 1.0)
+            .unwrap();
+
+        let mut processed = input;
+        Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap()
+            .process_interleaved(&mut processed)
+            .unwrap();
+
+        assert_eq!(mixed, processed);
+    }
+
+    /// Returns the lengths of `num_buffers` planar buffers of `len` samples each, except for
+    /// the buffer at `odd_channel`, which holds `odd_len` samples.
+    fn planar_lens(
+        num_buffers: usize,
+        len: usize,
+        odd_channel: usize,
+        odd_len: usize,
+    ) -> Vec<usize> {
+        (0..num_buffers)
+            .map(|channel| if channel == odd_channel { odd_len } else { len })
+            .collect()
+    }
+
+    #[test]
+    fn audio_layout_validation_is_consistent() {
+        const MAX_NUM_FRAMES: usize = 13;
+
+        for num_channels in 0..=Processor::MAX_CHANNELS + 1 {
+            let channels = num_channels as usize;
+            let supported = (1..=Processor::MAX_CHANNELS).contains(&num_channels);
+
+            // Contiguous buffers, including empty and prime-number lengths
+            for len in 0..=channels * (MAX_NUM_FRAMES + 2) + 7 {
+                let result = validate_audio_layout(
+                    num_channels,
+                    MAX_NUM_FRAMES,
+                    AudioLayout::Contiguous(len),
+                );
+                let expected = if !supported {
+                    Err(AicError::AudioConfigUnsupported)
+                } else if !len.is_multiple_of(channels)
+                    || len == 0
+                    || len / channels > MAX_NUM_FRAMES
+                {
+                    Err(AicError::AudioConfigMismatch)
+                } else {
+                    Ok(len / channels)
+                };
+                assert_eq!(result, expected, "{num_channels} channels, {len} samples");
+            }
+
+            // Planar buffers, including a wrong number of buffers and buffers of different lengths
+            for num_buffers in 0..=Processor::MAX_CHANNELS as usize + 2 {
+                for len in 0..=MAX_NUM_FRAMES + 2 {
+                    for odd_len in [len, len + 1, len.saturating_sub(1)] {
+                        for odd_channel in [0, num_buffers / 2, num_buffers.saturating_sub(1)] {
+                            let lens = planar_lens(num_buffers, len, odd_channel, odd_len);
+                            let result = validate_audio_layout(
+                                num_channels,
+                                MAX_NUM_FRAMES,
+                                AudioLayout::Planar(&mut lens.iter().copied()),
+                            );
+
+                            let num_frames = lens.first().copied().unwrap_or(0);
+                            let expected = if !supported {
+                                Err(AicError::AudioConfigUnsupported)
+                            } else if lens.len() != channels
+                                || lens.iter().any(|&channel_len| channel_len != num_frames)
+                                || num_frames == 0
+                                || num_frames > MAX_NUM_FRAMES
+                            {
+                                Err(AicError::AudioConfigMismatch)
+                            } else {
+                                Ok(num_frames)
+                            };
+                            assert_eq!(result, expected, "{num_channels} channels, {lens:?}");
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn empty_buffers_are_rejected_by_all_process_methods() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model)
+            .with_num_channels(2)
+            .with_allow_variable_frames(true);
+        let mut processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+
+        assert_eq!(
+            processor.process_interleaved(&mut []),
+            Err(AicError::AudioConfigMismatch)
+        );
+        assert_eq!(
+            processor.process_sequential(&mut []),
+            Err(AicError::AudioConfigMismatch)
+        );
+        let mut planar = vec![Vec::<f32>::new(); 2];
+        assert_eq!(
+            processor.process_planar(&mut planar),
+            Err(AicError::AudioConfigMismatch)
+        );
+        assert_eq!(
+            processor.process_interleaved_mix(&mut [], 0.5),
+            Err(AicError::AudioConfigMismatch)
+        );
+        assert_eq!(
+            processor.process_to(&[], &mut []),
+            Err(AicError::AudioConfigMismatch)
+        );
+    }
+
+    #[test]
+    fn processor_parameter_names_round_trip() {
+        for parameter in ProcessorParameter::ALL {
+            assert_eq!(parameter.to_string(), parameter.as_str());
+            assert_eq!(parameter.as_str().parse(), Ok(parameter));
+        }
+
+        assert_eq!(
+            ProcessorParameter::EnhancementLevel.as_str(),
+            "enhancement_level"
+        );
+    }
+
+    #[test]
+    fn unknown_processor_parameter_names_are_rejected() {
+        let error = "EnhancementLevel"
+            .parse::<ProcessorParameter>()
+            .unwrap_err();
+        assert_eq!(error.name(), "EnhancementLevel");
+        assert_eq!(
+            error.to_string(),
+            "Unknown parameter name `EnhancementLevel`"
+        );
+    }
+
+    #[test]
+    fn contexts_can_be_created_without_panicking() {
+        let (model, license_key) = load_test_model().unwrap();
+        let mut processor = Processor::new(&model, &license_key).unwrap();
+
+        // Contexts are available before and after initialization
+        assert!(processor.try_processor_context().is_ok());
+        assert!(processor.try_vad_context().is_ok());
+
+        processor
+            .initialize(&ProcessorConfig::optimal(&model))
+            .unwrap();
+        let context = processor.try_processor_context().unwrap();
+        assert_eq!(
+            context.output_delay(),
+            processor.processor_context().output_delay()
+        );
+        assert!(processor.try_vad_context().is_ok());
+    }
+
+    #[test]
+    fn bypass_round_trips_through_parameter() {
+        let (model, license_key) = load_test_model().unwrap();
+        let processor = Processor::new(&model, &license_key).unwrap();
+        let context = processor.processor_context();
+
+        for enabled in [true, false] {
+            context.set_bypass(enabled).unwrap();
+            assert_eq!(context.bypass(), Ok(enabled));
+            assert_eq!(
+                context.parameter(ProcessorParameter::Bypass),
+                Ok(if enabled { 1.0 } else { 0.0 })
+            );
+        }
+    }
+
+    #[test]
+    fn bypass_guard_restores_previous_value() {
+        let (model, license_key) = load_test_model().unwrap();
+        let processor = Processor::new(&model, &license_key).unwrap();
+        let context = processor.processor_context();
+
+        for previous in [0.0, 1.0] {
+            context
+                .set_parameter(ProcessorParameter::Bypass, previous)
+                .unwrap();
+
+            let guard = context.bypass_guard().unwrap();
+            assert_eq!(context.parameter(ProcessorParameter::Bypass), Ok(1.0));
+
+            drop(guard);
+            assert_eq!(context.parameter(ProcessorParameter::Bypass), Ok(previous));
+        }
+    }
+
+    #[test]
+    fn default_config_does_not_need_a_model() {
+        let config = ProcessorConfig::default().with_num_channels(2);
+
+        assert_eq!(
+            config,
+            ProcessorConfig {
+                sample_rate: 48000,
+                num_channels: 2,
+                num_frames: 480,
+                allow_variable_frames: false,
+            }
+        );
+        assert_ne!(config, ProcessorConfig::default());
+    }
+
+    #[test]
+    fn blocks_larger_than_num_frames_are_rejected() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model)
+            .with_num_channels(2)
+            .with_allow_variable_frames(true);
+        let mut processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+
+        let mut audio = vec![0.0f32; 2 * (config.num_frames + 1)];
+        assert_eq!(
+            processor.process_interleaved(&mut audio),
+            Err(AicError::AudioConfigMismatch)
+        );
+        assert_eq!(
+            processor.process_sequential(&mut audio),
+            Err(AicError::AudioConfigMismatch)
+        );
+        let mut planar = vec![vec![0.0f32; config.num_frames + 1]; 2];
+        assert_eq!(
+            processor.process_planar(&mut planar),
+            Err(AicError::AudioConfigMismatch)
+        );
+
+        // Smaller blocks are fine with variable frames
+        let mut audio = vec![0.0f32; 2 * (config.num_frames - 1)];
+        assert_eq!(processor.process_interleaved(&mut audio), Ok(()));
+        assert_eq!(processor.process_sequential(&mut audio), Ok(()));
+        let mut planar = vec![vec![0.0f32; config.num_frames - 1]; 2];
+        assert_eq!(processor.process_planar(&mut planar), Ok(()));
+    }
+
+    #[test]
+    fn warmup_gives_a_deterministic_starting_point() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model);
+        let input: Vec<f32> = (0..config.num_frames)
+            .map(|i| (i as f32 * 0.01).sin() * 0.5)
+            .collect();
+
+        let mut fresh = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+        fresh.warmup().unwrap();
+        let mut fresh_output = input.clone();
+        fresh.process_interleaved(&mut fresh_output).unwrap();
+
+        // A processor with history behaves like a fresh one after warming up
+        let mut used = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+        for _ in 0..4 {
+            let mut noise: Vec<f32> = (0..config.num_frames)
+                .map(|i| ((i * 7919) % 101) as f32 / 101.0 - 0.5)
+                .collect();
+            used.process_interleaved(&mut noise).unwrap();
+        }
+        used.warmup().unwrap();
+        let mut used_output = input;
+        used.process_interleaved(&mut used_output).unwrap();
+
+        assert_eq!(fresh_output, used_output);
+    }
+
+    #[test]
+    fn for_each_block_skips_ragged_blocks_without_variable_frames() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+        let mut processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+
+        let mut audio = vec![0.0f32; 2 * (3 * config.num_frames + 10)];
+        let mut blocks = Vec::new();
+        let processed = processor
+            .for_each_block(&mut audio, |block, _| blocks.push(block.len()))
+            .unwrap();
+
+        assert_eq!(processed, 3 * config.num_frames);
+        assert_eq!(blocks, vec![2 * config.num_frames; 3]);
+    }
+
+    #[test]
+    fn for_each_block_processes_ragged_blocks_with_variable_frames() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model).with_allow_variable_frames(true);
+        let mut processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+
+        let mut audio = vec![0.0f32; 2 * config.num_frames + 10];
+        let mut blocks = Vec::new();
+        let processed = processor
+            .for_each_block(&mut audio, |block, _| blocks.push(block.len()))
+            .unwrap();
+
+        assert_eq!(processed, audio.len());
+        assert_eq!(blocks, vec![config.num_frames, config.num_frames, 10]);
+    }
+
+    #[test]
+    fn for_each_block_rejects_partial_frames() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+        let mut processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+
+        let mut audio = vec![0.0f32; 2 * config.num_frames + 1];
+        assert_eq!(
+            processor.for_each_block(&mut audio, |_, _| {}),
+            Err(AicError::AudioConfigMismatch)
+        );
     }
 
-    fn as_const_ptr(&self) -> *const AicProcessor {
-        self.inner as *const AicProcessor
-    }
-}
+    #[test]
+    fn sanitizing_replaces_non_finite_input() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+        let mut processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+        assert!(!processor.sanitizes_input());
 
-impl<'a> Drop for Processor<'a> {
-    fn drop(&mut self) {
-        if !self.inner.is_null() {
-            // SAFETY:
-            // - `self.inner` was allocated by the SDK and is still owned by this wrapper.
-            // - This function is not thread-safe with concurrent processor use, but
-            //   `drop` has exclusive access to `self`.
-            unsafe { aic_processor_destroy(self.inner) };
-        }
-    }
-}
+        processor.set_sanitize_input(true);
+        assert!(processor.sanitizes_input());
 
-// SAFETY: Everything in Processor is Send, with the exception of the inner raw pointer.
-// The Processor only uses the raw pointer according to the safety contracts of the
-// unsafe APIs that require the pointer, and the Processor does not expose access to the
-// raw pointer in any of its methods. Therefore, it safe to implement Send for Processor.
-unsafe impl<'a> Send for Processor<'a> {}
+        let mut interleaved = vec![0.1f32; 2 * config.num_frames];
+        interleaved[0] = f32::NAN;
+        interleaved[3] = f32::INFINITY;
+        processor.process_interleaved(&mut interleaved).unwrap();
+        assert!(interleaved.iter().all(|sample| sample.is_finite()));
 
-// SAFETY: Processor does not expose any interior mutability, and all unsafe APIs that make use of
-// the inner raw pointer are only used in methods that take &mut self, which upholds the thread safety
-// contracts required by the unsafe APIs. Therefore, it is safe to implement Sync for Processor.
-unsafe impl<'a> Sync for Processor<'a> {}
+        let mut planar = vec![vec![0.1f32; config.num_frames]; 2];
+        planar[1][5] = f32::NEG_INFINITY;
+        processor.process_planar(&mut planar).unwrap();
+        assert!(planar.iter().flatten().all(|sample| sample.is_finite()));
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::{
-        fs,
-        path::{Path, PathBuf},
-        sync::{Mutex, OnceLock},
-    };
+        let mut sequential = vec![0.1f32; 2 * config.num_frames];
+        sequential[7] = f32::NAN;
+        processor.process_sequential(&mut sequential).unwrap();
+        assert!(sequential.iter().all(|sample| sample.is_finite()));
 
-    fn download_lock() -> &'static Mutex<()> {
-        static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
-        LOCK.get_or_init(|| Mutex::new(()))
-    }
-
-    fn find_existing_model(target_dir: &Path) -> Option<PathBuf> {
-        let entries = fs::read_dir(target_dir).ok()?;
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if path
-                .file_name()
-                .and_then(|n| n.to_str())
-                .map(|name| name.contains("rook_s_48khz") && name.ends_with(".aicmodel"))
-                .unwrap_or(false)
-                && path.is_file()
-            {
-                return Some(path);
-            }
-        }
-        None
+        assert_eq!(processor.sanitized_samples(), 4);
+
+        processor.set_sanitize_input(false);
+        assert_eq!(processor.sanitized_samples(), 0);
     }
 
-    /// Downloads the default test model `rook-s-48khz` into the crate's `target/` directory.
-    /// Returns the path to the downloaded model file.
-    fn get_rook_s_48khz() -> Result<PathBuf, AicError> {
-        let target_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("target");
+    #[test]
+    fn sanitizing_keeps_non_finite_samples_out_of_the_dry_signal() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model);
+        let mut processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+        processor.set_sanitize_input(true);
 
-        if let Some(existing) = find_existing_model(&target_dir) {
-            return Ok(existing);
+        let delay = processor.processor_context().output_delay();
+        for _ in 0..=delay.div_ceil(config.num_frames) {
+            let mut audio = vec![f32::NAN; config.num_frames];
+            processor.process_interleaved_mix(&mut audio, 0.5).unwrap();
+            assert!(audio.iter().all(|sample| sample.is_finite()));
         }
+    }
 
-        let _guard = download_lock().lock().unwrap();
-        if let Some(existing) = find_existing_model(&target_dir) {
-            return Ok(existing);
-        }
+    #[test]
+    fn levels_are_measured_per_channel() {
+        let (peak, rms) = measure_levels(&[0.5, -1.0, -0.5, 0.0, 0.5, 0.0, -0.5, 0.0], 2);
 
-        if cfg!(feature = "download-model") {
-            Model::download("rook-s-48khz", target_dir)
-        } else {
-            panic!(
-                "Model `rook-s-48khz` not found in {} and `download-model` feature is disabled",
-                target_dir.display()
-            );
-        }
+        assert_eq!(peak, vec![0.5, 1.0]);
+        assert_eq!(rms, vec![0.5, 0.5]);
+        assert_eq!(measure_levels(&[], 2), (vec![0.0; 2], vec![0.0; 2]));
     }
 
-    fn load_test_model() -> Result<(Model<'static>, String), AicError> {
-        let license_key = std::env::var("AIC_SDK_LICENSE")
-            .expect("AIC_SDK_LICENSE environment variable must be set for tests");
+    #[test]
+    fn metered_processing_reports_input_and_output_levels() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+        let mut processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+        processor
+            .processor_context()
+            .set_parameter(ProcessorParameter::Bypass, 1.0)
+            .unwrap();
+
+        let mut audio: Vec<f32> = (0..2 * config.num_frames)
+            .map(|i| if i % 2 == 0 { 0.25 } else { -0.5 })
+            .collect();
+        let levels = processor.process_interleaved_metered(&mut audio).unwrap();
 
-        let model_path = get_rook_s_48khz()?;
-        let model = Model::from_file(&model_path)?;
+        assert_eq!(levels.input_peak, vec![0.25, 0.5]);
+        assert_eq!(levels.input_rms, vec![0.25, 0.5]);
+        assert_eq!(levels.output_peak.len(), 2);
+        assert_eq!(levels.output_rms.len(), 2);
 
-        Ok((model, license_key))
+        let (output_peak, output_rms) = measure_levels(&audio, 2);
+        assert_eq!(levels.output_peak, output_peak);
+        assert_eq!(levels.output_rms, output_rms);
     }
 
     #[test]
-    fn model_creation_and_basic_operations() {
-        dbg!(crate::get_sdk_version());
-        dbg!(crate::get_compatible_model_version());
-
+    fn swap_model_keeps_config_and_parameters() {
         let (model, license_key) = load_test_model().unwrap();
         let config = ProcessorConfig::optimal(&model).with_num_channels(2);
-
         let mut processor = Processor::new(&model, &license_key)
             .unwrap()
             .with_config(&config)
             .unwrap();
+        processor
+            .processor_context()
+            .set_parameter(ProcessorParameter::EnhancementLevel, 0.3)
+            .unwrap();
+        processor
+            .vad_context()
+            .set_parameter(crate::VadParameter::Sensitivity, 9.0)
+            .unwrap();
+        processor.set_downmix(&[1.0, 0.0]).unwrap();
 
-        let num_channels = config.num_channels as usize;
-        let mut audio = vec![vec![0.0f32; config.num_frames]; num_channels];
-        let mut audio_refs: Vec<&mut [f32]> =
-            audio.iter_mut().map(|ch| ch.as_mut_slice()).collect();
+        processor.swap_model(&model.clone(), &license_key).unwrap();
+
+        assert_eq!(processor.config(), Some(&config));
+        assert_eq!(processor.downmix(), Some([1.0, 0.0].as_slice()));
+        assert_eq!(
+            processor
+                .processor_context()
+                .parameter(ProcessorParameter::EnhancementLevel),
+            Ok(0.3)
+        );
+        assert_eq!(
+            processor
+                .vad_context()
+                .parameter(crate::VadParameter::Sensitivity),
+            Ok(9.0)
+        );
 
-        processor.process_planar(&mut audio_refs).unwrap();
+        let mut audio = vec![0.0f32; 2 * config.num_frames];
+        processor.process_interleaved(&mut audio).unwrap();
     }
 
     #[test]
-    fn process_interleaved_fixed_frames() {
+    fn failed_swap_keeps_the_current_processor() {
         let (model, license_key) = load_test_model().unwrap();
-        let config = ProcessorConfig::optimal(&model).with_num_channels(2);
-
+        let config = ProcessorConfig::optimal(&model);
         let mut processor = Processor::new(&model, &license_key)
             .unwrap()
             .with_config(&config)
             .unwrap();
 
-        let num_channels = config.num_channels as usize;
-        let mut audio = vec![0.0f32; num_channels * config.num_frames];
+        assert_eq!(
+            processor.swap_model(&model, "invalid\0license"),
+            Err(AicError::LicenseFormatInvalid)
+        );
+
+        assert_eq!(processor.config(), Some(&config));
+        let mut audio = vec![0.0f32; config.num_frames];
         processor.process_interleaved(&mut audio).unwrap();
     }
 
     #[test]
-    fn process_planar_fixed_frames() {
+    fn frames_convert_to_durations() {
+        let at = |sample_rate| ProcessorConfig {
+            sample_rate,
+            ..Default::default()
+        };
+
+        assert_eq!(at(48000).frames_to_duration(480), Duration::from_millis(10));
+        assert_eq!(at(44100).frames_to_duration(441), Duration::from_millis(10));
+        assert_eq!(at(16000).frames_to_duration(256), Duration::from_millis(16));
+        assert_eq!(at(8000).frames_to_duration(0), Duration::ZERO);
+        // Not a whole number of milliseconds or nanoseconds
+        assert_eq!(at(44100).frames_to_duration(1), Duration::from_nanos(22676));
+        assert_eq!(
+            at(44100).frames_to_duration(1024),
+            Duration::from_nanos(23_219_955)
+        );
+        assert_eq!(
+            at(22050).frames_to_duration(256),
+            Duration::from_nanos(11_609_977)
+        );
+    }
+
+    #[test]
+    fn durations_convert_to_frames() {
+        let at = |sample_rate| ProcessorConfig {
+            sample_rate,
+            ..Default::default()
+        };
+
+        assert_eq!(at(48000).duration_to_frames(Duration::from_millis(10)), 480);
+        assert_eq!(at(44100).duration_to_frames(Duration::from_millis(10)), 441);
+        assert_eq!(at(16000).duration_to_frames(Duration::from_millis(20)), 320);
+        assert_eq!(at(8000).duration_to_frames(Duration::ZERO), 0);
+        // 220.5 frames are rounded up, 110.25 frames down
+        assert_eq!(at(22050).duration_to_frames(Duration::from_millis(10)), 221);
+        assert_eq!(
+            at(22050).duration_to_frames(Duration::from_micros(5000)),
+            110
+        );
+        // 1 ms at 44.1 kHz is 44.1 frames
+        assert_eq!(at(44100).duration_to_frames(Duration::from_millis(1)), 44);
+    }
+
+    #[test]
+    fn conversions_round_trip() {
+        for sample_rate in [8000, 16000, 22050, 44100, 48000, 96000] {
+            let config = ProcessorConfig {
+                sample_rate,
+                ..Default::default()
+            };
+            for frames in [0, 1, 160, 441, 480, 1024, 4800] {
+                assert_eq!(
+                    config.duration_to_frames(config.frames_to_duration(frames)),
+                    frames
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn output_delay_duration_matches_output_delay() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model);
+        let mut processor = Processor::new(&model, &license_key).unwrap();
+        assert_eq!(
+            processor.output_delay_duration(),
+            Err(AicError::ProcessorNotInitialized)
+        );
+
+        processor.initialize(&config).unwrap();
+        let delay = processor.processor_context().output_delay();
+        assert_eq!(
+            processor.output_delay_duration(),
+            Ok(config.frames_to_duration(delay))
+        );
+    }
+
+    #[test]
+    fn debug_reports_initialization_state() {
+        let (model, license_key) = load_test_model().unwrap();
+        let mut processor = Processor::new(&model, &license_key).unwrap();
+        assert_eq!(
+            format!("{processor:?}"),
+            "Processor { non_null: true, initialized: false, num_channels: None, .. }"
+        );
+
+        processor
+            .initialize(&ProcessorConfig::optimal(&model).with_num_channels(2))
+            .unwrap();
+        assert_eq!(
+            format!("{processor:?}"),
+            "Processor { non_null: true, initialized: true, num_channels: Some(2), .. }"
+        );
+        assert_eq!(
+            format!("{:?}", processor.processor_context()),
+            "ProcessorContext { non_null: true, .. }"
+        );
+        assert_eq!(
+            format!("{:?}", processor.vad_context()),
+            "VadContext { non_null: true, .. }"
+        );
+        assert_eq!(format!("{model:?}"), "Model { non_null: true, .. }");
+    }
+
+    #[test]
+    fn warmup_requires_initialization() {
+        let (model, license_key) = load_test_model().unwrap();
+        let mut processor = Processor::new(&model, &license_key).unwrap();
+
+        assert_eq!(processor.warmup(), Err(AicError::ProcessorNotInitialized));
+    }
+
+    #[test]
+    fn multichannel_audio_is_downmixed() {
         let (model, license_key) = load_test_model().unwrap();
+        let processor = Processor::new(&model, &license_key).unwrap();
+
+        assert_eq!(processor.channel_mode(), ChannelMode::Downmixed);
+    }
+
+    #[test]
+    fn accessors_reflect_initialization() {
+        let (model, license_key) = load_test_model().unwrap();
+        let mut processor = Processor::new(&model, &license_key).unwrap();
+        assert!(!processor.is_initialized());
+        assert_eq!(processor.num_channels(), None);
+        assert_eq!(processor.config(), None);
+
         let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+        processor.initialize(&config).unwrap();
+        assert!(processor.is_initialized());
+        assert_eq!(processor.num_channels(), Some(2));
+        assert_eq!(processor.config(), Some(&config));
+
+        // A rejected configuration leaves the processor uninitialized
+        assert!(
+            processor
+                .reinitialize(&config.clone().with_num_channels(0))
+                .is_err()
+        );
+        assert!(!processor.is_initialized());
+        assert_eq!(processor.num_channels(), None);
+    }
+
+    #[test]
+    fn initialize_rejects_more_than_max_channels() {
+        let (model, license_key) = load_test_model().unwrap();
+        let mut processor = Processor::new(&model, &license_key).unwrap();
+
+        let config = ProcessorConfig::optimal(&model).with_num_channels(Processor::MAX_CHANNELS);
+        assert_eq!(processor.initialize(&config), Ok(()));
+
+        let config = config.with_num_channels(Processor::MAX_CHANNELS + 1);
+        let mut processor = Processor::new(&model, &license_key).unwrap();
+        assert_eq!(
+            processor.initialize(&config),
+            Err(AicError::AudioConfigUnsupported)
+        );
+
+        let mut planar = vec![vec![0.0f32; config.num_frames]; config.num_channels as usize];
+        assert_eq!(
+            processor.process_planar(&mut planar),
+            Err(AicError::ProcessorNotInitialized)
+        );
+    }
+
+    #[test]
+    fn process_planar_checks_the_channel_limit_first() {
+        let (model, license_key) = load_test_model().unwrap();
+        let mut processor = Processor::new(&model, &license_key).unwrap();
+        processor.config =
+            Some(ProcessorConfig::optimal(&model).with_num_channels(Processor::MAX_CHANNELS + 1));
+
+        // No channel buffers at all: the limit is reported before the buffers are inspected
+        let mut planar: [Vec<f32>; 0] = [];
+        assert_eq!(
+            processor.process_planar(&mut planar),
+            Err(AicError::AudioConfigUnsupported)
+        );
+    }
+
+    #[test]
+    fn zero_configured_channels_return_an_error() {
+        let (model, license_key) = load_test_model().unwrap();
+        let mut processor = Processor::new(&model, &license_key).unwrap();
+        processor.config = Some(ProcessorConfig::optimal(&model).with_num_channels(0));
+
+        let mut audio = vec![0.0f32; 16];
+        assert_eq!(
+            processor.process_interleaved(&mut audio),
+            Err(AicError::AudioConfigUnsupported)
+        );
+        assert_eq!(
+            processor.process_sequential(&mut audio),
+            Err(AicError::AudioConfigUnsupported)
+        );
+    }
+
+    #[test]
+    fn with_parameter_is_in_effect_for_the_first_block() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model);
+        let input: Vec<f32> = (0..config.num_frames)
+            .map(|i| (i as f32 * 0.01).sin() * 0.5)
+            .collect();
 
         let mut processor = Processor::new(&model, &license_key)
             .unwrap()
             .with_config(&config)
+            .unwrap()
+            .with_parameter(ProcessorParameter::EnhancementLevel, 0.2)
+            .unwrap();
+        let level = processor
+            .processor_context()
+            .parameter(ProcessorParameter::EnhancementLevel)
             .unwrap();
+        assert!((level - 0.2).abs() < 1e-6);
 
-        let mut left = vec![0.0f32; config.num_frames];
-        let mut right = vec![0.0f32; config.num_frames];
-        let mut audio = [left.as_mut_slice(), right.as_mut_slice()];
-        processor.process_planar(&mut audio).unwrap();
+        let mut output = input.clone();
+        processor.process_interleaved(&mut output).unwrap();
+
+        // Setting the parameter through a context before processing yields the same output
+        let mut expected_processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+        expected_processor
+            .processor_context()
+            .set_parameter(ProcessorParameter::EnhancementLevel, 0.2)
+            .unwrap();
+        let mut expected = input;
+        expected_processor
+            .process_interleaved(&mut expected)
+            .unwrap();
+
+        assert_eq!(output, expected);
     }
 
     #[test]
-    fn process_sequential_fixed_frames() {
+    fn with_parameter_rejects_out_of_range_values() {
         let (model, license_key) = load_test_model().unwrap();
-        let config = ProcessorConfig::optimal(&model).with_num_channels(2);
 
-        let mut processor = Processor::new(&model, &license_key)
+        let result = Processor::new(&model, &license_key)
             .unwrap()
-            .with_config(&config)
+            .with_config(&ProcessorConfig::optimal(&model))
+            .unwrap()
+            .with_parameter(ProcessorParameter::EnhancementLevel, 2.0);
+
+        assert!(matches!(result, Err(AicError::ParameterOutOfRange)));
+    }
+
+    #[test]
+    fn enhancement_level_reflects_set_enhancement_level() {
+        let (model, license_key) = load_test_model().unwrap();
+        let processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&ProcessorConfig::optimal(&model))
             .unwrap();
 
-        let num_channels = config.num_channels as usize;
-        let mut audio = vec![0.0f32; num_channels * config.num_frames];
-        processor.process_sequential(&mut audio).unwrap();
+        processor.set_enhancement_level(0.3).unwrap();
+        assert_eq!(processor.enhancement_level().unwrap(), 0.3);
+        assert_eq!(
+            processor
+                .processor_context()
+                .parameter(ProcessorParameter::EnhancementLevel)
+                .unwrap(),
+            0.3
+        );
+
+        for level in [-0.1, 1.5, f32::NAN] {
+            assert_eq!(
+                processor.set_enhancement_level(level),
+                Err(AicError::ParameterOutOfRange)
+            );
+        }
+        assert_eq!(processor.enhancement_level().unwrap(), 0.3);
     }
 
     #[test]
@@ -1244,6 +4430,113 @@ mod tests {
         assert_eq!(result, Err(AicError::AudioConfigMismatch));
     }
 
+    #[test]
+    fn output_delay_grows_away_from_the_internal_block_size() {
+        let (model, license_key) = load_test_model().unwrap();
+        let optimal = ProcessorConfig::optimal(&model);
+        let delay_with = |num_frames: usize| {
+            let config = ProcessorConfig {
+                num_frames,
+                ..optimal.clone()
+            };
+            let processor = Processor::new(&model, &license_key)
+                .unwrap()
+                .with_config(&config)
+                .unwrap();
+            assert_eq!(processor.internal_block_size(), Some(optimal.num_frames));
+            processor.processor_context().output_delay()
+        };
+
+        assert_eq!(
+            Processor::new(&model, &license_key)
+                .unwrap()
+                .internal_block_size(),
+            None
+        );
+
+        let block_size = optimal.num_frames;
+        let base_delay = delay_with(block_size);
+        for num_frames in [block_size / 2, block_size + 1, block_size * 2 - 1] {
+            assert!(delay_with(num_frames) > base_delay, "{num_frames} frames");
+        }
+    }
+
+    #[test]
+    fn smoothed_parameters_reach_the_target_after_ramp_blocks() {
+        let (model, license_key) = load_test_model().unwrap();
+        let processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&ProcessorConfig::optimal(&model))
+            .unwrap();
+        let context = processor.processor_context();
+        context
+            .set_parameter(ProcessorParameter::EnhancementLevel, 0.0)
+            .unwrap();
+
+        context
+            .set_parameter_smoothed(ProcessorParameter::EnhancementLevel, 1.0, 4)
+            .unwrap();
+        for (block, expected) in [0.25, 0.5, 0.75].into_iter().enumerate() {
+            assert_eq!(context.apply_ramps(), Ok(1), "block {block}");
+            assert_eq!(
+                context.parameter(ProcessorParameter::EnhancementLevel),
+                Ok(expected)
+            );
+        }
+        assert_eq!(context.apply_ramps(), Ok(0));
+        assert_eq!(
+            context.parameter(ProcessorParameter::EnhancementLevel),
+            Ok(1.0)
+        );
+
+        // Finished ramps leave the parameter alone
+        assert_eq!(context.apply_ramps(), Ok(0));
+        assert_eq!(
+            context.parameter(ProcessorParameter::EnhancementLevel),
+            Ok(1.0)
+        );
+
+        assert_eq!(
+            context.set_parameter_smoothed(ProcessorParameter::EnhancementLevel, 1.5, 4),
+            Err(AicError::ParameterOutOfRange)
+        );
+        context
+            .set_parameter_smoothed(ProcessorParameter::EnhancementLevel, 0.2, 0)
+            .unwrap();
+        assert_eq!(
+            context.parameter(ProcessorParameter::EnhancementLevel),
+            Ok(0.2)
+        );
+    }
+
+    #[test]
+    fn smoothed_parameters_are_shared_between_contexts() {
+        let (model, license_key) = load_test_model().unwrap();
+        let processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&ProcessorConfig::optimal(&model))
+            .unwrap();
+        let control = processor.processor_context();
+        let audio = processor.processor_context();
+        control
+            .set_parameter(ProcessorParameter::EnhancementLevel, 0.0)
+            .unwrap();
+
+        control
+            .set_parameter_smoothed(ProcessorParameter::EnhancementLevel, 1.0, 2)
+            .unwrap();
+        assert_eq!(audio.apply_ramps(), Ok(1));
+        assert_eq!(
+            control.parameter(ProcessorParameter::EnhancementLevel),
+            Ok(0.5)
+        );
+        assert_eq!(processor.processor_context().apply_ramps(), Ok(0));
+        assert_eq!(
+            control.parameter(ProcessorParameter::EnhancementLevel),
+            Ok(1.0)
+        );
+    }
+
     #[test]
     fn model_can_be_dropped_after_creating_processor() {
         let (model, license_key) = load_test_model().unwrap();
@@ -1257,10 +4550,64 @@ mod tests {
 
         let num_channels = config.num_channels as usize;
         let mut audio = vec![vec![0.0f32; config.num_frames]; num_channels];
-        let mut audio_refs: Vec<&mut [f32]> =
-            audio.iter_mut().map(|ch| ch.as_mut_slice()).collect();
 
-        processor.process_planar(&mut audio_refs).unwrap();
+        processor.process_planar(&mut audio).unwrap();
+    }
+
+    #[test]
+    fn model_processor_and_contexts_can_be_dropped_in_any_order() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model);
+        drop(model);
+
+        // Borrowed from by every model below, so it has to outlive all of them
+        let buffer = ModelBuffer::new(fs::read(get_rook_s_48khz().unwrap()).unwrap());
+
+        // 0: model, 1: processor, 2: processor context, 3: VAD context
+        let orders = [
+            [0, 1, 2, 3],
+            [3, 2, 1, 0],
+            [1, 0, 3, 2],
+            [0, 2, 1, 3],
+            [2, 3, 0, 1],
+        ];
+        for order in orders {
+            let model = Model::from_buffer(buffer.as_slice()).unwrap();
+            let mut processor = Processor::new(&model, &license_key)
+                .unwrap()
+                .with_config(&config)
+                .unwrap();
+            let mut audio = vec![0.0f32; config.num_frames];
+            processor.process_interleaved(&mut audio).unwrap();
+
+            let mut processor_context = Some(processor.processor_context());
+            let mut vad_context = Some(processor.vad_context());
+            let mut processor = Some(processor);
+            let mut model = Some(model);
+
+            // Everything still alive is used after each drop
+            for index in order {
+                match index {
+                    0 => drop(model.take()),
+                    1 => drop(processor.take()),
+                    2 => drop(processor_context.take()),
+                    _ => drop(vad_context.take()),
+                }
+
+                if let Some(processor) = &mut processor {
+                    processor.process_interleaved(&mut audio).unwrap();
+                }
+                if let Some(processor_context) = &processor_context {
+                    processor_context.reset().unwrap();
+                }
+                if let Some(vad_context) = &vad_context {
+                    vad_context.is_speech_detected();
+                }
+                if let Some(model) = &model {
+                    assert_eq!(model.optimal_sample_rate(), config.sample_rate);
+                }
+            }
+        }
     }
 
     #[test]
@@ -1325,4 +4672,21 @@ mod _compile_fail_tests {
     //!     processor.process_planar(&mut audio).unwrap();
     //! }
     //! ```
+    //!
+    //! Compile-fail regression: a `Processor` must not outlive its model buffer, even when the
+    //! `Model` itself is dropped inside the function.
+    //!
+    //! ```rust,compile_fail
+    //! use aic_sdk::{Model, Processor};
+    //!
+    //! fn processor_from_local_buffer(license_key: &str) -> Processor<'static> {
+    //!     let buffer = vec![0u8; 64];
+    //!     let model = Model::from_buffer(&buffer).unwrap();
+    //!     Processor::new(&model, license_key).unwrap()
+    //! }
+    //!
+    //! fn main() {
+    //!     let _ = processor_from_local_buffer("license");
+    //! }
+    //! ```
 }
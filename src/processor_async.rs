@@ -1,5 +1,5 @@
 use crate::{
-    AicError, Model, OtelConfig, Processor, ProcessorConfig, ProcessorContext, VadContext,
+    AicError, License, Model, OtelConfig, Processor, ProcessorConfig, ProcessorContext, VadContext,
 };
 use async_lock::Mutex;
 use futures_channel::oneshot;
@@ -27,6 +27,14 @@ fn get_global_thread_pool() -> &'static rayon::ThreadPool {
     })
 }
 
+/// Waits for the result of a job spawned on the thread pool.
+///
+/// The job only drops its sender without sending a result if it panicked. This is reported
+/// as [`AicError::Internal`] instead of panicking in the caller's task.
+async fn join<T>(rx: oneshot::Receiver<Result<T, AicError>>) -> Result<T, AicError> {
+    rx.await.unwrap_or(Err(AicError::Internal))
+}
+
 /// A wrapper around [`Processor`] for use in async contexts.
 ///
 /// # Threading
@@ -61,7 +69,7 @@ impl ProcessorAsync {
     /// Creates a new async audio enhancement processor instance.
     ///
     /// See [`Processor::new`] for details.
-    pub fn new(model: &Model<'static>, license_key: &str) -> Result<Self, AicError> {
+    pub fn new(model: &Model<'static>, license_key: impl Into<License>) -> Result<Self, AicError> {
         let processor = Processor::new(model, license_key)?;
         Ok(Self {
             inner: Arc::new(Mutex::new(processor)),
@@ -74,7 +82,7 @@ impl ProcessorAsync {
     /// See [`Processor::with_otel_config`] for details.
     pub fn with_otel_config(
         model: &Model<'static>,
-        license_key: &str,
+        license_key: impl Into<License>,
         otel_config: &OtelConfig,
     ) -> Result<Self, AicError> {
         let processor = Processor::with_otel_config(model, license_key, otel_config)?;
@@ -105,7 +113,23 @@ impl ProcessorAsync {
         get_global_thread_pool().spawn(move || {
             let _ = tx.send(processor.initialize(&config));
         });
-        rx.await.expect("Rayon worker dropped")
+        join(rx).await
+    }
+
+    /// Applies a new configuration to an already initialized processor.
+    ///
+    /// See [`Processor::reinitialize`] for details.
+    ///
+    /// # Warning
+    /// This allocates memory internally. Do not call from latency-sensitive paths.
+    pub async fn reinitialize(&self, config: &ProcessorConfig) -> Result<(), AicError> {
+        let config = config.clone();
+        let (tx, rx) = oneshot::channel();
+        let mut processor = self.inner.lock_arc().await;
+        get_global_thread_pool().spawn(move || {
+            let _ = tx.send(processor.reinitialize(&config));
+        });
+        join(rx).await
     }
 
     /// Processes audio with interleaved channel data.
@@ -121,7 +145,7 @@ impl ProcessorAsync {
             let result = processor.process_interleaved(&mut audio).map(|_| audio);
             let _ = tx.send(result);
         });
-        rx.await.expect("Rayon worker dropped")
+        join(rx).await
     }
 
     /// Processes audio with separate buffers for each channel (planar layout).
@@ -140,7 +164,7 @@ impl ProcessorAsync {
             let result = processor.process_planar(&mut audio).map(|_| audio);
             let _ = tx.send(result);
         });
-        rx.await.expect("Rayon worker dropped")
+        join(rx).await
     }
 
     /// Processes audio with sequential channel data.
@@ -156,7 +180,7 @@ impl ProcessorAsync {
             let result = processor.process_sequential(&mut audio).map(|_| audio);
             let _ = tx.send(result);
         });
-        rx.await.expect("Rayon worker dropped")
+        join(rx).await
     }
 
     /// Returns a [`ProcessorContext`] for real-time parameter control.
@@ -172,4 +196,18 @@ impl ProcessorAsync {
     pub async fn vad_context(&self) -> VadContext {
         self.inner.lock().await.vad_context()
     }
+
+    /// Returns a [`ProcessorContext`], or an error instead of panicking.
+    ///
+    /// See [`Processor::try_processor_context`] for details.
+    pub async fn try_processor_context(&self) -> Result<ProcessorContext, AicError> {
+        self.inner.lock().await.try_processor_context()
+    }
+
+    /// Returns a [`VadContext`], or an error instead of panicking.
+    ///
+    /// See [`Processor::try_vad_context`] for details.
+    pub async fn try_vad_context(&self) -> Result<VadContext, AicError> {
+        self.inner.lock().await.try_vad_context()
+    }
 }
@@ -0,0 +1,282 @@
+use crate::{
+    error::AicError,
+    license::License,
+    model::Model,
+    processor::{OtelConfig, Processor, ProcessorConfig, ProcessorContext, ProcessorParameter},
+    vad::{VadContext, VadParameter},
+};
+
+use std::collections::BTreeMap;
+
+/// Sets up a [`Processor`] with its configuration, parameters and VAD parameters in one call.
+///
+/// Created with [`Processor::builder`]. [`ProcessorBuilder::build`] validates the whole setup
+/// before the processor is created, then creates and initializes it, applies all parameters
+/// and returns the processor together with its [`ProcessorContext`] and [`VadContext`]. If
+/// anything is rejected, the first error is returned and no processor is handed out.
+///
+/// Without [`ProcessorBuilder::with_config`], the model's optimal configuration is used, see
+/// [`ProcessorConfig::optimal`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use aic_sdk::{Model, Processor, ProcessorConfig, ProcessorParameter, VadParameter};
+/// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+/// let model = Model::from_file("/path/to/model.aicmodel")?;
+/// let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+///
+/// let handles = Processor::builder(&model, &license_key)
+///     .with_config(&config)
+///     .with_parameter(ProcessorParameter::EnhancementLevel, 0.8)
+///     .with_vad_parameter(VadParameter::SpeechHoldDuration, 0.08)
+///     .build()?;
+///
+/// let mut processor = handles.processor;
+/// let mut audio = vec![0.0f32; config.num_channels as usize * config.num_frames];
+/// processor.process_interleaved(&mut audio)?;
+/// println!("Speech detected: {}", handles.vad_context.is_speech_detected());
+/// # Ok::<(), aic_sdk::AicError>(())
+/// ```
+#[derive(Debug)]
+#[must_use = "a builder does nothing until `build` is called"]
+pub struct ProcessorBuilder<'m, 'a> {
+    model: &'m Model<'a>,
+    license: License,
+    config: Option<ProcessorConfig>,
+    otel_config: Option<OtelConfig>,
+    parameters: BTreeMap<ProcessorParameter, f32>,
+    vad_parameters: BTreeMap<VadParameter, f32>,
+}
+
+/// A processor created by [`ProcessorBuilder::build`], together with its contexts.
+#[derive(Debug)]
+pub struct ProcessorHandles<'a> {
+    /// The initialized processor with all parameters applied.
+    pub processor: Processor<'a>,
+    /// Context to control the processor's parameters from any thread.
+    pub processor_context: ProcessorContext,
+    /// Context to query the voice activity detection from any thread.
+    pub vad_context: VadContext,
+}
+
+impl<'m, 'a> ProcessorBuilder<'m, 'a> {
+    pub(crate) fn new(model: &'m Model<'a>, license: License) -> Self {
+        Self {
+            model,
+            license,
+            config: None,
+            otel_config: None,
+            parameters: BTreeMap::new(),
+            vad_parameters: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the configuration the processor is initialized with.
+    ///
+    /// Defaults to the model's optimal configuration.
+    pub fn with_config(mut self, config: &ProcessorConfig) -> Self {
+        self.config = Some(config.clone());
+        self
+    }
+
+    /// Sets the telemetry configuration of the processor, see [`Processor::with_otel_config`].
+    pub fn with_otel_config(mut self, otel_config: &OtelConfig) -> Self {
+        self.otel_config = Some(otel_config.clone());
+        self
+    }
+
+    /// Sets the initial value of a processor parameter. Setting a parameter twice keeps the
+    /// last value.
+    pub fn with_parameter(mut self, parameter: ProcessorParameter, value: f32) -> Self {
+        self.parameters.insert(parameter, value);
+        self
+    }
+
+    /// Sets the initial values of several processor parameters, e.g. from a map of stored user
+    /// settings.
+    pub fn with_parameters(
+        mut self,
+        parameters: impl IntoIterator<Item = (ProcessorParameter, f32)>,
+    ) -> Self {
+        self.parameters.extend(parameters);
+        self
+    }
+
+    /// Sets the initial value of a VAD parameter. Setting a parameter twice keeps the last
+    /// value.
+    pub fn with_vad_parameter(mut self, parameter: VadParameter, value: f32) -> Self {
+        self.vad_parameters.insert(parameter, value);
+        self
+    }
+
+    /// Sets the initial values of several VAD parameters.
+    pub fn with_vad_parameters(
+        mut self,
+        parameters: impl IntoIterator<Item = (VadParameter, f32)>,
+    ) -> Self {
+        self.vad_parameters.extend(parameters);
+        self
+    }
+
+    /// Creates the processor, initializes it and applies all parameters.
+    ///
+    /// The license, configuration and parameter values are checked before the processor is
+    /// created, so a setup that is obviously invalid fails without allocating SDK state.
+    /// Limits that depend on the model, e.g. the range of [`VadParameter::Sensitivity`], are
+    /// checked by the SDK while the parameters are applied.
+    ///
+    /// # Returns
+    ///
+    /// Returns the processor and its contexts, or the first [`AicError`]:
+    /// [`AicError::LicenseFormatInvalid`] for a malformed license key,
+    /// [`AicError::AudioConfigUnsupported`] for a configuration the model does not support,
+    /// [`AicError::ParameterOutOfRange`] for a parameter value outside its range, or any
+    /// error reported by the SDK while creating or initializing the processor.
+    ///
+    /// # Warning
+    /// Do not call from audio processing threads as this allocates memory.
+    pub fn build(self) -> Result<ProcessorHandles<'a>, AicError> {
+        let config = self
+            .config
+            .clone()
+            .unwrap_or_else(|| ProcessorConfig::optimal(self.model));
+        self.validate(&config)?;
+
+        let mut processor = match &self.otel_config {
+            Some(otel_config) => {
+                Processor::with_otel_config(self.model, &self.license, otel_config)?
+            }
+            None => Processor::new(self.model, &self.license)?,
+        };
+        processor.initialize(&config)?;
+
+        let processor_context = processor.try_processor_context()?;
+        for (&parameter, &value) in &self.parameters {
+            processor_context.set_parameter(parameter, value)?;
+        }
+
+        let vad_context = processor.try_vad_context()?;
+        for (&parameter, &value) in &self.vad_parameters {
+            vad_context.set_parameter(parameter, value)?;
+        }
+
+        Ok(ProcessorHandles {
+            processor,
+            processor_context,
+            vad_context,
+        })
+    }
+
+    /// Checks everything that can be checked without creating a processor.
+    fn validate(&self, config: &ProcessorConfig) -> Result<(), AicError> {
+        self.license.to_c_string()?;
+
+        if config.num_channels == 0
+            || config.num_channels > Processor::MAX_CHANNELS
+            || !self.model.supports(config.sample_rate, config.num_frames)
+        {
+            return Err(AicError::AudioConfigUnsupported);
+        }
+
+        // Both processor parameters range from 0.0 to 1.0
+        if !self
+            .parameters
+            .values()
+            .all(|value| (0.0..=1.0).contains(value))
+        {
+            return Err(AicError::ParameterOutOfRange);
+        }
+
+        // The upper limits of VAD parameters depend on the model, but none can be negative
+        if !self
+            .vad_parameters
+            .values()
+            .all(|value| value.is_finite() && *value >= 0.0)
+        {
+            return Err(AicError::ParameterOutOfRange);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::load_test_model;
+
+    #[test]
+    fn build_applies_the_whole_setup() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+
+        let handles = Processor::builder(&model, &license_key)
+            .with_config(&config)
+            .with_parameters([(ProcessorParameter::EnhancementLevel, 0.7)])
+            .with_vad_parameter(VadParameter::Sensitivity, 6.0)
+            .build()
+            .unwrap();
+
+        assert_eq!(handles.processor.config(), Some(&config));
+        assert_eq!(
+            handles
+                .processor_context
+                .parameter(ProcessorParameter::EnhancementLevel)
+                .unwrap(),
+            0.7
+        );
+        assert_eq!(
+            handles
+                .vad_context
+                .parameter(VadParameter::Sensitivity)
+                .unwrap(),
+            6.0
+        );
+    }
+
+    #[test]
+    fn build_defaults_to_the_optimal_config() {
+        let (model, license_key) = load_test_model().unwrap();
+
+        let handles = Processor::builder(&model, &license_key).build().unwrap();
+        assert_eq!(
+            handles.processor.config(),
+            Some(&ProcessorConfig::optimal(&model))
+        );
+    }
+
+    #[test]
+    fn invalid_setups_are_rejected_up_front() {
+        let (model, license_key) = load_test_model().unwrap();
+        let builder = || Processor::builder(&model, &license_key);
+
+        assert_eq!(
+            Processor::builder(&model, "invalid\0license")
+                .build()
+                .unwrap_err(),
+            AicError::LicenseFormatInvalid
+        );
+        assert_eq!(
+            builder()
+                .with_config(&ProcessorConfig::optimal(&model).with_num_channels(0))
+                .build()
+                .unwrap_err(),
+            AicError::AudioConfigUnsupported
+        );
+        assert_eq!(
+            builder()
+                .with_parameter(ProcessorParameter::EnhancementLevel, 1.5)
+                .build()
+                .unwrap_err(),
+            AicError::ParameterOutOfRange
+        );
+        assert_eq!(
+            builder()
+                .with_vad_parameter(VadParameter::SpeechHoldDuration, f32::NAN)
+                .build()
+                .unwrap_err(),
+            AicError::ParameterOutOfRange
+        );
+    }
+}
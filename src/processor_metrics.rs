@@ -0,0 +1,381 @@
+use crate::{
+    error::AicError,
+    processor::{Processor, ProcessorContext},
+};
+
+use std::time::{Duration, Instant};
+
+/// Number of histogram buckets per budget.
+const BUCKETS_PER_BUDGET: u32 = 64;
+/// Number of histogram buckets. Times above four budgets are collected in the last bucket.
+const NUM_BUCKETS: usize = 4 * BUCKETS_PER_BUDGET as usize + 1;
+
+/// Measures how long a [`Processor`] takes to process each block.
+///
+/// [`ProcessorMetrics`] wraps a processor and times every `process_*` call. It keeps the
+/// maximum and mean processing time, a histogram for percentiles, and counts overruns: blocks
+/// that took longer than the real-time budget. By default the budget is the duration of one
+/// block, `num_frames / sample_rate`, so an overrun means the processor could not keep up with
+/// a real-time stream. Use [`ProcessorMetrics::with_budget`] to reserve a safety margin.
+///
+/// Recording is real-time safe. Query the collected metrics with
+/// [`ProcessorMetrics::snapshot`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use aic_sdk::{Model, Processor, ProcessorConfig, ProcessorMetrics};
+/// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+/// # let model = Model::from_file("/path/to/model.aicmodel")?;
+/// let config = ProcessorConfig::optimal(&model);
+/// let processor = Processor::new(&model, &license_key)?.with_config(&config)?;
+/// let mut metrics = ProcessorMetrics::new(processor)?;
+///
+/// let mut audio = vec![0.0f32; config.num_frames];
+/// metrics.process_interleaved(&mut audio)?;
+///
+/// let snapshot = metrics.snapshot();
+/// println!("p99: {:?}, overruns: {}", snapshot.p99, snapshot.overruns);
+/// # Ok::<(), aic_sdk::AicError>(())
+/// ```
+pub struct ProcessorMetrics<'a> {
+    processor: Processor<'a>,
+    budget: Duration,
+    /// Width of a histogram bucket, never zero
+    bucket_width: Duration,
+    blocks: u64,
+    overruns: u64,
+    total: Duration,
+    max: Duration,
+    histogram: [u64; NUM_BUCKETS],
+}
+
+/// Processing time statistics collected by [`ProcessorMetrics`].
+///
+/// All durations are zero if no block has been processed yet. Percentiles are estimated from
+/// a histogram with a resolution of 1/64 of the budget, and are capped at the maximum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProcessorMetricsSnapshot {
+    /// Number of blocks processed successfully.
+    pub blocks: u64,
+    /// Number of blocks that took longer than the budget.
+    pub overruns: u64,
+    /// Processing time budget per block.
+    pub budget: Duration,
+    /// Mean processing time per block.
+    pub mean: Duration,
+    /// Maximum processing time of a single block.
+    pub max: Duration,
+    /// Median processing time.
+    pub p50: Duration,
+    /// 95th percentile of the processing time.
+    pub p95: Duration,
+    /// 99th percentile of the processing time.
+    pub p99: Duration,
+}
+
+impl<'a> ProcessorMetrics<'a> {
+    /// Wraps an initialized processor.
+    ///
+    /// The budget is set to the duration of one block of the processor's configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `processor` - The processor to measure.
+    ///
+    /// # Returns
+    ///
+    /// Returns the metrics wrapper, or [`AicError::ProcessorNotInitialized`] if the processor
+    /// has not been initialized.
+    pub fn new(processor: Processor<'a>) -> Result<Self, AicError> {
+        let config = processor
+            .config()
+            .ok_or(AicError::ProcessorNotInitialized)?;
+        let budget = config.frames_to_duration(config.num_frames);
+
+        Ok(Self {
+            processor,
+            budget,
+            bucket_width: bucket_width(budget),
+            blocks: 0,
+            overruns: 0,
+            total: Duration::ZERO,
+            max: Duration::ZERO,
+            histogram: [0; NUM_BUCKETS],
+        })
+    }
+
+    /// Sets the processing time budget per block and clears the collected metrics.
+    ///
+    /// # Arguments
+    ///
+    /// * `budget` - Blocks taking longer than this are counted as overruns.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use aic_sdk::{Model, Processor, ProcessorConfig, ProcessorMetrics};
+    /// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+    /// # let model = Model::from_file("/path/to/model.aicmodel")?;
+    /// # let config = ProcessorConfig::optimal(&model);
+    /// let processor = Processor::new(&model, &license_key)?.with_config(&config)?;
+    /// let metrics = ProcessorMetrics::new(processor)?;
+    ///
+    /// // Reserve 30% of the period as a safety margin
+    /// let budget = metrics.budget().mul_f64(0.7);
+    /// let metrics = metrics.with_budget(budget);
+    /// # Ok::<(), aic_sdk::AicError>(())
+    /// ```
+    pub fn with_budget(mut self, budget: Duration) -> Self {
+        self.budget = budget;
+        self.bucket_width = bucket_width(budget);
+        self.reset_metrics();
+        self
+    }
+
+    /// Returns the processing time budget per block.
+    pub fn budget(&self) -> Duration {
+        self.budget
+    }
+
+    /// Processes planar audio with [`Processor::process_planar`] and records the processing time.
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Can be called from audio processing threads.
+    pub fn process_planar<V: AsMut<[f32]>>(&mut self, audio: &mut [V]) -> Result<(), AicError> {
+        let start = Instant::now();
+        self.processor.process_planar(audio)?;
+        self.record(start.elapsed());
+        Ok(())
+    }
+
+    /// Processes interleaved audio with [`Processor::process_interleaved`] and records the
+    /// processing time.
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Can be called from audio processing threads.
+    pub fn process_interleaved(&mut self, audio: &mut [f32]) -> Result<(), AicError> {
+        let start = Instant::now();
+        self.processor.process_interleaved(audio)?;
+        self.record(start.elapsed());
+        Ok(())
+    }
+
+    /// Processes sequential audio with [`Processor::process_sequential`] and records the
+    /// processing time.
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Can be called from audio processing threads.
+    pub fn process_sequential(&mut self, audio: &mut [f32]) -> Result<(), AicError> {
+        let start = Instant::now();
+        self.processor.process_sequential(audio)?;
+        self.record(start.elapsed());
+        Ok(())
+    }
+
+    /// Adds the processing time of a successfully processed block.
+    fn record(&mut self, elapsed: Duration) {
+        self.blocks += 1;
+        self.total += elapsed;
+        self.max = self.max.max(elapsed);
+        if elapsed > self.budget {
+            self.overruns += 1;
+        }
+
+        let bucket = elapsed.as_nanos() / self.bucket_width.as_nanos();
+        self.histogram[(bucket as usize).min(NUM_BUCKETS - 1)] += 1;
+    }
+
+    /// Returns the metrics collected since creation or the last
+    /// [`ProcessorMetrics::reset_metrics`].
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Can be called from audio processing threads.
+    pub fn snapshot(&self) -> ProcessorMetricsSnapshot {
+        let mean = match u32::try_from(self.blocks) {
+            Ok(0) => Duration::ZERO,
+            Ok(blocks) => self.total / blocks,
+            Err(_) => Duration::from_secs_f64(self.total.as_secs_f64() / self.blocks as f64),
+        };
+
+        ProcessorMetricsSnapshot {
+            blocks: self.blocks,
+            overruns: self.overruns,
+            budget: self.budget,
+            mean,
+            max: self.max,
+            p50: self.percentile(0.5),
+            p95: self.percentile(0.95),
+            p99: self.percentile(0.99),
+        }
+    }
+
+    /// Returns the estimated processing time below which the fraction `quantile` of all blocks
+    /// were processed, e.g. `0.99` for the 99th percentile.
+    ///
+    /// The estimate is the upper bound of the histogram bucket containing the percentile,
+    /// capped at the maximum processing time. Returns zero if no block was processed.
+    ///
+    /// # Arguments
+    ///
+    /// * `quantile` - Fraction between `0.0` and `1.0`. Values outside are clamped.
+    pub fn percentile(&self, quantile: f64) -> Duration {
+        if self.blocks == 0 {
+            return Duration::ZERO;
+        }
+
+        let rank = ((quantile.clamp(0.0, 1.0) * self.blocks as f64).ceil() as u64).max(1);
+        let mut count = 0;
+        for (bucket, &blocks) in self.histogram.iter().enumerate() {
+            count += blocks;
+            if count >= rank {
+                return (self.bucket_width * (bucket as u32 + 1)).min(self.max);
+            }
+        }
+
+        self.max
+    }
+
+    /// Clears all collected metrics.
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Can be called from audio processing threads.
+    pub fn reset_metrics(&mut self) {
+        self.blocks = 0;
+        self.overruns = 0;
+        self.total = Duration::ZERO;
+        self.max = Duration::ZERO;
+        self.histogram = [0; NUM_BUCKETS];
+    }
+
+    /// Creates a [`ProcessorContext`] of the wrapped processor.
+    ///
+    /// # Warning
+    /// Do not call from audio processing threads as this allocates memory.
+    pub fn processor_context(&self) -> ProcessorContext {
+        self.processor.processor_context()
+    }
+
+    /// Creates a [`ProcessorContext`] of the wrapped processor, returning an error instead of
+    /// panicking.
+    ///
+    /// See [`Processor::try_processor_context`] for details.
+    ///
+    /// # Warning
+    /// Do not call from audio processing threads as this allocates memory.
+    pub fn try_processor_context(&self) -> Result<ProcessorContext, AicError> {
+        self.processor.try_processor_context()
+    }
+
+    /// Returns a reference to the wrapped processor, e.g. to create a
+    /// [`VadContext`](crate::VadContext).
+    pub fn processor(&self) -> &Processor<'a> {
+        &self.processor
+    }
+
+    /// Consumes the metrics wrapper and returns the wrapped processor.
+    pub fn into_inner(self) -> Processor<'a> {
+        self.processor
+    }
+}
+
+fn bucket_width(budget: Duration) -> Duration {
+    (budget / BUCKETS_PER_BUDGET).max(Duration::from_nanos(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ProcessorConfig, test_support::load_test_model};
+
+    fn test_metrics() -> (ProcessorMetrics<'static>, ProcessorConfig) {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model);
+        let processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+        (ProcessorMetrics::new(processor).unwrap(), config)
+    }
+
+    #[test]
+    fn budget_defaults_to_block_duration() {
+        let (metrics, config) = test_metrics();
+
+        assert_eq!(
+            metrics.budget(),
+            config.frames_to_duration(config.num_frames)
+        );
+    }
+
+    #[test]
+    fn uninitialized_processor_is_rejected() {
+        let (model, license_key) = load_test_model().unwrap();
+        let processor = Processor::new(&model, &license_key).unwrap();
+
+        assert!(matches!(
+            ProcessorMetrics::new(processor),
+            Err(AicError::ProcessorNotInitialized)
+        ));
+    }
+
+    #[test]
+    fn processed_blocks_are_recorded() {
+        let (mut metrics, config) = test_metrics();
+        assert_eq!(metrics.snapshot().blocks, 0);
+        assert_eq!(metrics.snapshot().max, Duration::ZERO);
+
+        let mut audio = vec![0.0f32; config.num_frames];
+        for _ in 0..10 {
+            metrics.process_interleaved(&mut audio).unwrap();
+        }
+        // Failed calls are not recorded
+        let mut too_long = vec![0.0f32; config.num_frames + 1];
+        assert!(metrics.process_interleaved(&mut too_long).is_err());
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.blocks, 10);
+        assert!(snapshot.max > Duration::ZERO);
+        assert!(snapshot.mean <= snapshot.max);
+        assert!(snapshot.p50 <= snapshot.p95);
+        assert!(snapshot.p95 <= snapshot.p99);
+        assert!(snapshot.p99 <= snapshot.max);
+
+        metrics.reset_metrics();
+        assert_eq!(metrics.snapshot().blocks, 0);
+    }
+
+    #[test]
+    fn blocks_exceeding_the_budget_are_overruns() {
+        let (metrics, config) = test_metrics();
+        let mut metrics = metrics.with_budget(Duration::ZERO);
+
+        let mut audio = vec![0.0f32; config.num_frames];
+        for _ in 0..3 {
+            metrics.process_interleaved(&mut audio).unwrap();
+        }
+
+        assert_eq!(metrics.snapshot().overruns, 3);
+    }
+
+    #[test]
+    fn percentiles_follow_the_histogram() {
+        let (metrics, _) = test_metrics();
+        let mut metrics = metrics.with_budget(Duration::from_millis(64));
+
+        for millis in 1..=100 {
+            metrics.record(Duration::from_micros(millis * 500));
+        }
+
+        // Buckets are 1 ms wide
+        assert_eq!(metrics.percentile(0.5), Duration::from_millis(26));
+        assert_eq!(metrics.percentile(0.99), Duration::from_millis(50));
+        assert_eq!(metrics.percentile(1.0), Duration::from_millis(50));
+        assert_eq!(metrics.snapshot().overruns, 0);
+    }
+}
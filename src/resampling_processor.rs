@@ -0,0 +1,414 @@
+use crate::{
+    error::AicError,
+    processor::{Processor, ProcessorConfig},
+    stream_processor::StreamProcessor,
+};
+
+use std::collections::VecDeque;
+
+/// Enhances audio at any sample rate by resampling it to the processing sample rate.
+///
+/// Models work best at their optimal sample rate. If the audio device runs at a different rate,
+/// [`ResamplingProcessor`] converts the input to the sample rate of the given
+/// [`ProcessorConfig`] (usually the model's optimal rate), enhances it in blocks of
+/// `num_frames` frames using a [`StreamProcessor`], and converts the result back to the
+/// input sample rate.
+///
+/// Resampling uses linear interpolation without an anti-aliasing filter. It is cheap, but
+/// it attenuates high frequencies, and converting to a lower sample rate aliases: content
+/// above half the lower rate folds back into the audible band instead of being removed. This
+/// happens when the input rate is above the processing rate (e.g. 96 kHz input for a 48 kHz
+/// model) and when the enhanced audio is converted back to an input rate below the
+/// processing rate. Band-limit the input to half the lower of the two rates if the aliasing
+/// matters, or use a dedicated resampling crate with a [`StreamProcessor`] instead.
+///
+/// Audio is processed in-place: every call returns as many frames as it was given, delayed
+/// by a constant [`ResamplingProcessor::output_delay`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use aic_sdk::{Model, Processor, ProcessorConfig, ResamplingProcessor};
+/// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+/// # let model = Model::from_file("/path/to/model.aicmodel")?;
+/// let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+/// let processor = Processor::new(&model, &license_key)?;
+/// let mut resampling = ResamplingProcessor::new(processor, &config, 44100)?;
+///
+/// let mut audio = vec![0.0f32; 2 * 512];
+/// resampling.process_interleaved(&mut audio)?;
+/// # Ok::<(), aic_sdk::AicError>(())
+/// ```
+pub struct ResamplingProcessor<'a> {
+    stream: StreamProcessor<'a>,
+    input_sample_rate: u32,
+    processing_sample_rate: u32,
+    num_channels: usize,
+    /// Converts the input to the processing sample rate
+    upsampler: LinearResampler,
+    /// Converts the enhanced audio back to the input sample rate
+    downsampler: LinearResampler,
+    /// Input audio converted to the processing sample rate
+    resampled: Vec<f32>,
+    /// Enhanced block popped from the stream processor
+    block: Vec<f32>,
+    /// Enhanced audio at the input sample rate
+    output: VecDeque<f32>,
+    /// Frames of silence the output was primed with
+    priming_frames: usize,
+}
+
+impl<'a> ResamplingProcessor<'a> {
+    /// Creates a new resampling processor.
+    ///
+    /// # Arguments
+    ///
+    /// * `processor` - The processor used to enhance the audio.
+    /// * `config` - Configuration the processor is initialized with. Audio is enhanced at
+    ///   `config.sample_rate` in blocks of `config.num_frames` frames. Use
+    ///   [`ProcessorConfig::optimal`] for the lowest latency.
+    /// * `input_sample_rate` - Sample rate in Hz of the audio passed to
+    ///   [`ResamplingProcessor::process_interleaved`].
+    ///
+    /// # Returns
+    ///
+    /// Returns the resampling processor, or an [`AicError`] if the processor could not be
+    /// initialized. Returns [`AicError::AudioConfigUnsupported`] if `input_sample_rate` is zero.
+    ///
+    /// # Warning
+    /// Do not call from audio processing threads as this allocates memory.
+    pub fn new(
+        processor: Processor<'a>,
+        config: &ProcessorConfig,
+        input_sample_rate: u32,
+    ) -> Result<Self, AicError> {
+        if input_sample_rate == 0 {
+            return Err(AicError::AudioConfigUnsupported);
+        }
+
+        let stream = StreamProcessor::new(processor, config)?;
+        let num_channels = config.num_channels as usize;
+
+        // Enough silence to bridge the time until the first block has been enhanced,
+        // plus one frame of interpolation look-ahead in each direction.
+        let priming_frames =
+            convert_frames(config.num_frames, config.sample_rate, input_sample_rate) + 2;
+
+        let mut output = VecDeque::with_capacity(num_channels * 4 * priming_frames);
+        output.extend(std::iter::repeat_n(0.0, num_channels * priming_frames));
+
+        Ok(Self {
+            block: vec![0.0; stream.block_len()],
+            resampled: Vec::with_capacity(2 * stream.block_len()),
+            stream,
+            input_sample_rate,
+            processing_sample_rate: config.sample_rate,
+            num_channels,
+            upsampler: LinearResampler::new(num_channels, input_sample_rate, config.sample_rate),
+            downsampler: LinearResampler::new(num_channels, config.sample_rate, input_sample_rate),
+            output,
+            priming_frames,
+        })
+    }
+
+    /// Returns the sample rate in Hz of the audio passed to and returned from the processor.
+    pub fn input_sample_rate(&self) -> u32 {
+        self.input_sample_rate
+    }
+
+    /// Returns the sample rate in Hz at which the audio is enhanced.
+    pub fn processing_sample_rate(&self) -> u32 {
+        self.processing_sample_rate
+    }
+
+    /// Enhances interleaved audio at the input sample rate in-place.
+    ///
+    /// # Arguments
+    ///
+    /// * `audio` - Interleaved audio of any length. Its length must be a multiple of the number
+    ///   of channels.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success or an [`AicError`] if processing fails.
+    /// Returns [`AicError::AudioConfigMismatch`] if `audio` does not contain whole frames.
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe as long as the internal buffers do not need to grow. They are sized for
+    /// the processor's block size and only grow if much larger chunks are processed.
+    pub fn process_interleaved(&mut self, audio: &mut [f32]) -> Result<(), AicError> {
+        if !audio.len().is_multiple_of(self.num_channels) {
+            return Err(AicError::AudioConfigMismatch);
+        }
+
+        self.push(audio)?;
+
+        for sample in audio.iter_mut() {
+            // The output was primed with enough silence that it can never run dry.
+            *sample = self.output.pop_front().unwrap_or(0.0);
+        }
+
+        Ok(())
+    }
+
+    /// Enhances interleaved input audio and writes as much enhanced audio as is available and
+    /// fits into `output`, which can have a different length than `input`.
+    ///
+    /// Unlike [`ResamplingProcessor::process_interleaved`], which always returns as many
+    /// frames as it is given, this lets the consumer pull enhanced audio at its own pace.
+    /// Resampling produces a fractional number of frames per chunk, so the enhanced audio that
+    /// is available does not track the input exactly. Frames that do not fit into `output`
+    /// are kept for the next call, and `output` is only partially filled if not enough
+    /// enhanced audio is available. Both buffers are at the input sample rate.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Interleaved audio of any length. Its length must be a multiple of the number
+    ///   of channels. Can be empty to only drain enhanced audio.
+    /// * `output` - Interleaved output audio of any length. Its length must be a multiple of the
+    ///   number of channels.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of frames written to the start of `output`, or an [`AicError`] if
+    /// processing fails. Returns [`AicError::AudioConfigMismatch`] if either buffer does not
+    /// contain whole frames, in which case nothing is processed.
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe as long as the internal buffers do not need to grow. They grow if
+    /// enhanced audio is consistently pulled slower than input is pushed.
+    pub fn process_to(&mut self, input: &[f32], output: &mut [f32]) -> Result<usize, AicError> {
+        if !input.len().is_multiple_of(self.num_channels)
+            || !output.len().is_multiple_of(self.num_channels)
+        {
+            return Err(AicError::AudioConfigMismatch);
+        }
+
+        self.push(input)?;
+
+        let len = output.len().min(self.output.len());
+        for (dst, src) in output.iter_mut().zip(self.output.drain(..len)) {
+            *dst = src;
+        }
+        Ok(len / self.num_channels)
+    }
+
+    /// Resamples `input`, enhances all complete blocks and queues the enhanced audio at the
+    /// input sample rate.
+    fn push(&mut self, input: &[f32]) -> Result<(), AicError> {
+        self.resampled.clear();
+        self.upsampler.process(input, &mut self.resampled);
+        self.stream.push(&self.resampled)?;
+
+        while self.stream.pop(&mut self.block).is_some() {
+            self.resampled.clear();
+            self.downsampler.process(&self.block, &mut self.resampled);
+            self.output.extend(&self.resampled);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the delay in samples at the input sample rate between the input and the
+    /// enhanced output.
+    ///
+    /// This includes the processor's [`output_delay`](crate::ProcessorContext::output_delay),
+    /// the time spent waiting for a full block at the processing sample rate, and the
+    /// interpolation delay of the resamplers.
+    pub fn output_delay(&self) -> usize {
+        let processor_delay = convert_frames(
+            self.stream.output_delay(),
+            self.processing_sample_rate,
+            self.input_sample_rate,
+        );
+        self.priming_frames + processor_delay
+    }
+
+    /// Returns a reference to the wrapped processor, e.g. to create a
+    /// [`ProcessorContext`](crate::ProcessorContext) or [`VadContext`](crate::VadContext).
+    pub fn processor(&self) -> &Processor<'a> {
+        self.stream.processor()
+    }
+}
+
+/// Converts a number of frames between sample rates, rounding up.
+fn convert_frames(frames: usize, from_rate: u32, to_rate: u32) -> usize {
+    (frames as u64 * to_rate as u64).div_ceil(from_rate as u64) as usize
+}
+
+/// Streaming linear-interpolation resampler for interleaved audio.
+///
+/// There is no low-pass filter, so downsampling aliases content above the new Nyquist frequency.
+struct LinearResampler {
+    num_channels: usize,
+    /// Input frames advanced per output frame
+    step: f64,
+    /// Position of the next output frame, where `0.0` is `previous` and `1.0` is the first
+    /// frame of the next input chunk
+    position: f64,
+    /// Last input frame of the previous chunk
+    previous: Vec<f32>,
+}
+
+impl LinearResampler {
+    fn new(num_channels: usize, from_rate: u32, to_rate: u32) -> Self {
+        Self {
+            num_channels,
+            step: from_rate as f64 / to_rate as f64,
+            position: 0.0,
+            previous: vec![0.0; num_channels],
+        }
+    }
+
+    /// Resamples `input` and appends the result to `output`.
+    fn process(&mut self, input: &[f32], output: &mut Vec<f32>) {
+        let num_frames = input.len() / self.num_channels;
+        if num_frames == 0 {
+            return;
+        }
+
+        // Frame `0` is the last frame of the previous chunk, frame `i` is `input` frame `i - 1`.
+        let sample = |frame: usize, ch: usize| {
+            if frame == 0 {
+                self.previous[ch]
+            } else {
+                input[(frame - 1) * self.num_channels + ch]
+            }
+        };
+
+        while self.position < num_frames as f64 {
+            let frame = self.position as usize;
+            let fraction = (self.position - frame as f64) as f32;
+            for ch in 0..self.num_channels {
+                let current = sample(frame, ch);
+                let next = sample(frame + 1, ch);
+                output.push(current + (next - current) * fraction);
+            }
+            self.position += self.step;
+        }
+
+        self.position -= num_frames as f64;
+        self.previous
+            .copy_from_slice(&input[(num_frames - 1) * self.num_channels..]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ProcessorParameter, test_support::load_test_model};
+
+    fn sine(len: usize, frequency: f32, sample_rate: u32) -> Vec<f32> {
+        (0..len)
+            .map(|i| {
+                (2.0 * std::f32::consts::PI * frequency * i as f32 / sample_rate as f32).sin() * 0.5
+            })
+            .collect()
+    }
+
+    #[test]
+    fn linear_resampler_preserves_constant_signal() {
+        let mut resampler = LinearResampler::new(2, 44100, 48000);
+        let mut output = Vec::new();
+
+        // The first output frame interpolates from the initial silence, so skip the first chunk.
+        resampler.process(&[1.0; 2 * 100], &mut output);
+        output.clear();
+        resampler.process(&[1.0; 2 * 441], &mut output);
+
+        assert!(output.iter().all(|&sample| (sample - 1.0).abs() < 1e-6));
+    }
+
+    #[test]
+    fn linear_resampler_converts_frame_counts() {
+        for (from_rate, to_rate) in [(44100, 48000), (48000, 16000), (16000, 48000)] {
+            let mut resampler = LinearResampler::new(1, from_rate, to_rate);
+            let mut output = Vec::new();
+
+            let input_frames = from_rate as usize;
+            for chunk in vec![0.0f32; input_frames].chunks(123) {
+                resampler.process(chunk, &mut output);
+            }
+
+            assert!(output.len().abs_diff(to_rate as usize) <= 1);
+        }
+    }
+
+    #[test]
+    fn linear_resampler_interpolates_between_chunks() {
+        let mut resampler = LinearResampler::new(1, 1, 2);
+        let mut output = Vec::new();
+
+        resampler.process(&[2.0], &mut output);
+        resampler.process(&[4.0, 6.0], &mut output);
+
+        assert_eq!(output, [0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+    }
+
+    #[test]
+    fn process_to_matches_in_place_processing() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model);
+        let input_sample_rate = 44100;
+
+        let input = sine(input_sample_rate as usize / 2, 100.0, input_sample_rate);
+
+        let processor = Processor::new(&model, &license_key).unwrap();
+        let mut resampling =
+            ResamplingProcessor::new(processor, &config, input_sample_rate).unwrap();
+        let mut expected = input.clone();
+        for chunk in expected.chunks_mut(441) {
+            resampling.process_interleaved(chunk).unwrap();
+        }
+
+        let processor = Processor::new(&model, &license_key).unwrap();
+        let mut resampling =
+            ResamplingProcessor::new(processor, &config, input_sample_rate).unwrap();
+        let mut output = Vec::new();
+        let mut buffer = vec![0.0f32; 300];
+        for chunk in input.chunks(441) {
+            let num_frames = resampling.process_to(chunk, &mut buffer).unwrap();
+            assert!(num_frames <= buffer.len());
+            output.extend_from_slice(&buffer[..num_frames]);
+        }
+        while output.len() < expected.len() {
+            let num_frames = resampling.process_to(&[], &mut buffer).unwrap();
+            assert!(num_frames > 0);
+            output.extend_from_slice(&buffer[..num_frames]);
+        }
+
+        assert_eq!(output[..expected.len()], expected);
+    }
+
+    #[test]
+    fn bypassed_output_is_delayed_input() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model);
+        let input_sample_rate = 44100;
+
+        let processor = Processor::new(&model, &license_key).unwrap();
+        let mut resampling =
+            ResamplingProcessor::new(processor, &config, input_sample_rate).unwrap();
+        resampling
+            .processor()
+            .processor_context()
+            .set_parameter(ProcessorParameter::Bypass, 1.0)
+            .unwrap();
+        assert_eq!(resampling.input_sample_rate(), input_sample_rate);
+        assert_eq!(resampling.processing_sample_rate(), config.sample_rate);
+
+        let input = sine(input_sample_rate as usize, 100.0, input_sample_rate);
+        let mut output = input.clone();
+        for chunk in output.chunks_mut(441) {
+            resampling.process_interleaved(chunk).unwrap();
+        }
+
+        let delay = resampling.output_delay();
+        assert!(delay < input.len() / 2);
+        for (&sample, &expected) in output[delay..].iter().zip(&input) {
+            assert!((sample - expected).abs() < 0.05);
+        }
+    }
+}
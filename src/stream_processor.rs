@@ -0,0 +1,315 @@
+use crate::{
+    error::AicError,
+    processor::{Processor, ProcessorConfig, ProcessorContext},
+};
+
+use std::collections::VecDeque;
+
+/// Adapts audio chunks of arbitrary length to the fixed block size of a [`Processor`].
+///
+/// Many audio sources deliver chunks that do not match the model's optimal number of frames,
+/// e.g. 10 ms WebRTC frames. Instead of enabling `allow_variable_frames`, which adds latency
+/// inside the processor, [`StreamProcessor`] buffers incoming interleaved audio with
+/// [`StreamProcessor::push`], enhances it whenever a full block is available, and hands out
+/// the enhanced audio in blocks of `num_frames` frames with [`StreamProcessor::pop`].
+///
+/// # Latency
+///
+/// The enhanced stream is offset from the input stream by
+/// [`StreamProcessor::output_delay`] samples, exactly as if the audio had been processed in
+/// aligned blocks. In addition, up to `num_frames - 1` frames wait in the input buffer until a
+/// block is complete, see [`StreamProcessor::buffered_frames`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use aic_sdk::{Model, Processor, ProcessorConfig, StreamProcessor};
+/// # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+/// # let model = Model::from_file("/path/to/model.aicmodel")?;
+/// let config = ProcessorConfig::optimal(&model);
+/// let processor = Processor::new(&model, &license_key)?;
+/// let mut stream = StreamProcessor::new(processor, &config)?;
+///
+/// let mut block = vec![0.0f32; stream.block_len()];
+/// for chunk in [vec![0.0f32; 160], vec![0.0f32; 441], vec![0.0f32; 7]] {
+///     stream.push(&chunk)?;
+///     while let Some(num_frames) = stream.pop(&mut block) {
+///         // Use the enhanced `num_frames` frames in `block`...
+///     }
+/// }
+/// # Ok::<(), aic_sdk::AicError>(())
+/// ```
+pub struct StreamProcessor<'a> {
+    processor: Processor<'a>,
+    /// Context of `processor`, created once so that `reset` does not allocate
+    context: ProcessorContext,
+    num_channels: usize,
+    num_frames: usize,
+    /// Interleaved input waiting for a full block
+    input: VecDeque<f32>,
+    /// Interleaved enhanced output waiting to be popped
+    output: VecDeque<f32>,
+    /// Scratch buffer holding the block that is being processed
+    block: Vec<f32>,
+}
+
+impl<'a> StreamProcessor<'a> {
+    /// Creates a new stream processor.
+    ///
+    /// The processor is initialized with `config`, or reinitialized if it already was. Use
+    /// [`ProcessorConfig::optimal`] to process in blocks of the model's optimal number of
+    /// frames. `allow_variable_frames` is ignored,
+    /// since the stream processor only ever processes full blocks.
+    ///
+    /// # Arguments
+    ///
+    /// * `processor` - The processor used to enhance the audio.
+    /// * `config` - Audio processing configuration. Audio is processed in blocks of
+    ///   `config.num_frames` frames.
+    ///
+    /// # Returns
+    ///
+    /// Returns the stream processor, or an [`AicError`] if the processor could not be initialized.
+    ///
+    /// # Warning
+    /// Do not call from audio processing threads as this allocates memory.
+    pub fn new(mut processor: Processor<'a>, config: &ProcessorConfig) -> Result<Self, AicError> {
+        let config = config.clone().with_allow_variable_frames(false);
+        processor.reinitialize(&config)?;
+
+        let num_channels = config.num_channels as usize;
+        let block_len = num_channels * config.num_frames;
+
+        Ok(Self {
+            context: processor.try_processor_context()?,
+            processor,
+            num_channels,
+            num_frames: config.num_frames,
+            input: VecDeque::with_capacity(2 * block_len),
+            output: VecDeque::with_capacity(2 * block_len),
+            block: vec![0.0; block_len],
+        })
+    }
+
+    /// Returns the number of interleaved samples in a block, i.e. `num_channels * num_frames`.
+    ///
+    /// Buffers passed to [`StreamProcessor::pop`] must hold at least this many samples.
+    pub fn block_len(&self) -> usize {
+        self.block.len()
+    }
+
+    /// Appends interleaved audio of any length and enhances all complete blocks.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Interleaved audio. Its length must be a multiple of the number of channels.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success or an [`AicError`] if processing fails.
+    /// Returns [`AicError::AudioConfigMismatch`] if `input` does not contain whole frames,
+    /// in which case nothing is buffered.
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe as long as the internal buffers do not need to grow. They are sized
+    /// for two blocks and only grow if larger chunks are pushed or enhanced blocks are not
+    /// popped.
+    pub fn push(&mut self, input: &[f32]) -> Result<(), AicError> {
+        if !input.len().is_multiple_of(self.num_channels) {
+            return Err(AicError::AudioConfigMismatch);
+        }
+
+        self.input.extend(input);
+
+        let block_len = self.block.len();
+        while self.input.len() >= block_len {
+            for (dst, src) in self.block.iter_mut().zip(self.input.drain(..block_len)) {
+                *dst = src;
+            }
+            self.processor.process_interleaved(&mut self.block)?;
+            self.output.extend(&self.block);
+        }
+
+        Ok(())
+    }
+
+    /// Writes the next enhanced block into `out`, if one is available.
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - Destination for one interleaved block. Only the first
+    ///   [`StreamProcessor::block_len`] samples are written.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of frames written, or `None` if no complete block is available yet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than [`StreamProcessor::block_len`].
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Can be called from audio processing threads.
+    pub fn pop(&mut self, out: &mut [f32]) -> Option<usize> {
+        let block_len = self.block.len();
+        assert!(
+            out.len() >= block_len,
+            "Output buffer holds {} samples, but a block has {block_len} samples",
+            out.len()
+        );
+
+        if self.output.len() < block_len {
+            return None;
+        }
+
+        for (dst, src) in out.iter_mut().zip(self.output.drain(..block_len)) {
+            *dst = src;
+        }
+        Some(self.num_frames)
+    }
+
+    /// Returns the number of input frames waiting for a block to be completed.
+    pub fn buffered_frames(&self) -> usize {
+        self.input.len() / self.num_channels
+    }
+
+    /// Returns the number of enhanced frames that are ready to be popped.
+    pub fn available_frames(&self) -> usize {
+        self.output.len() / self.num_channels
+    }
+
+    /// Returns the delay in samples between the input stream and the enhanced output stream.
+    ///
+    /// This is the processor's [`output_delay`](crate::ProcessorContext::output_delay).
+    /// Buffering in the stream processor does not add to it: the n-th output frame always
+    /// corresponds to input frame `n - output_delay`.
+    pub fn output_delay(&self) -> usize {
+        self.context.output_delay()
+    }
+
+    /// Discards all buffered input and output audio and resets the processor state.
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Can be called from audio processing threads.
+    pub fn reset(&mut self) -> Result<(), AicError> {
+        self.input.clear();
+        self.output.clear();
+        self.context.reset()
+    }
+
+    /// Returns a reference to the wrapped processor, e.g. to create a
+    /// [`ProcessorContext`](crate::ProcessorContext) or [`VadContext`](crate::VadContext).
+    pub fn processor(&self) -> &Processor<'a> {
+        &self.processor
+    }
+
+    /// Consumes the stream processor and returns the wrapped processor.
+    ///
+    /// Audio that is still buffered is discarded.
+    pub fn into_inner(self) -> Processor<'a> {
+        self.processor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::load_test_model;
+
+    fn test_signal(len: usize) -> Vec<f32> {
+        (0..len)
+            .map(|i| (i as f32 * 0.05).sin() * 0.5 + (i as f32 * 0.37).sin() * 0.1)
+            .collect()
+    }
+
+    #[test]
+    fn ragged_chunks_match_block_aligned_processing() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+        let block_len = config.num_channels as usize * config.num_frames;
+        let input = test_signal(block_len * 10);
+
+        let mut expected = input.clone();
+        let mut processor = Processor::new(&model, &license_key)
+            .unwrap()
+            .with_config(&config)
+            .unwrap();
+        for block in expected.chunks_exact_mut(block_len) {
+            processor.process_interleaved(block).unwrap();
+        }
+
+        let processor = Processor::new(&model, &license_key).unwrap();
+        let mut stream = StreamProcessor::new(processor, &config).unwrap();
+        let mut output = Vec::new();
+        let mut block = vec![0.0f32; stream.block_len()];
+
+        // Chunk lengths in frames, chosen to never line up with the block size
+        let chunk_frames = [1, 7, 160, 33, 480, 2, 999];
+        let mut remaining = input.as_slice();
+        for &frames in chunk_frames.iter().cycle() {
+            if remaining.is_empty() {
+                break;
+            }
+            let len = (frames * 2).min(remaining.len());
+            let (chunk, rest) = remaining.split_at(len);
+            remaining = rest;
+
+            stream.push(chunk).unwrap();
+            while let Some(num_frames) = stream.pop(&mut block) {
+                assert_eq!(num_frames, config.num_frames);
+                output.extend_from_slice(&block);
+            }
+        }
+
+        assert_eq!(output.len(), expected.len());
+        for (&sample, &expected) in output.iter().zip(&expected) {
+            assert!((sample - expected).abs() < 1e-6);
+        }
+        assert_eq!(stream.buffered_frames(), 0);
+        assert_eq!(stream.available_frames(), 0);
+    }
+
+    #[test]
+    fn pop_returns_none_until_block_is_complete() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model);
+
+        let processor = Processor::new(&model, &license_key).unwrap();
+        let mut stream = StreamProcessor::new(processor, &config).unwrap();
+        let mut block = vec![0.0f32; stream.block_len()];
+
+        stream.push(&vec![0.0; config.num_frames - 1]).unwrap();
+        assert_eq!(stream.buffered_frames(), config.num_frames - 1);
+        assert_eq!(stream.pop(&mut block), None);
+
+        stream.push(&[0.0]).unwrap();
+        assert_eq!(stream.buffered_frames(), 0);
+        assert_eq!(stream.pop(&mut block), Some(config.num_frames));
+        assert_eq!(stream.pop(&mut block), None);
+    }
+
+    #[test]
+    fn push_rejects_partial_frames() {
+        let (model, license_key) = load_test_model().unwrap();
+        let config = ProcessorConfig::optimal(&model).with_num_channels(2);
+
+        let processor = Processor::new(&model, &license_key).unwrap();
+        let mut stream = StreamProcessor::new(processor, &config).unwrap();
+
+        assert_eq!(stream.push(&[0.0; 3]), Err(AicError::AudioConfigMismatch));
+        assert_eq!(stream.buffered_frames(), 0);
+    }
+
+    #[test]
+    fn stream_processor_is_send_and_sync() {
+        // Compile-time check that StreamProcessor implements Send and Sync.
+        fn assert_send<T: Send>() {}
+        fn assert_sync<T: Sync>() {}
+
+        assert_send::<StreamProcessor>();
+        assert_sync::<StreamProcessor>();
+    }
+}
@@ -0,0 +1,81 @@
+//! Test models shared by the unit tests of all modules.
+
+use crate::{error::AicError, model::Model};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::{Mutex, OnceLock},
+};
+
+fn download_lock() -> &'static Mutex<()> {
+    static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+    LOCK.get_or_init(|| Mutex::new(()))
+}
+
+fn find_existing_model(target_dir: &Path, file_prefix: &str) -> Option<PathBuf> {
+    let entries = fs::read_dir(target_dir).ok()?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .map(|name| name.contains(file_prefix) && name.ends_with(".aicmodel"))
+            .unwrap_or(false)
+            && path.is_file()
+        {
+            return Some(path);
+        }
+    }
+    None
+}
+
+/// Downloads the model `model_id` into the crate's `target/` directory, unless it is
+/// already there. Returns the path to the model file.
+fn get_model(model_id: &str) -> Result<PathBuf, AicError> {
+    let target_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("target");
+    // Model files are named after the id with underscores, e.g. `rook_s_48khz_v4.aicmodel`
+    let file_prefix = model_id.replace(['-', '.'], "_");
+
+    if let Some(existing) = find_existing_model(&target_dir, &file_prefix) {
+        return Ok(existing);
+    }
+
+    let _guard = download_lock().lock().unwrap();
+    if let Some(existing) = find_existing_model(&target_dir, &file_prefix) {
+        return Ok(existing);
+    }
+
+    if cfg!(feature = "download-model") {
+        Model::download(model_id, target_dir)
+    } else {
+        panic!(
+            "Model `{model_id}` not found in {} and `download-model` feature is disabled",
+            target_dir.display()
+        );
+    }
+}
+
+/// Downloads the default test model `rook-s-48khz`. Returns the path to the model file.
+pub(crate) fn get_rook_s_48khz() -> Result<PathBuf, AicError> {
+    get_model("rook-s-48khz")
+}
+
+/// Downloads the analyzer test model `tyto-l-16khz`. Returns the path to the model file.
+pub(crate) fn get_tyto_l_16khz() -> Result<PathBuf, AicError> {
+    get_model("tyto-l-16khz")
+}
+
+pub(crate) fn license_key() -> String {
+    std::env::var("AIC_SDK_LICENSE")
+        .expect("AIC_SDK_LICENSE environment variable must be set for tests")
+}
+
+/// Loads `rook-s-48khz` and reads the license key from `AIC_SDK_LICENSE`.
+pub(crate) fn load_test_model() -> Result<(Model<'static>, String), AicError> {
+    let license_key = license_key();
+
+    let model_path = get_rook_s_48khz()?;
+    let model = Model::from_file(&model_path)?;
+
+    Ok((model, license_key))
+}
@@ -0,0 +1,212 @@
+//! Helpers for regression tests of the SDK's output.
+//!
+//! [`VadComparison`] compares the speech decisions of a [`VadContext`](crate::VadContext),
+//! collected once per processed block, against a stored reference. Unlike comparing two
+//! `Vec<bool>` with `assert_eq!`, it reports where the sequences diverge and how many
+//! decisions differ, and it can align sequences that are shifted against each other by the
+//! detection delay.
+//!
+//! # Example
+//!
+//! ```rust
+//! use aic_sdk::testing::VadComparison;
+//!
+//! let expected = [false, false, true, true, true, false];
+//! let actual = [false, false, false, true, true, true];
+//!
+//! let comparison = VadComparison::exact(&actual, &expected);
+//! assert_eq!(comparison.mismatches, 2);
+//! assert_eq!(comparison.first_mismatch, Some(2));
+//!
+//! // The actual decisions lag the reference by one block
+//! let comparison = VadComparison::aligned(&actual, &expected, 2);
+//! assert_eq!(comparison.lag, 1);
+//! assert!(comparison.is_match(), "{comparison}");
+//! ```
+
+use std::fmt;
+
+/// The result of comparing a sequence of VAD decisions against an expected sequence.
+///
+/// Indices refer to positions in the expected sequence, i.e. to processed blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VadComparison {
+    /// Number of blocks the actual decisions lag the expected ones. The decision for expected
+    /// block `i` is taken from actual block `i + lag`. Always `0` for [`VadComparison::exact`].
+    pub lag: isize,
+    /// Number of decisions that were compared.
+    pub compared: usize,
+    /// Number of compared decisions that differ. For [`VadComparison::exact`], and for
+    /// [`VadComparison::aligned`] if the sequences do not overlap, decisions missing from the
+    /// shorter sequence also count as mismatches.
+    pub mismatches: usize,
+    /// Index of the first differing decision, if any.
+    pub first_mismatch: Option<usize>,
+    /// Number of decisions in the actual sequence.
+    pub actual_len: usize,
+    /// Number of decisions in the expected sequence.
+    pub expected_len: usize,
+}
+
+impl VadComparison {
+    /// Compares two sequences of VAD decisions block by block.
+    ///
+    /// Sequences of different length never match, every decision missing from the shorter one
+    /// counts as a mismatch.
+    pub fn exact(actual: &[bool], expected: &[bool]) -> Self {
+        let compared = actual.len().max(expected.len());
+        let differs = |i: usize| actual.get(i) != expected.get(i);
+
+        Self {
+            lag: 0,
+            compared,
+            mismatches: (0..compared).filter(|&i| differs(i)).count(),
+            first_mismatch: (0..compared).find(|&i| differs(i)),
+            actual_len: actual.len(),
+            expected_len: expected.len(),
+        }
+    }
+
+    /// Compares two sequences of VAD decisions, allowing the actual sequence to be shifted
+    /// against the expected one by up to `max_lag` blocks in either direction.
+    ///
+    /// The shift with the fewest mismatches is chosen, preferring smaller shifts on ties.
+    /// Only the overlapping part of both sequences is compared, so the blocks at either end
+    /// that are shifted out do not count as mismatches. Keep `max_lag` well below the length of
+    /// the sequences, otherwise a large shift with only a few overlapping blocks can match by
+    /// chance. If the sequences do not overlap at any shift, e.g. because the actual sequence
+    /// is empty, they are compared like in [`VadComparison::exact`].
+    ///
+    /// To convert the processor's [output delay](crate::ProcessorContext::output_delay) into
+    /// blocks, divide it by the number of frames per block.
+    pub fn aligned(actual: &[bool], expected: &[bool], max_lag: usize) -> Self {
+        // Shifts beyond the longer sequence leave nothing to compare
+        let max_lag = max_lag.min(actual.len().max(expected.len())) as isize;
+
+        let mut lags: Vec<isize> = (-max_lag..=max_lag).collect();
+        lags.sort_by_key(|lag| lag.unsigned_abs());
+
+        lags.into_iter()
+            .map(|lag| Self::shifted(actual, expected, lag))
+            .filter(|comparison| comparison.compared > 0)
+            .min_by_key(|comparison| comparison.mismatches)
+            .unwrap_or_else(|| Self::exact(actual, expected))
+    }
+
+    /// Returns `true` if no compared decisions differ.
+    pub fn is_match(&self) -> bool {
+        self.mismatches == 0
+    }
+
+    fn shifted(actual: &[bool], expected: &[bool], lag: isize) -> Self {
+        let pairs = expected.iter().enumerate().filter_map(|(i, expected)| {
+            let actual = actual.get(i.checked_add_signed(lag)?)?;
+            Some((i, actual != expected))
+        });
+
+        let mut compared = 0;
+        let mut mismatches = 0;
+        let mut first_mismatch = None;
+        for (i, differs) in pairs {
+            compared += 1;
+            if differs {
+                mismatches += 1;
+                first_mismatch.get_or_insert(i);
+            }
+        }
+
+        Self {
+            lag,
+            compared,
+            mismatches,
+            first_mismatch,
+            actual_len: actual.len(),
+            expected_len: expected.len(),
+        }
+    }
+}
+
+impl fmt::Display for VadComparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} of {} VAD decisions differ",
+            self.mismatches, self.compared
+        )?;
+        if let Some(index) = self.first_mismatch {
+            write!(f, ", first at block {index}")?;
+        }
+        if self.lag != 0 {
+            write!(f, ", actual decisions lag by {} block(s)", self.lag)?;
+        }
+        if self.actual_len != self.expected_len {
+            write!(
+                f,
+                " ({} actual vs. {} expected decisions)",
+                self.actual_len, self.expected_len
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_reports_first_mismatch_and_count() {
+        let comparison =
+            VadComparison::exact(&[true, false, true, true], &[true, true, true, false]);
+
+        assert_eq!(comparison.mismatches, 2);
+        assert_eq!(comparison.first_mismatch, Some(1));
+        assert!(!comparison.is_match());
+        assert_eq!(
+            comparison.to_string(),
+            "2 of 4 VAD decisions differ, first at block 1"
+        );
+    }
+
+    #[test]
+    fn exact_counts_missing_decisions() {
+        let comparison = VadComparison::exact(&[false, true], &[false, true, true]);
+
+        assert_eq!(comparison.mismatches, 1);
+        assert_eq!(comparison.first_mismatch, Some(2));
+        assert!(VadComparison::exact(&[true, false], &[true, false]).is_match());
+    }
+
+    #[test]
+    fn aligned_finds_the_detection_delay() {
+        let expected = [false, true, true, false, false, true, false];
+        let delayed = [false, false, false, true, true, false, false, true];
+
+        let comparison = VadComparison::aligned(&delayed, &expected, 3);
+        assert_eq!(comparison.lag, 2);
+        assert!(comparison.is_match(), "{comparison}");
+
+        let comparison = VadComparison::aligned(&expected, &delayed, 3);
+        assert_eq!(comparison.lag, -2);
+        assert!(comparison.is_match(), "{comparison}");
+    }
+
+    #[test]
+    fn aligned_prefers_no_lag_on_ties() {
+        let comparison = VadComparison::aligned(&[false; 4], &[false; 4], 2);
+
+        assert_eq!(comparison.lag, 0);
+        assert_eq!(comparison.compared, 4);
+    }
+
+    #[test]
+    fn aligned_without_actual_decisions_does_not_match() {
+        let comparison = VadComparison::aligned(&[], &[true, false], 2);
+
+        assert_eq!(comparison.lag, 0);
+        assert_eq!(comparison.mismatches, 2);
+        assert_eq!(comparison.first_mismatch, Some(0));
+        assert!(!comparison.is_match(), "{comparison}");
+        assert!(VadComparison::aligned(&[], &[], 2).is_match());
+    }
+}
@@ -2,8 +2,18 @@ use crate::error::*;
 
 use aic_sdk_sys::{AicVadParameter::*, *};
 
+use std::{fmt, str::FromStr};
+
 /// Configurable parameters for Voice Activity Detection.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// With the `serde` feature, parameters are serialized by their snake case names
+/// (e.g. `"speech_hold_duration"`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum VadParameter {
     /// Controls for how long the VAD continues to detect speech after the audio signal
     /// no longer contains speech.
@@ -67,6 +77,46 @@ pub enum VadParameter {
     MinimumSpeechDuration,
 }
 
+impl VadParameter {
+    /// All parameters, in declaration order.
+    pub const ALL: [VadParameter; 3] = [
+        VadParameter::SpeechHoldDuration,
+        VadParameter::Sensitivity,
+        VadParameter::MinimumSpeechDuration,
+    ];
+
+    /// Returns the stable snake case name of the parameter, e.g. `"speech_hold_duration"`.
+    ///
+    /// The names match the serde representation and never change between releases.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VadParameter::SpeechHoldDuration => "speech_hold_duration",
+            VadParameter::Sensitivity => "sensitivity",
+            VadParameter::MinimumSpeechDuration => "minimum_speech_duration",
+        }
+    }
+}
+
+impl fmt::Display for VadParameter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for VadParameter {
+    type Err = ParseParameterError;
+
+    /// Parses a parameter from its name as returned by [`VadParameter::as_str`].
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "speech_hold_duration" => Ok(VadParameter::SpeechHoldDuration),
+            "sensitivity" => Ok(VadParameter::Sensitivity),
+            "minimum_speech_duration" => Ok(VadParameter::MinimumSpeechDuration),
+            _ => Err(ParseParameterError::new(name)),
+        }
+    }
+}
+
 impl From<VadParameter> for AicVadParameter::Type {
     fn from(parameter: VadParameter) -> Self {
         match parameter {
@@ -123,6 +173,10 @@ impl VadContext {
     /// Align speech decisions to the input timeline using that delay.
     ///
     /// If the backing processor stops being processed, the VAD will not update its prediction.
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Can be called from audio processing threads.
     pub fn is_speech_detected(&self) -> bool {
         let mut value: bool = false;
         // SAFETY:
@@ -162,6 +216,10 @@ impl VadContext {
     /// Align speech decisions to the input timeline using that delay.
     ///
     /// If the backing processor stops being processed, the VAD will not update its prediction.
+    ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Can be called from audio processing threads.
     pub fn raw_vad_probability(&self) -> f32 {
         let mut value: f32 = 0.0;
         // SAFETY:
@@ -187,6 +245,10 @@ impl VadContext {
     ///
     /// Returns `Ok(())` on success or an `AicError` if the parameter cannot be set.
     ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Can be called from audio processing threads.
+    ///
     /// # Example
     ///
     /// ```rust,no_run
@@ -218,6 +280,10 @@ impl VadContext {
     ///
     /// Returns `Ok(value)` containing the current parameter value, or an `AicError` if the query fails.
     ///
+    /// # Real-time safety
+    ///
+    /// Real-time safe. Can be called from audio processing threads.
+    ///
     /// # Example
     ///
     /// ```rust,no_run
@@ -256,6 +322,39 @@ impl Drop for VadContext {
     }
 }
 
+// Only reports whether the handle is set, the SDK context is never accessed.
+impl fmt::Debug for VadContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VadContext")
+            .field("non_null", &!self.inner.is_null())
+            .finish_non_exhaustive()
+    }
+}
+
 // Safety: The underlying C library should be thread-safe for individual VadContext instances
 unsafe impl Send for VadContext {}
 unsafe impl Sync for VadContext {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vad_parameter_names_round_trip() {
+        for parameter in VadParameter::ALL {
+            assert_eq!(parameter.to_string(), parameter.as_str());
+            assert_eq!(parameter.as_str().parse(), Ok(parameter));
+        }
+
+        assert_eq!(
+            VadParameter::MinimumSpeechDuration.as_str(),
+            "minimum_speech_duration"
+        );
+    }
+
+    #[test]
+    fn unknown_vad_parameter_names_are_rejected() {
+        let error = "hold".parse::<VadParameter>().unwrap_err();
+        assert_eq!(error, ParseParameterError::new("hold"));
+    }
+}
@@ -0,0 +1,105 @@
+//! Reading and writing WAV files as interleaved `f32` samples.
+//!
+//! These helpers are meant for demos and tests: they load a whole file into memory and
+//! convert it to the normalized `f32` samples expected by
+//! [`Processor::process_interleaved`](crate::Processor::process_interleaved).
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use aic_sdk::{Model, Processor, ProcessorConfig};
+//! # let license_key = std::env::var("AIC_SDK_LICENSE").unwrap();
+//! # let model = Model::from_file("/path/to/model.aicmodel")?;
+//! let (spec, mut samples) = aic_sdk::wav::read_interleaved("noisy.wav")?;
+//!
+//! let config = ProcessorConfig {
+//!     sample_rate: spec.sample_rate,
+//!     num_channels: spec.channels,
+//!     num_frames: model.optimal_num_frames(spec.sample_rate),
+//!     allow_variable_frames: true,
+//! };
+//! let mut processor = Processor::new(&model, &license_key)?.with_config(&config)?;
+//! processor.for_each_block(&mut samples, |_, _| {})?;
+//!
+//! aic_sdk::wav::write_interleaved("enhanced.wav", spec, &samples)?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::path::Path;
+
+pub use hound::{Error as WavError, SampleFormat, WavSpec};
+
+/// Reads a WAV file into interleaved samples normalized to `[-1.0, 1.0]`.
+///
+/// Float samples are returned unchanged. Integer samples are divided by `2^(bits - 1)`, so
+/// e.g. the 16-bit range `-32768..=32767` maps to `-1.0..=0.99997`.
+///
+/// # Arguments
+///
+/// * `path` - Path of the WAV file.
+///
+/// # Returns
+///
+/// Returns the format of the file and its interleaved samples, or a [`WavError`] if the file
+/// cannot be read or decoded.
+pub fn read_interleaved<P: AsRef<Path>>(path: P) -> Result<(WavSpec, Vec<f32>), WavError> {
+    let reader = hound::WavReader::open(path)?;
+    let spec = reader.spec();
+
+    let samples = match spec.sample_format {
+        SampleFormat::Float => reader.into_samples::<f32>().collect::<Result<_, _>>()?,
+        SampleFormat::Int => {
+            let scale = int_scale(spec.bits_per_sample);
+            reader
+                .into_samples::<i32>()
+                .map(|sample| sample.map(|sample| sample as f32 / scale))
+                .collect::<Result<_, _>>()?
+        }
+    };
+
+    Ok((spec, samples))
+}
+
+/// Writes interleaved samples to a WAV file in the format given by `spec`.
+///
+/// This is the inverse of [`read_interleaved`]. For integer formats, samples are multiplied
+/// by `2^(bits - 1)`, rounded and clipped to the range of the format.
+///
+/// # Arguments
+///
+/// * `path` - Path of the WAV file. Existing files are overwritten.
+/// * `spec` - Format of the file, e.g. the one returned by [`read_interleaved`].
+/// * `samples` - Interleaved samples, normalized to `[-1.0, 1.0]`.
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success, or a [`WavError`] if the file cannot be written or the format
+/// is not supported.
+pub fn write_interleaved<P: AsRef<Path>>(
+    path: P,
+    spec: WavSpec,
+    samples: &[f32],
+) -> Result<(), WavError> {
+    let mut writer = hound::WavWriter::create(path, spec)?;
+
+    match spec.sample_format {
+        SampleFormat::Float => {
+            for &sample in samples {
+                writer.write_sample(sample)?;
+            }
+        }
+        SampleFormat::Int => {
+            let scale = int_scale(spec.bits_per_sample);
+            let (min, max) = (-scale, scale - 1.0);
+            for &sample in samples {
+                writer.write_sample((sample * scale).round().clamp(min, max) as i32)?;
+            }
+        }
+    }
+
+    writer.finalize()
+}
+
+fn int_scale(bits_per_sample: u16) -> f32 {
+    (1u64 << bits_per_sample.saturating_sub(1)) as f32
+}
@@ -0,0 +1,47 @@
+//! Test model shared by the integration tests.
+
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+use aic_sdk::Model;
+
+fn download_lock() -> &'static Mutex<()> {
+    static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+    LOCK.get_or_init(|| Mutex::new(()))
+}
+
+fn find_existing_model(target_dir: &Path) -> Option<PathBuf> {
+    let entries = std::fs::read_dir(target_dir).ok()?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().is_some_and(|ext| ext == "aicmodel")
+            && path
+                .file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with("quail_vf_2_1_s_16khz"))
+        {
+            return Some(path);
+        }
+    }
+    None
+}
+
+/// Downloads the test model `quail-vf-2.1-s-16khz` into the crate's `target/` directory.
+/// Returns the path to the downloaded model file.
+pub fn get_test_model_path() -> PathBuf {
+    let target_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("target");
+
+    if let Some(existing) = find_existing_model(&target_dir) {
+        return existing;
+    }
+
+    let _guard = download_lock().lock().unwrap();
+    if let Some(existing) = find_existing_model(&target_dir) {
+        return existing;
+    }
+
+    Model::download("quail-vf-2.1-s-16khz", &target_dir).expect("Failed to download test model")
+}
+
+pub fn license_key() -> String {
+    std::env::var("AIC_SDK_LICENSE").expect("AIC_SDK_LICENSE environment variable not set")
+}
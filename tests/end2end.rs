@@ -1,102 +1,20 @@
-use std::path::{Path, PathBuf};
-use std::sync::{Mutex, OnceLock};
+mod common;
 
-use aic_sdk::{Model, Processor, ProcessorConfig, ProcessorParameter};
+use std::path::Path;
+
+use aic_sdk::{
+    Model, Processor, ProcessorConfig, ProcessorParameter, layout, testing::VadComparison,
+};
+use common::{get_test_model_path, license_key};
 
 pub const TEST_AUDIO_PATH: &str = "tests/data/test_signal.wav";
 pub const TEST_AUDIO_ENHANCED_PATH: &str = "tests/data/test_signal_enhanced.wav";
 pub const VAD_RESULTS_PATH: &str = "tests/data/vad_results.json";
 
-fn download_lock() -> &'static Mutex<()> {
-    static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
-    LOCK.get_or_init(|| Mutex::new(()))
-}
-
-fn find_existing_model(target_dir: &Path) -> Option<PathBuf> {
-    let entries = std::fs::read_dir(target_dir).ok()?;
-    for entry in entries.flatten() {
-        let path = entry.path();
-        if path.extension().is_some_and(|ext| ext == "aicmodel")
-            && path
-                .file_name()
-                .is_some_and(|name| name.to_string_lossy().starts_with("quail_vf_2_1_s_16khz"))
-        {
-            return Some(path);
-        }
-    }
-    None
-}
-
-/// Downloads the test model `quail-vf-2.1-s-16khz` into the crate's `target/` directory.
-/// Returns the path to the downloaded model file.
-fn get_test_model_path() -> PathBuf {
-    let target_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("target");
-
-    if let Some(existing) = find_existing_model(&target_dir) {
-        return existing;
-    }
-
-    let _guard = download_lock().lock().unwrap();
-    if let Some(existing) = find_existing_model(&target_dir) {
-        return existing;
-    }
-
-    Model::download("quail-vf-2.1-s-16khz", &target_dir).expect("Failed to download test model")
-}
-
-fn license_key() -> String {
-    std::env::var("AIC_SDK_LICENSE").expect("AIC_SDK_LICENSE environment variable not set")
-}
-
 fn load_audio(path: impl AsRef<Path>) -> audio_file::Audio<f32> {
     audio_file::read(path, audio_file::ReadConfig::default()).expect("Failed to read audio file")
 }
 
-fn interleaved_to_sequential(interleaved: &[f32], num_channels: usize) -> Vec<f32> {
-    let num_frames = interleaved.len() / num_channels;
-    let mut sequential = vec![0.0f32; interleaved.len()];
-    for frame in 0..num_frames {
-        for ch in 0..num_channels {
-            sequential[ch * num_frames + frame] = interleaved[frame * num_channels + ch];
-        }
-    }
-    sequential
-}
-
-fn sequential_to_interleaved(sequential: &[f32], num_channels: usize) -> Vec<f32> {
-    let num_frames = sequential.len() / num_channels;
-    let mut interleaved = vec![0.0f32; sequential.len()];
-    for frame in 0..num_frames {
-        for ch in 0..num_channels {
-            interleaved[frame * num_channels + ch] = sequential[ch * num_frames + frame];
-        }
-    }
-    interleaved
-}
-
-fn interleaved_to_planar(interleaved: &[f32], num_channels: usize) -> Vec<Vec<f32>> {
-    let num_frames = interleaved.len() / num_channels;
-    let mut planar = vec![vec![0.0f32; num_frames]; num_channels];
-    for frame in 0..num_frames {
-        for ch in 0..num_channels {
-            planar[ch][frame] = interleaved[frame * num_channels + ch];
-        }
-    }
-    planar
-}
-
-fn planar_to_interleaved(planar: &[Vec<f32>]) -> Vec<f32> {
-    let num_channels = planar.len();
-    let num_frames = planar[0].len();
-    let mut interleaved = vec![0.0f32; num_channels * num_frames];
-    for frame in 0..num_frames {
-        for ch in 0..num_channels {
-            interleaved[frame * num_channels + ch] = planar[ch][frame];
-        }
-    }
-    interleaved
-}
-
 /// Tests audio enhancement by processing an entire stereo file containing voice in a single pass.
 /// Uses a non-optimal frame size (full file length) to verify the internal frame adapter handles
 /// arbitrary input sizes correctly. Uses a reduced enhancement level (0.9) and slightly lower
@@ -116,7 +34,7 @@ fn process_full_file_interleaved() {
         allow_variable_frames: false,
     };
 
-    let mut processor = Processor::new(&model, &license_key())
+    let mut processor = Processor::new(&model, license_key())
         .expect("Failed to create processor")
         .with_config(&config)
         .expect("Failed to initialize processor");
@@ -154,7 +72,7 @@ fn process_full_file_sequential() {
         allow_variable_frames: false,
     };
 
-    let mut processor = Processor::new(&model, &license_key())
+    let mut processor = Processor::new(&model, license_key())
         .expect("Failed to create processor")
         .with_config(&config)
         .expect("Failed to initialize processor");
@@ -164,12 +82,16 @@ fn process_full_file_sequential() {
         .set_parameter(ProcessorParameter::EnhancementLevel, 0.9)
         .expect("Failed to set enhancement level");
 
-    let mut samples = interleaved_to_sequential(&audio.samples_interleaved, num_channels);
+    let mut samples = vec![0.0f32; audio.samples_interleaved.len()];
+    layout::interleaved_to_sequential(&audio.samples_interleaved, &mut samples, num_channels)
+        .expect("Failed to convert to sequential layout");
     processor
         .process_sequential(&mut samples)
         .expect("Failed to process audio");
 
-    let result = sequential_to_interleaved(&samples, num_channels);
+    let mut result = vec![0.0f32; samples.len()];
+    layout::sequential_to_interleaved(&samples, &mut result, num_channels)
+        .expect("Failed to convert to interleaved layout");
     let expected = load_audio(TEST_AUDIO_ENHANCED_PATH);
     for (&sample, expected) in result.iter().zip(expected.samples_interleaved) {
         assert!(approx::abs_diff_eq!(sample, expected, epsilon = 1e-6));
@@ -193,7 +115,7 @@ fn process_full_file_planar() {
         allow_variable_frames: false,
     };
 
-    let mut processor = Processor::new(&model, &license_key())
+    let mut processor = Processor::new(&model, license_key())
         .expect("Failed to create processor")
         .with_config(&config)
         .expect("Failed to initialize processor");
@@ -203,12 +125,16 @@ fn process_full_file_planar() {
         .set_parameter(ProcessorParameter::EnhancementLevel, 0.9)
         .expect("Failed to set enhancement level");
 
-    let mut planar = interleaved_to_planar(&audio.samples_interleaved, num_channels);
+    let mut planar = vec![vec![0.0f32; num_frames]; num_channels];
+    layout::interleaved_to_planar(&audio.samples_interleaved, &mut planar)
+        .expect("Failed to convert to planar layout");
     processor
         .process_planar(&mut planar)
         .expect("Failed to process audio");
 
-    let result = planar_to_interleaved(&planar);
+    let mut result = vec![0.0f32; num_channels * num_frames];
+    layout::planar_to_interleaved(&planar, &mut result)
+        .expect("Failed to convert to interleaved layout");
     let expected = load_audio(TEST_AUDIO_ENHANCED_PATH);
     for (&sample, expected) in result.iter().zip(expected.samples_interleaved) {
         assert!(approx::abs_diff_eq!(sample, expected, epsilon = 1e-6));
@@ -233,7 +159,7 @@ fn process_blocks_with_vad() {
         allow_variable_frames: false,
     };
 
-    let mut processor = Processor::new(&model, &license_key())
+    let mut processor = Processor::new(&model, license_key())
         .expect("Failed to create processor")
         .with_config(&config)
         .expect("Failed to initialize processor");
@@ -262,7 +188,8 @@ fn process_blocks_with_vad() {
         std::fs::read_to_string(VAD_RESULTS_PATH).expect("Failed to read VAD results");
     let expected_results: Vec<bool> =
         serde_json::from_str(&expected_json).expect("Failed to parse VAD results");
-    assert_eq!(speech_detected_results, expected_results);
+    let comparison = VadComparison::exact(&speech_detected_results, &expected_results);
+    assert!(comparison.is_match(), "{comparison}");
 }
 
 /// Tests that VAD output is independent of the enhancement level.
@@ -282,7 +209,7 @@ fn process_blocks_with_vad_and_enhancement() {
         allow_variable_frames: false,
     };
 
-    let mut processor = Processor::new(&model, &license_key())
+    let mut processor = Processor::new(&model, license_key())
         .expect("Failed to create processor")
         .with_config(&config)
         .expect("Failed to initialize processor");
@@ -313,5 +240,98 @@ fn process_blocks_with_vad_and_enhancement() {
         std::fs::read_to_string(VAD_RESULTS_PATH).expect("Failed to read VAD results");
     let expected_results: Vec<bool> =
         serde_json::from_str(&expected_json).expect("Failed to parse VAD results");
-    assert_eq!(speech_detected_results, expected_results);
+    let comparison = VadComparison::exact(&speech_detected_results, &expected_results);
+    assert!(comparison.is_match(), "{comparison}");
+}
+
+/// Tests the `for_each_block` helper against the VAD reference.
+/// Mirrors `process_blocks_with_vad`, but lets the processor split the buffer into blocks.
+/// The final partial block is skipped, since variable frames are not allowed.
+#[test]
+fn process_blocks_with_vad_using_for_each_block() {
+    let audio = load_audio(TEST_AUDIO_PATH);
+    let model = Model::from_file(get_test_model_path()).expect("Failed to load model");
+
+    let config = ProcessorConfig {
+        sample_rate: audio.sample_rate,
+        num_channels: audio.num_channels,
+        num_frames: model.optimal_num_frames(audio.sample_rate),
+        allow_variable_frames: false,
+    };
+
+    let mut processor = Processor::new(&model, license_key())
+        .expect("Failed to create processor")
+        .with_config(&config)
+        .expect("Failed to initialize processor");
+
+    processor
+        .processor_context()
+        .set_parameter(ProcessorParameter::Bypass, 1.0)
+        .expect("Failed to set bypass");
+
+    let mut samples = audio.samples_interleaved.clone();
+    let mut speech_detected_results = Vec::new();
+    processor
+        .for_each_block(&mut samples, |_, vad_ctx| {
+            speech_detected_results.push(vad_ctx.is_speech_detected());
+        })
+        .expect("Failed to process blocks");
+
+    let expected_json =
+        std::fs::read_to_string(VAD_RESULTS_PATH).expect("Failed to read VAD results");
+    let expected_results: Vec<bool> =
+        serde_json::from_str(&expected_json).expect("Failed to parse VAD results");
+    let comparison = VadComparison::exact(&speech_detected_results, &expected_results);
+    assert!(comparison.is_match(), "{comparison}");
+}
+
+/// Tests that resetting the processor restores its deterministic initial state.
+/// Processes the same audio in optimal frame-sized blocks twice on one processor, resetting it
+/// in between, and verifies that both passes, including the VAD results, match. This is the
+/// guarantee the reference comparisons above and users' own golden tests rely on.
+#[test]
+fn process_blocks_after_reset_is_reproducible() {
+    let audio = load_audio(TEST_AUDIO_PATH);
+    let num_channels = audio.num_channels as usize;
+    let model = Model::from_file(get_test_model_path()).expect("Failed to load model");
+
+    let config = ProcessorConfig {
+        sample_rate: audio.sample_rate,
+        num_channels: audio.num_channels,
+        num_frames: model.optimal_num_frames(audio.sample_rate),
+        allow_variable_frames: false,
+    };
+
+    let mut processor = Processor::new(&model, license_key())
+        .expect("Failed to create processor")
+        .with_config(&config)
+        .expect("Failed to initialize processor");
+
+    let proc_ctx = processor.processor_context();
+    proc_ctx
+        .set_parameter(ProcessorParameter::EnhancementLevel, 0.9)
+        .expect("Failed to set enhancement level");
+    let vad_ctx = processor.vad_context();
+
+    let block_size = config.num_frames * num_channels;
+    let process_once = |processor: &mut Processor| {
+        let mut samples = audio.samples_interleaved.clone();
+        let mut speech_detected_results = Vec::new();
+        for chunk in samples.chunks_exact_mut(block_size) {
+            processor
+                .process_interleaved(chunk)
+                .expect("Failed to process block");
+            speech_detected_results.push(vad_ctx.is_speech_detected());
+        }
+        (samples, speech_detected_results)
+    };
+
+    let (first, first_vad) = process_once(&mut processor);
+    proc_ctx.reset().expect("Failed to reset processor");
+    let (second, second_vad) = process_once(&mut processor);
+
+    assert_eq!(first_vad, second_vad);
+    for (&sample, &expected) in second.iter().zip(&first) {
+        assert!(approx::abs_diff_eq!(sample, expected, epsilon = 1e-6));
+    }
 }
@@ -0,0 +1,51 @@
+#![cfg(feature = "file")]
+
+mod common;
+
+use std::path::PathBuf;
+
+use aic_sdk::Model;
+use common::{get_test_model_path, license_key};
+
+const TEST_AUDIO_PATH: &str = "tests/data/test_signal.wav";
+
+/// Enhances the bundled test signal end to end and checks that the output keeps the sample
+/// rate, channel count and length of the input.
+#[test]
+fn enhance_file_preserves_format_and_length() {
+    let model = Model::from_file(get_test_model_path()).expect("Failed to load model");
+    let output_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("target")
+        .join("test_signal_enhance_file.wav");
+
+    aic_sdk::file::enhance_file(TEST_AUDIO_PATH, &output_path, license_key(), &model)
+        .expect("Failed to enhance file");
+
+    let input = hound::WavReader::open(TEST_AUDIO_PATH).expect("Failed to open input");
+    let output = hound::WavReader::open(&output_path).expect("Failed to open output");
+
+    assert_eq!(output.spec().sample_rate, input.spec().sample_rate);
+    assert_eq!(output.spec().channels, input.spec().channels);
+    assert_eq!(output.duration(), input.duration());
+
+    let samples: Vec<f32> = output
+        .into_samples::<f32>()
+        .collect::<Result<_, _>>()
+        .expect("Failed to read output samples");
+    assert!(samples.iter().all(|sample| sample.is_finite()));
+    assert!(samples.iter().any(|&sample| sample != 0.0));
+}
+
+#[test]
+fn enhance_file_reports_missing_input() {
+    let model = Model::from_file(get_test_model_path()).expect("Failed to load model");
+
+    let result = aic_sdk::file::enhance_file(
+        "tests/data/does_not_exist.wav",
+        "target/does_not_exist_enhanced.wav",
+        license_key(),
+        &model,
+    );
+
+    assert!(matches!(result, Err(aic_sdk::file::FileError::Io(_))));
+}
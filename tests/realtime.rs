@@ -0,0 +1,168 @@
+//! Verifies that the `Processor::process_*` methods do not allocate once the processor has been
+//! initialized, and that `layout::ScratchBuffers` does not allocate after construction.
+//!
+//! A counting global allocator records every heap allocation made through Rust's allocator on
+//! the current thread. Allocations made by the native SDK itself bypass the Rust allocator and
+//! are not covered by these tests.
+
+mod common;
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+use aic_sdk::{Model, Processor, ProcessorConfig, layout::ScratchBuffers};
+use common::{get_test_model_path, license_key};
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+fn record_allocation() {
+    // `try_with` avoids panicking inside the allocator while thread-locals are being destroyed.
+    let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+}
+
+// SAFETY:
+// - All allocations are forwarded unchanged to the system allocator.
+// - Recording an allocation only touches a const-initialized thread-local, which never allocates.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record_allocation();
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        record_allocation();
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record_allocation();
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Runs `f` and returns the number of heap allocations it made on the current thread.
+fn count_allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+/// Creates an initialized stereo processor and returns it together with its configuration.
+fn create_processor<'a>(model: &Model<'a>) -> (Processor<'a>, ProcessorConfig) {
+    let config = ProcessorConfig::optimal(model).with_num_channels(2);
+    let processor = Processor::new(model, license_key())
+        .expect("Failed to create processor")
+        .with_config(&config)
+        .expect("Failed to initialize processor");
+    (processor, config)
+}
+
+#[test]
+fn process_interleaved_does_not_allocate() {
+    let model = Model::from_file(get_test_model_path()).expect("Failed to load model");
+    let (mut processor, config) = create_processor(&model);
+    let mut audio = vec![0.0f32; config.num_channels as usize * config.num_frames];
+
+    let allocations = count_allocations(|| {
+        for _ in 0..10 {
+            processor.process_interleaved(&mut audio).unwrap();
+        }
+    });
+
+    assert_eq!(allocations, 0);
+}
+
+#[test]
+fn process_sequential_does_not_allocate() {
+    let model = Model::from_file(get_test_model_path()).expect("Failed to load model");
+    let (mut processor, config) = create_processor(&model);
+    let mut audio = vec![0.0f32; config.num_channels as usize * config.num_frames];
+
+    let allocations = count_allocations(|| {
+        for _ in 0..10 {
+            processor.process_sequential(&mut audio).unwrap();
+        }
+    });
+
+    assert_eq!(allocations, 0);
+}
+
+#[test]
+fn process_planar_does_not_allocate() {
+    let model = Model::from_file(get_test_model_path()).expect("Failed to load model");
+    let (mut processor, config) = create_processor(&model);
+    let mut audio = vec![vec![0.0f32; config.num_frames]; config.num_channels as usize];
+
+    let allocations = count_allocations(|| {
+        for _ in 0..10 {
+            processor.process_planar(&mut audio).unwrap();
+        }
+    });
+
+    assert_eq!(allocations, 0);
+}
+
+#[test]
+fn cross_layout_processing_does_not_allocate() {
+    let model = Model::from_file(get_test_model_path()).expect("Failed to load model");
+    let (mut processor, config) = create_processor(&model);
+    let mut interleaved = vec![0.0f32; config.num_channels as usize * config.num_frames];
+    let mut planar = vec![vec![0.0f32; config.num_frames]; config.num_channels as usize];
+
+    let allocations = count_allocations(|| {
+        for _ in 0..10 {
+            processor
+                .process_deinterleaved_from_interleaved(&interleaved, &mut planar)
+                .unwrap();
+            processor
+                .process_interleaved_from_planar(&planar, &mut interleaved)
+                .unwrap();
+        }
+    });
+
+    assert_eq!(allocations, 0);
+}
+
+#[test]
+fn process_frames_does_not_allocate() {
+    let model = Model::from_file(get_test_model_path()).expect("Failed to load model");
+    let (mut processor, config) = create_processor(&model);
+    let mut frames = vec![[0.0f32; 2]; config.num_frames];
+
+    let allocations = count_allocations(|| {
+        for _ in 0..10 {
+            processor.process_frames(&mut frames).unwrap();
+        }
+    });
+
+    assert_eq!(allocations, 0);
+}
+
+#[test]
+fn scratch_buffers_do_not_allocate() {
+    let mut scratch = ScratchBuffers::new(2, 480);
+    let interleaved = vec![0.5f32; 2 * 480];
+    let planar = vec![vec![0.25f32; 480]; 2];
+
+    let allocations = count_allocations(|| {
+        for _ in 0..10 {
+            scratch.deinterleave_into(&interleaved).unwrap();
+            scratch.interleave_into(&planar).unwrap();
+        }
+    });
+
+    assert_eq!(allocations, 0);
+}
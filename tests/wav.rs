@@ -0,0 +1,72 @@
+#![cfg(feature = "wav")]
+
+use std::path::PathBuf;
+
+use aic_sdk::wav::{self, SampleFormat, WavSpec};
+
+const TEST_AUDIO_PATH: &str = "tests/data/test_signal.wav";
+
+fn output_path(name: &str) -> PathBuf {
+    let target_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("target");
+    std::fs::create_dir_all(&target_dir).expect("Failed to create target directory");
+    target_dir.join(name)
+}
+
+/// Reads the bundled test signal and checks that the samples match the ones decoded by
+/// `audio_file`, which the other integration tests use.
+#[test]
+fn read_matches_reference_decoder() {
+    let (spec, samples) = wav::read_interleaved(TEST_AUDIO_PATH).expect("Failed to read WAV");
+    let reference: audio_file::Audio<f32> =
+        audio_file::read(TEST_AUDIO_PATH, audio_file::ReadConfig::default())
+            .expect("Failed to read audio file");
+
+    assert_eq!(spec.sample_rate, reference.sample_rate);
+    assert_eq!(spec.channels, reference.num_channels);
+    assert_eq!(samples.len(), reference.samples_interleaved.len());
+    for (&sample, expected) in samples.iter().zip(reference.samples_interleaved) {
+        assert!(approx::abs_diff_eq!(sample, expected, epsilon = 1e-6));
+    }
+}
+
+/// Writes and reads back float and integer files, checking that samples survive the round
+/// trip up to the quantization of the format and that out-of-range samples are clipped.
+#[test]
+fn samples_round_trip_in_all_formats() {
+    let samples = [0.0f32, 0.5, -0.5, 0.25, -1.0, 0.999, 1.5, -1.5];
+
+    for (bits_per_sample, sample_format, epsilon) in [
+        (32, SampleFormat::Float, 0.0),
+        (16, SampleFormat::Int, 1.0 / 32768.0),
+        (24, SampleFormat::Int, 1.0 / 8_388_608.0),
+    ] {
+        let spec = WavSpec {
+            channels: 2,
+            sample_rate: 16000,
+            bits_per_sample,
+            sample_format,
+        };
+        let path = output_path(&format!("wav_round_trip_{bits_per_sample}.wav"));
+
+        wav::write_interleaved(&path, spec, &samples).expect("Failed to write WAV");
+        let (read_spec, read_samples) = wav::read_interleaved(&path).expect("Failed to read WAV");
+
+        assert_eq!(read_spec, spec);
+        assert_eq!(read_samples.len(), samples.len());
+        for (&read, &written) in read_samples.iter().zip(&samples) {
+            let expected = match sample_format {
+                SampleFormat::Float => written,
+                SampleFormat::Int => written.clamp(-1.0, 1.0 - epsilon),
+            };
+            assert!(
+                approx::abs_diff_eq!(read, expected, epsilon = epsilon),
+                "{bits_per_sample} bit: wrote {written}, read {read}"
+            );
+        }
+    }
+}
+
+#[test]
+fn read_reports_missing_file() {
+    assert!(wav::read_interleaved("tests/data/does_not_exist.wav").is_err());
+}